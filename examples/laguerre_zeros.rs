@@ -1,16 +1,7 @@
+use integrate::utils::math::{max, min};
 use num::Float;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
-fn max<F: Float>(a: F, b: F) -> F {
-    let two: F = F::one() + F::one();
-    ((a + b) + (a - b).abs()) / two
-}
-
-fn min<F: Float>(a: F, b: F) -> F {
-    let two: F = F::one() + F::one();
-    ((a + b) - (a - b).abs()) / two
-}
-
 #[time_graph::instrument]
 pub fn nb_eigenvalues_lt_x<F: Float + Send + Sync>(
     diagonal: &[F],
@@ -68,9 +59,17 @@ fn kth_eigenvalue<F: Float + Send + Sync>(diagonal: &[F], off_diagonal: &[F], k:
 
     let (mut xlower, mut xupper) = gershgorin_bounds(diagonal, off_diagonal);
 
-    let mut tolerance = two * epsilon * (xupper.abs() + xlower.abs());
+    let mut tolerance = max(
+        two * epsilon * (xupper.abs() + xlower.abs()),
+        F::min_positive_value(),
+    );
+
+    // capped, as the bracket stops shrinking once its bounds are consecutive floats
+    for _ in 0..4_096 {
+        if (xupper - xlower).abs() <= tolerance {
+            break;
+        }
 
-    while (xupper - xlower).abs() > tolerance {
         let xmid = (xupper + xlower) / two;
 
         let nb_eig_lt_xmid = nb_eigenvalues_lt_x(diagonal, off_diagonal, xmid);
@@ -81,7 +80,10 @@ fn kth_eigenvalue<F: Float + Send + Sync>(diagonal: &[F], off_diagonal: &[F], k:
             xlower = xmid;
         }
 
-        tolerance = epsilon * (xupper.abs() + xlower.abs());
+        tolerance = max(
+            epsilon * (xupper.abs() + xlower.abs()),
+            F::min_positive_value(),
+        );
     }
 
     (xlower + xupper) / two
@@ -10,3 +10,4 @@
 //!
 
 pub mod simpson;
+pub mod simpson_2d;
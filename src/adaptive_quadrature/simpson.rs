@@ -17,12 +17,16 @@ use std::fmt;
 
 use std::ops::{AddAssign, MulAssign};
 
-#[derive(Clone, Debug)]
+use crate::gauss_quadrature::legendre::legendre_rule;
+use crate::newton_cotes::simpson::simpson_rule_with_abs;
+use crate::newton_cotes::utils::oriented;
+use crate::tolerance::Tolerance;
+
+#[derive(Debug)]
 struct SubInterval<F: Float> {
     upper_limit: F,
     lower_limit: F,
     function: [F; 5],
-    interval: Option<Box<SubInterval<F>>>,
 }
 
 type Result<T> = std::result::Result<T, AdaptiveSimpsonError>;
@@ -36,13 +40,76 @@ impl fmt::Display for AdaptiveSimpsonError {
         write!(f, "{}", msg)
     }
 }
+
+/// Parameters of [`adaptive_simpson_method_with_config`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdaptiveConfig<F: Float> {
+    /// Minimum subinterval length to be used.
+    pub min_h: F,
+    /// Tolerance.
+    pub tolerance: F,
+    /// Maximum number of evaluations of the integrand, unlimited by default.
+    pub eval_budget: usize,
+}
+
+/// Tolerance of [`AdaptiveConfig::default`], and of the adaptive method run by
+/// [`quad_simple`], which doesn't apply to its fallback.
+pub const DEFAULT_TOLERANCE: f64 = 1e-8;
+
+/// Minimum subinterval length of [`AdaptiveConfig::default`], and fraction of $b - a$ used
+/// as the minimum subinterval length by [`quad_simple`].
+pub const DEFAULT_MIN_H: f64 = 1e-10;
+
+/// Number of subintervals of the composite Simpson's rule giving the first estimate of the
+/// integral in [`adaptive_simpson_method_with_tolerance`]: a single one would vanish as soon
+/// as $f(a) + 4 f(\frac{a+b}{2}) + f(b) = 0$.
+pub const PILOT_INTERVALS: usize = 16;
+
+/// Number of Gauss-Legendre points [`quad_simple`] falls back on.
+const FALLBACK_POINTS: usize = 1_000;
+
+/// Configuration with a tolerance of [`DEFAULT_TOLERANCE`], a minimum subinterval length of
+/// [`DEFAULT_MIN_H`] and no evaluation budget.
+impl<F: Float> Default for AdaptiveConfig<F> {
+    fn default() -> Self {
+        AdaptiveConfig::new(
+            F::from(DEFAULT_MIN_H).unwrap(),
+            F::from(DEFAULT_TOLERANCE).unwrap(),
+        )
+    }
+}
+
+impl<F: Float> AdaptiveConfig<F> {
+    /// Configuration with the given `min_h` and `tolerance`, and no evaluation budget.
+    pub fn new(min_h: F, tolerance: F) -> Self {
+        AdaptiveConfig {
+            min_h,
+            tolerance,
+            eval_budget: usize::MAX,
+        }
+    }
+}
+
+/// Result of [`adaptive_simpson_method_with_config`].
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdaptiveOutcome<F: Float> {
+    /// Approximation of the integral, only a best-effort one if `budget_exceeded` is set.
+    pub integral: F,
+    /// Number of evaluations of the integrand.
+    pub evaluations: usize,
+    /// Whether the evaluation budget ran out before the tolerance was met everywhere.
+    pub budget_exceeded: bool,
+}
+
 /// Simpson-Simpson adaptive method
 ///
 /// Integrate, using the Simpson-Simpson adaptive method, the user supplied function $f$ from $a$ to $b$.
 ///
 /// * `func` - Integrand function of a single variable.
 /// * `lower_limit` is the lower limit of integration.
-/// * `upper_limit`  is the upper limit of integration where `upper_limit` > `lower_limit`.
+/// * `upper_limit`  is the upper limit of integration, the sign of the result changes if
+///   `upper_limit` < `lower_limit`.
 /// * `tolerance` is the tolerance.
 /// * `min_h` is the minimum subinterval length to be used.
 ///
@@ -82,6 +149,12 @@ impl fmt::Display for AdaptiveSimpsonError {
 /// };
 ///
 /// ```
+///
+/// # Panics
+///
+/// Panics unless $0 <$ `min_h` $< |b - a|$: a `min_h` of zero or less would let the
+/// subintervals be halved until they vanish, and a `min_h` of at least $b - a$ would fail
+/// before the first estimate.
 pub fn adaptive_simpson_method<Func, F: Float + MulAssign + AddAssign + fmt::Debug>(
     func: Func,
     lower_limit: F,
@@ -92,6 +165,276 @@ pub fn adaptive_simpson_method<Func, F: Float + MulAssign + AddAssign + fmt::Deb
 where
     Func: Fn(F) -> F + Sync + Copy,
 {
+    adaptive_simpson_method_with_progress(func, lower_limit, upper_limit, min_h, tolerance, None)
+}
+
+/// Simpson-Simpson adaptive method reporting its progress.
+///
+/// Same as [`adaptive_simpson_method`], except that each time a subinterval is accepted,
+/// `progress` is called with the fraction of $\[a, b\]$ integrated so far, i.e. the
+/// position of the left end of the remaining interval relative to $\[a, b\]$. The fractions
+/// increase up to $1$, reached when the integration succeeds.
+///
+/// `progress` is only called from the calling thread.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` is the lower limit of integration.
+/// * `upper_limit`  is the upper limit of integration, the sign of the result changes if
+///   `upper_limit` < `lower_limit`.
+/// * `min_h` is the minimum subinterval length to be used.
+/// * `tolerance` is the tolerance.
+/// * `progress` is the optional progress callback.
+///
+/// # Examples
+/// ```
+/// use integrate::adaptive_quadrature::simpson::adaptive_simpson_method_with_progress;
+///
+///
+/// let f = |x: f64| x.exp();
+///
+/// let report = |fraction: f64| println!("{:.0}%", 100.0 * fraction);
+///
+/// let result = adaptive_simpson_method_with_progress(f, 0.0, 1.0, 10.0e-3, 10.0e-6, Some(&report));
+/// ```
+///
+/// # Panics
+///
+/// Panics unless $0 <$ `min_h` $< |b - a|$.
+pub fn adaptive_simpson_method_with_progress<Func, F: Float + MulAssign + AddAssign + fmt::Debug>(
+    func: Func,
+    lower_limit: F,
+    upper_limit: F,
+    min_h: F,
+    tolerance: F,
+    progress: Option<&dyn Fn(f64)>,
+) -> Result<F>
+where
+    Func: Fn(F) -> F + Sync + Copy,
+{
+    let config = AdaptiveConfig::new(min_h, tolerance);
+
+    adaptive_simpson(func, lower_limit, upper_limit, &config, progress)
+        .map(|outcome| outcome.integral)
+}
+
+/// Simpson-Simpson adaptive method with a budget of evaluations.
+///
+/// Same as [`adaptive_simpson_method`], with `min_h` and `tolerance` taken from `config`.
+/// Once `config.eval_budget` evaluations of the integrand are reached, the method stops
+/// and returns a best-effort approximation with `budget_exceeded` set: the accepted
+/// subintervals, plus the composite Simpson's rule on the current subinterval and on the
+/// ones left to its right, from the values of $f$ already computed. The budget is never
+/// exceeded, except by the first estimate, which always takes 5 evaluations.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` is the lower limit of integration.
+/// * `upper_limit`  is the upper limit of integration, the sign of the result changes if
+///   `upper_limit` < `lower_limit`.
+/// * `config` - minimum subinterval length, tolerance and evaluation budget.
+///
+/// # Examples
+/// ```
+/// use integrate::adaptive_quadrature::simpson::{
+///     adaptive_simpson_method_with_config, AdaptiveConfig,
+/// };
+///
+///
+/// let f = |x: f64| x.exp();
+///
+/// let config = AdaptiveConfig {
+///     eval_budget: 1_000,
+///     ..AdaptiveConfig::new(1e-6, 1e-9)
+/// };
+///
+/// let outcome = adaptive_simpson_method_with_config(f, 0.0, 1.0, &config).unwrap();
+///
+/// assert!(!outcome.budget_exceeded);
+/// assert!(outcome.evaluations <= 1_000);
+/// ```
+///
+/// # Panics
+///
+/// Panics unless $0 <$ `config.min_h` $< |b - a|$.
+pub fn adaptive_simpson_method_with_config<Func, F: Float + MulAssign + AddAssign + fmt::Debug>(
+    func: Func,
+    lower_limit: F,
+    upper_limit: F,
+    config: &AdaptiveConfig<F>,
+) -> Result<AdaptiveOutcome<F>>
+where
+    Func: Fn(F) -> F + Sync + Copy,
+{
+    adaptive_simpson(func, lower_limit, upper_limit, config, None)
+}
+
+/// Simpson-Simpson adaptive method with a combined absolute and relative tolerance.
+///
+/// Same as [`adaptive_simpson_method`], the tolerance being [`Tolerance::bound`] of the
+/// integral. Since the integral is not known beforehand, it is first estimated by the
+/// composite Simpson's rule with [`PILOT_INTERVALS`] subintervals. If the bound at the
+/// resulting integral is smaller than at that first estimate, the method is run once more
+/// with the smaller bound. If the first estimate gives a zero bound, as may happen with a
+/// purely relative tolerance, the first run uses $\epsilon_{rel} \int_a^b |f(x)| dx$
+/// instead, estimated from the same nodes, and if $f$ vanishes at all of these nodes, $0$ is
+/// returned.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` is the lower limit of integration.
+/// * `upper_limit`  is the upper limit of integration, the sign of the result changes if
+///   `upper_limit` < `lower_limit`.
+/// * `min_h` is the minimum subinterval length to be used.
+/// * `tolerance` is the combined tolerance.
+///
+/// # Examples
+/// ```
+/// use integrate::adaptive_quadrature::simpson::adaptive_simpson_method_with_tolerance;
+/// use integrate::tolerance::Tolerance;
+///
+///
+/// let f = |x: f64| 1e8 * x.exp();
+///
+/// let tolerance = Tolerance::relative(1e-9);
+/// let integral = adaptive_simpson_method_with_tolerance(f, 0.0, 1.0, 1e-6, &tolerance).unwrap();
+///
+/// let exact = 1e8 * (1f64.exp() - 1.0);
+/// assert!((integral - exact).abs() < 1e-9 * exact);
+/// ```
+///
+/// # Panics
+///
+/// Panics unless $0 <$ `min_h` $< |b - a|$.
+pub fn adaptive_simpson_method_with_tolerance<
+    Func,
+    F: Float + MulAssign + AddAssign + fmt::Debug + Send + Sync,
+>(
+    func: Func,
+    lower_limit: F,
+    upper_limit: F,
+    min_h: F,
+    tolerance: &Tolerance,
+) -> Result<F>
+where
+    Func: Fn(F) -> F + Sync + Copy,
+{
+    let (lower_limit, upper_limit, sign) = oriented(lower_limit, upper_limit);
+    let sign = F::from(sign).unwrap();
+
+    check_min_h(lower_limit, upper_limit, min_h);
+
+    let (estimate, magnitude) =
+        simpson_rule_with_abs(func, lower_limit, upper_limit, PILOT_INTERVALS);
+
+    let mut bound = tolerance.bound(estimate);
+    if bound == 0.0 {
+        // the integral of |f| is zero only if f vanishes at every pilot node
+        if magnitude == 0.0 {
+            return Ok(F::zero());
+        }
+
+        bound = tolerance.rel() * magnitude;
+    }
+
+    let integral = adaptive_simpson_method(
+        func,
+        lower_limit,
+        upper_limit,
+        min_h,
+        F::from(bound).unwrap(),
+    )?;
+
+    let refined_bound = tolerance.bound(integral.to_f64().unwrap());
+    if refined_bound >= bound {
+        return Ok(sign * integral);
+    }
+
+    adaptive_simpson_method(
+        func,
+        lower_limit,
+        upper_limit,
+        min_h,
+        F::from(refined_bound).unwrap(),
+    )
+    .map(|integral| sign * integral)
+}
+
+/// Integrates $f(x)$ from $a$ to $b$ with default settings, returning just the value.
+///
+/// The Simpson-Simpson adaptive method is run with a tolerance of [`DEFAULT_TOLERANCE`] and
+/// a minimum subinterval length of [`DEFAULT_MIN_H`] times $b - a$. If it fails, typically
+/// because of a singularity of $f$, the $1000$ point Gauss-Legendre rule, which never
+/// evaluates $f$ at $a$ nor $b$, is used instead.
+///
+/// The tolerance only holds when the adaptive method succeeds: the accuracy of the fallback
+/// is neither controlled nor estimated, and may be far worse than [`DEFAULT_TOLERANCE`], e.g.
+/// about $10^{-2}$ for $\frac{1}{\sqrt{x}}$ over $\[0, 1\]$, without any sign of it. When
+/// the accuracy matters, use [`adaptive_simpson_method`], which reports the failure.
+///
+/// Equal limits give $0$.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+///
+/// # Examples
+/// ```
+/// use integrate::adaptive_quadrature::simpson::quad_simple;
+///
+///
+/// let integral = quad_simple(|x: f64| x.exp(), 0.0, 1.0);
+///
+/// assert!((integral - (1f64.exp() - 1.0)).abs() < 1e-8);
+/// ```
+///
+/// # Panics
+///
+/// Panics if one of the limits is not finite.
+#[must_use]
+pub fn quad_simple<Func>(func: Func, lower_limit: f64, upper_limit: f64) -> f64
+where
+    Func: Fn(f64) -> f64 + Sync,
+{
+    if !lower_limit.is_finite() || !upper_limit.is_finite() {
+        panic!("Integral limits a and b must be finite");
+    }
+
+    if lower_limit == upper_limit {
+        return 0.0;
+    }
+
+    let (lower_limit, upper_limit, sign) = oriented(lower_limit, upper_limit);
+
+    let config = AdaptiveConfig {
+        min_h: DEFAULT_MIN_H * (upper_limit - lower_limit),
+        ..AdaptiveConfig::default()
+    };
+
+    sign * match adaptive_simpson(&func, lower_limit, upper_limit, &config, None) {
+        Ok(outcome) => outcome.integral,
+        Err(_) => legendre_rule(&func, lower_limit, upper_limit, FALLBACK_POINTS),
+    }
+}
+
+fn adaptive_simpson<Func, F: Float + MulAssign + AddAssign + fmt::Debug>(
+    func: Func,
+    lower_limit: F,
+    upper_limit: F,
+    config: &AdaptiveConfig<F>,
+    progress: Option<&dyn Fn(f64)>,
+) -> Result<AdaptiveOutcome<F>>
+where
+    Func: Fn(F) -> F + Sync + Copy,
+{
+    let AdaptiveConfig {
+        min_h,
+        tolerance,
+        eval_budget,
+    } = *config;
+
+    let (lower_limit, upper_limit, sign) = oriented(lower_limit, upper_limit);
+    let sign = F::from(sign).unwrap();
+
+    check_min_h(lower_limit, upper_limit, min_h);
+
     let two = F::one() + F::one();
 
     let mut integral: F = F::zero();
@@ -100,7 +443,7 @@ where
     // Create the initial level, with lower_limit = a, upper_limit = b,
     // and f(x) evaluated at a, b, and (a + b) / 2.
 
-    let interval: SubInterval<F> = SubInterval {
+    let mut pinterval: SubInterval<F> = SubInterval {
         upper_limit,
         lower_limit,
         function: [
@@ -110,19 +453,20 @@ where
             F::nan(),
             func(upper_limit),
         ],
-        interval: None,
     };
 
-    let mut pinterval = Box::new(interval);
+    // Intervals remaining to the right of the current interval, the
+    // closest one on top of the stack.
+    let mut stack: Vec<SubInterval<F>> = Vec::new();
 
     // Calculate the tolerance for the current interval.
     // calculate the single subinterval Simpson rule,
     // and the two subintervals composite Simpson rule.
 
-    let mut epsilon = epsilon_density * (upper_limit - lower_limit);
-    let (mut s1, mut s2) = simpson_rule_update(func, &mut pinterval);
+    let mut evaluations = 3;
 
-    let mut qinterval: SubInterval<F>;
+    let mut epsilon = epsilon_density * (upper_limit - lower_limit);
+    let (mut s1, mut s2) = simpson_rule_update(func, &mut pinterval, &mut evaluations);
 
     while pinterval.upper_limit - pinterval.lower_limit > min_h {
         if (s1 - s2).abs() < epsilon {
@@ -135,17 +479,28 @@ where
 
             integral += s2;
 
-            if pinterval.interval.is_none() {
-                return Ok(integral);
+            if let Some(progress) = progress {
+                let fraction = (pinterval.upper_limit - lower_limit) / (upper_limit - lower_limit);
+                progress(fraction.to_f64().unwrap());
             }
 
             // Move to the next interval
-            qinterval = *pinterval.interval.take().unwrap();
+            let mut qinterval = match stack.pop() {
+                Some(qinterval) => qinterval,
+                None => {
+                    return Ok(AdaptiveOutcome {
+                        integral: sign * integral,
+                        evaluations,
+                        budget_exceeded: false,
+                    })
+                }
+            };
+
             qinterval.lower_limit = pinterval.upper_limit;
             qinterval.function[0] = qinterval.function[2];
             qinterval.function[2] = qinterval.function[3];
 
-            pinterval = Box::new(qinterval);
+            pinterval = qinterval;
         } else {
             // If the two estimates are not close, then create a new
             // interval with same left end point and right end point
@@ -157,32 +512,73 @@ where
             let upper_limit = if limit1 > limit2 { limit1 } else { limit2 };
             let lower_limit = if limit1 > limit2 { limit2 } else { limit1 };
 
-            qinterval = SubInterval {
+            let mut qinterval = SubInterval {
                 lower_limit,
                 upper_limit,
                 function: [F::nan(); 5],
-                interval: None,
             };
 
             qinterval.function[0] = pinterval.function[0];
             qinterval.function[2] = pinterval.function[1];
             qinterval.function[4] = pinterval.function[2];
 
-            qinterval.interval = Some(pinterval);
+            stack.push(pinterval);
+
+            pinterval = qinterval;
+        }
 
-            pinterval = Box::new(qinterval);
+        if evaluations + 2 > eval_budget {
+            return Ok(AdaptiveOutcome {
+                integral: sign * (integral + best_effort(&pinterval, &stack)),
+                evaluations,
+                budget_exceeded: true,
+            });
         }
 
         // Update Simpson's rule for the new interval
-        (s1, s2) = simpson_rule_update(func, &mut pinterval);
+        (s1, s2) = simpson_rule_update(func, &mut pinterval, &mut evaluations);
         epsilon = epsilon_density * (pinterval.upper_limit - pinterval.lower_limit);
     }
     Err(AdaptiveSimpsonError)
 }
 
+/// Checks that the minimum subinterval length `min_h` lies in $(0, b - a)$.
+fn check_min_h<F: Float>(lower_limit: F, upper_limit: F, min_h: F) {
+    if min_h.is_nan() || min_h <= F::zero() {
+        panic!("min_h must be positive.");
+    }
+
+    if min_h >= upper_limit - lower_limit {
+        panic!("min_h must be less than the length of the integration interval.");
+    }
+}
+
+/// Simpson's rule on the current interval, whose function values at the quarter points
+/// are not computed yet, and on the remaining intervals to its right.
+fn best_effort<F: Float>(pinterval: &SubInterval<F>, stack: &[SubInterval<F>]) -> F {
+    let two = F::one() + F::one();
+    let four = two + two;
+    let six = four + two;
+
+    // the current interval, from its end points and midpoint
+    let f = &pinterval.function;
+    let h = pinterval.upper_limit - pinterval.lower_limit;
+    let current = (f[0] + four * f[2] + f[4]) * h / six;
+
+    // the right halves of the intervals on the stack, each one starting at the midpoint
+    let remaining = stack.iter().fold(F::zero(), |sum, qinterval| {
+        let f = &qinterval.function;
+        let h = (qinterval.upper_limit - qinterval.lower_limit) / two;
+        sum + (f[2] + four * f[3] + f[4]) * h / six
+    });
+
+    current + remaining
+}
+
 fn simpson_rule_update<Func, F: Float + MulAssign + fmt::Debug>(
     func: Func,
     pinterval: &mut SubInterval<F>,
+    evaluations: &mut usize,
 ) -> (F, F)
 where
     Func: Fn(F) -> F + Sync,
@@ -196,6 +592,7 @@ where
 
     pinterval.function[1] = func(pinterval.lower_limit + h4);
     pinterval.function[3] = func(pinterval.upper_limit - h4);
+    *evaluations += 2;
 
     let mut s1 = pinterval.function[0] + four * pinterval.function[2] + pinterval.function[4];
     s1 *= h / six;
@@ -0,0 +1,209 @@
+//! Adaptive Quadrature over a Rectangle
+//!
+//! The two dimensional analogue of the Simpson-Simpson adaptive method integrates a
+//! function $f(x, y)$ over the rectangle $\[a,b\] \times \[c,d\]$.
+//!
+//! On each cell, the tensor product Simpson's rule on the $3 \times 3$ grid of the cell is
+//! compared to the sum of the same rule applied to the four quadrants of the cell, which
+//! uses the $5 \times 5$ grid. If the two estimates are close, relative to the area of the
+//! cell, the finer estimate is accepted, otherwise the cell is split into its four quadrants,
+//! each one being processed in turn, starting from a uniform $8 \times 8$ partition. The
+//! $3 \times 3$ grids of the quadrants are taken from the $5 \times 5$ grid of the cell, so
+//! that no value of $f$ is computed twice for a cell.
+
+use std::fmt;
+
+use num::Float;
+
+/// Number of times every cell of the initial rectangle is split into quadrants before any
+/// of them can be accepted, so that features narrower than the initial cell are sampled.
+const MIN_DEPTH: usize = 3;
+
+/// Number of times a cell of the initial rectangle may be split into quadrants.
+const MAX_DEPTH: usize = 20;
+
+type Result<T> = std::result::Result<T, AdaptiveSimpson2dError>;
+
+#[derive(Debug, Clone)]
+pub struct AdaptiveSimpson2dError;
+
+impl fmt::Display for AdaptiveSimpson2dError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let msg = "A cell was split the maximum number of times without its estimated error falling below the pro-rated tolerance";
+        write!(f, "{}", msg)
+    }
+}
+
+impl std::error::Error for AdaptiveSimpson2dError {}
+
+#[derive(Debug)]
+struct Cell<F: Float> {
+    x: (F, F),
+    y: (F, F),
+    // f on the 3 x 3 grid of the cell, values[i][j] = f(x_i, y_j)
+    values: [[F; 3]; 3],
+    depth: usize,
+}
+
+/// Tensor product Simpson's rule over a cell of area `area`, from the values of $f$ on its
+/// $3 \times 3$ grid.
+fn simpson_3x3<F: Float>(values: &[[F; 3]; 3], area: F) -> F {
+    let weights = [F::one(), F::from(4).unwrap(), F::one()];
+
+    let mut sum = F::zero();
+    for (i, row) in values.iter().enumerate() {
+        for (j, value) in row.iter().enumerate() {
+            sum = sum + weights[i] * weights[j] * *value;
+        }
+    }
+
+    sum * area / F::from(36).unwrap()
+}
+
+/// Simpson-Simpson adaptive method over a rectangle
+///
+/// Integrate, using the two dimensional Simpson-Simpson adaptive method, the user supplied
+/// function $f(x, y)$ over $\[a,b\] \times \[c,d\]$.
+///
+/// * `func` - Integrand function of two variables.
+/// * `x_limits` - limits $(a, b)$ of integration in $x$, where $a < b$.
+/// * `y_limits` - limits $(c, d)$ of integration in $y$, where $c < d$.
+/// * `tolerance` is the tolerance.
+///
+/// A cell is accepted when its coarse and fine estimates differ by less than
+/// $$ 2 * \verb|tolerance| * \frac{\text{area of the cell}}{(b-a)(d-c)}$$
+///
+/// To avoid accepting a cell whose grids miss a narrow feature of $f$ altogether, the
+/// rectangle is first split uniformly into $8 \times 8$ cells.
+///
+/// If a cell still fails this test after being split 20 times, the process is terminated
+/// with an `AdaptiveSimpson2dError` error.
+///
+/// # Examples
+/// ```
+/// use integrate::adaptive_quadrature::simpson_2d::adaptive_simpson_2d;
+///
+///
+/// let f = |x: f64, y: f64| (x * y).exp();
+///
+/// let result = adaptive_simpson_2d(f, (0.0, 1.0), (0.0, 1.0), 1e-10);
+///
+/// assert!((result.unwrap() - 1.317_902_151_454_404).abs() < 1e-9);
+/// ```
+pub fn adaptive_simpson_2d<Func, F: Float>(
+    func: Func,
+    x_limits: (F, F),
+    y_limits: (F, F),
+    tolerance: F,
+) -> Result<F>
+where
+    Func: Fn(F, F) -> F,
+{
+    let two = F::one() + F::one();
+
+    let total_area = (x_limits.1 - x_limits.0) * (y_limits.1 - y_limits.0);
+    let epsilon_density = two * tolerance / total_area;
+
+    let grid = |(x0, x1): (F, F), (y0, y1): (F, F), n: usize| {
+        let step = F::from(n - 1).unwrap();
+        let xs: Vec<F> = (0..n)
+            .map(|i| x0 + (x1 - x0) * F::from(i).unwrap() / step)
+            .collect();
+        let ys: Vec<F> = (0..n)
+            .map(|j| y0 + (y1 - y0) * F::from(j).unwrap() / step)
+            .collect();
+        (xs, ys)
+    };
+
+    let (xs, ys) = grid(x_limits, y_limits, 3);
+    let mut values = [[F::zero(); 3]; 3];
+    for (i, x) in xs.iter().enumerate() {
+        for (j, y) in ys.iter().enumerate() {
+            values[i][j] = func(*x, *y);
+        }
+    }
+
+    let mut stack = vec![Cell {
+        x: x_limits,
+        y: y_limits,
+        values,
+        depth: 0,
+    }];
+
+    let mut integral = F::zero();
+
+    while let Some(cell) = stack.pop() {
+        let area = (cell.x.1 - cell.x.0) * (cell.y.1 - cell.y.0);
+        let coarse = simpson_3x3(&cell.values, area);
+
+        // f on the 5 x 5 grid, reusing the 3 x 3 grid at even indices
+        let (xs, ys) = grid(cell.x, cell.y, 5);
+        let mut fine_values = [[F::zero(); 5]; 5];
+        for (i, x) in xs.iter().enumerate() {
+            for (j, y) in ys.iter().enumerate() {
+                fine_values[i][j] = if i % 2 == 0 && j % 2 == 0 {
+                    cell.values[i / 2][j / 2]
+                } else {
+                    func(*x, *y)
+                };
+            }
+        }
+
+        let quadrant = |qi: usize, qj: usize| {
+            let mut values = [[F::zero(); 3]; 3];
+            for (i, row) in values.iter_mut().enumerate() {
+                for (j, value) in row.iter_mut().enumerate() {
+                    *value = fine_values[2 * qi + i][2 * qj + j];
+                }
+            }
+            values
+        };
+
+        let quadrants = [
+            (0, 0, quadrant(0, 0)),
+            (0, 1, quadrant(0, 1)),
+            (1, 0, quadrant(1, 0)),
+            (1, 1, quadrant(1, 1)),
+        ];
+
+        let fine = quadrants.iter().fold(F::zero(), |sum, (_, _, values)| {
+            sum + simpson_3x3(values, area / (two + two))
+        });
+
+        if cell.depth >= MIN_DEPTH && (coarse - fine).abs() < epsilon_density * area {
+            integral = integral + fine;
+            continue;
+        }
+
+        if cell.depth == MAX_DEPTH {
+            return Err(AdaptiveSimpson2dError);
+        }
+
+        let x_mid = xs[2];
+        let y_mid = ys[2];
+
+        for (qi, qj, values) in quadrants {
+            let x = if qi == 0 {
+                (cell.x.0, x_mid)
+            } else {
+                (x_mid, cell.x.1)
+            };
+            let y = if qj == 0 {
+                (cell.y.0, y_mid)
+            } else {
+                (y_mid, cell.y.1)
+            };
+
+            stack.push(Cell {
+                x,
+                y,
+                values,
+                depth: cell.depth + 1,
+            });
+        }
+    }
+
+    Ok(integral)
+}
+
+// tests in tests/test_adaptive_quadrature.rs
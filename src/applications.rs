@@ -0,0 +1,244 @@
+//! Applications
+//!
+//! Quantities commonly expressed as integrals, computed with any of the rules of
+//! [`Method`].
+//!
+//! The length of the graph of a differentiable function $f$ over $\[a, b\]$ is
+//!
+//! ```math
+//! L = \int_{a}^{b} \sqrt{1 + f^\prime(x)^2} dx
+//! ```
+//!
+//! The integral of $f$ against a histogram, a weight $w$ taking the value $w_k$ on the bin
+//! $\[e_k, e_{k+1})$, is
+//!
+//! ```math
+//! \int_{e_0}^{e_m} f(x) w(x) dx = \sum_{k=0}^{m-1} w_k \int_{e_k}^{e_{k+1}} f(x) dx
+//! ```
+//!
+//! An unnormalized density $f \geq 0$ over $\[a, b\]$ is turned into a probability density
+//! by dividing it by its normalization constant
+//!
+//! ```math
+//! Z = \int_{a}^{b} f(x) dx
+//! ```
+
+use num::Float;
+
+use crate::error::QuadError;
+use crate::method::Method;
+
+/// Computes the length of the graph of $f$ over $\[a, b\]$ from its derivative $f^\prime$.
+///
+/// * `df` - derivative $f^\prime$ of the function, analytic or numerical.
+/// * `lower_limit` - lower limit of the interval.
+/// * `upper_limit` - upper limit of the interval.
+/// * `n` - number of subintervals, or number of points for Gauss-Legendre.
+/// * `rule` - integration method applied to $\sqrt{1 + f^\prime(x)^2}$.
+///
+/// # Examples
+/// ```
+/// use integrate::applications::arc_length;
+/// use integrate::method::Method;
+///
+///
+/// // the graph of f(x) = x² / 2 over [0, 1]
+/// let df = |x: f64| x;
+///
+/// let length = arc_length(df, 0.0, 1.0, 100, Method::Simpson);
+///
+/// let exact = (2f64.sqrt() + (1.0 + 2f64.sqrt()).ln()) / 2.0;
+/// assert!((length - exact).abs() < 1e-10);
+/// ```
+#[must_use]
+pub fn arc_length<DFunc, F: Float + Sync + Send>(
+    df: DFunc,
+    lower_limit: F,
+    upper_limit: F,
+    n: usize,
+    rule: Method,
+) -> f64
+where
+    DFunc: Fn(F) -> F + Sync,
+{
+    let integrand = |x: F| {
+        let slope = df(x);
+        (F::one() + slope * slope).sqrt()
+    };
+
+    rule.integrate(integrand, lower_limit, upper_limit, n)
+}
+
+/// Integrates $f$ against the piecewise-constant weight described by a histogram.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `edges` - strictly increasing bin edges $e_0, \dots, e_m$.
+/// * `bin_values` - value $w_k$ of the weight on the bin $\[e_k, e_{k+1})$.
+/// * `n_per_bin` - number of subintervals, or number of points for Gauss-Legendre, in each bin.
+/// * `rule` - integration method applied to $f$ on each bin.
+///
+/// Each bin is integrated separately, so that the discontinuities of the weight fall on
+/// the limits of integration rather than inside them.
+///
+/// # Examples
+/// ```
+/// use integrate::applications::integrate_against_histogram;
+/// use integrate::method::Method;
+///
+///
+/// let f = |x: f64| x;
+///
+/// let integral = integrate_against_histogram(f, &[0.0, 1.0, 2.0], &[1.0, 3.0], 10, Method::Simpson);
+///
+/// // 1 * 1/2 + 3 * 3/2
+/// assert!((integral - 5.0).abs() < 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `edges` does not have one more element than `bin_values`, if there is no bin,
+/// or if `edges` is not strictly increasing.
+#[must_use]
+pub fn integrate_against_histogram<Func>(
+    func: Func,
+    edges: &[f64],
+    bin_values: &[f64],
+    n_per_bin: usize,
+    rule: Method,
+) -> f64
+where
+    Func: Fn(f64) -> f64 + Sync,
+{
+    if edges.len() != bin_values.len() + 1 {
+        panic!(
+            "edges must have one more element than bin_values, got {} and {}.",
+            edges.len(),
+            bin_values.len()
+        );
+    }
+
+    if bin_values.is_empty() {
+        panic!("at least one bin is required.");
+    }
+
+    if edges.windows(2).any(|w| w[0] >= w[1]) {
+        panic!("edges must be strictly increasing.");
+    }
+
+    edges
+        .windows(2)
+        .zip(bin_values)
+        .map(|(edge, &value)| value * rule.integrate(&func, edge[0], edge[1], n_per_bin))
+        .sum()
+}
+
+/// Computes the normalization constant $Z$ of an unnormalized density $f$ over $\[a, b\]$,
+/// and returns it along with the normalized density $x \mapsto \frac{f(x)}{Z}$.
+///
+/// * `func` - unnormalized density.
+/// * `lower_limit` - lower limit of the interval.
+/// * `upper_limit` - upper limit of the interval.
+/// * `n` - number of subintervals, or number of points for Gauss-Legendre.
+/// * `rule` - integration method used to compute $Z$.
+///
+/// # Examples
+/// ```
+/// use integrate::applications::normalize;
+/// use integrate::method::Method;
+///
+///
+/// let (z, density) = normalize(|x: f64| x, 0.0, 2.0, 10, Method::Simpson).unwrap();
+///
+/// assert!((z - 2.0).abs() < 1e-14);
+/// assert!((density(1.0) - 0.5).abs() < 1e-14);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`QuadError::NonPositiveIntegral`] if $Z$ is zero, negative or `NaN`.
+pub fn normalize<Func>(
+    func: Func,
+    lower_limit: f64,
+    upper_limit: f64,
+    n: usize,
+    rule: Method,
+) -> Result<(f64, impl Fn(f64) -> f64), QuadError>
+where
+    Func: Fn(f64) -> f64 + Sync,
+{
+    let z = rule.integrate(&func, lower_limit, upper_limit, n);
+
+    if z.is_nan() || z <= 0.0 {
+        return Err(QuadError::NonPositiveIntegral);
+    }
+
+    Ok((z, move |x: f64| func(x) / z))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_1_SQRT_2, PI};
+
+    use super::*;
+
+    #[test]
+    fn test_line() {
+        for rule in Method::ALL {
+            let length = arc_length(|_: f64| 1.0, 0.0, 1.0, 10, rule);
+
+            assert!((length - 2f64.sqrt()).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_semicircle() {
+        // upper unit half circle y = √(1 - x²), between the angles π/4 and 3π/4
+        let df = |x: f64| -x / (1.0 - x * x).sqrt();
+
+        let length = arc_length(df, -FRAC_1_SQRT_2, FRAC_1_SQRT_2, 20, Method::GaussLegendre);
+        assert!((length - PI / 2.0).abs() < 1e-10);
+
+        let length = arc_length(df, -FRAC_1_SQRT_2, FRAC_1_SQRT_2, 1_000, Method::Simpson);
+        assert!((length - PI / 2.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_integrate_against_histogram() {
+        let edges = [-1.0, 0.5, 2.0];
+        let bin_values = [2.0, 0.25];
+
+        for rule in Method::ALL {
+            let integral = integrate_against_histogram(|_| 1.0, &edges, &bin_values, 4, rule);
+
+            // 2 * 1.5 + 0.25 * 1.5
+            assert!((integral - 3.375).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_histogram_length_mismatch() {
+        let _ = integrate_against_histogram(|x| x, &[0.0, 1.0], &[1.0, 2.0], 4, Method::Simpson);
+    }
+
+    #[test]
+    fn test_normalize_gaussian() {
+        let f = |x: f64| (-x * x / 2.0).exp();
+
+        let (z, density) = normalize(f, -20.0, 20.0, 1_000, Method::Simpson).unwrap();
+        assert!((z - (2.0 * PI).sqrt()).abs() < 1e-12);
+
+        let total = Method::Simpson.integrate(&density, -20.0, 20.0, 1_000);
+        assert!((total - 1.0).abs() < 1e-12);
+
+        // standard normal density at 0
+        assert!((density(0.0) - 1.0 / (2.0 * PI).sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_normalize_non_positive() {
+        assert!(normalize(|_: f64| -1.0, 0.0, 1.0, 10, Method::Simpson).is_err());
+        assert!(normalize(|_: f64| 0.0, 0.0, 1.0, 10, Method::Simpson).is_err());
+        assert!(normalize(|_: f64| f64::NAN, 0.0, 1.0, 10, Method::Simpson).is_err());
+    }
+}
@@ -0,0 +1,442 @@
+//! Convergence analysis
+//!
+//! For a rule of order $p$, the error made when integrating $f(x)$ over $\[a,b\]$ with
+//! $n$ subintervals behaves like
+//!
+//! ```math
+//! E(n) \approx C n^{-p}
+//! ```
+//!
+//! for some constant $C$ depending on $f$, $a$ and $b$. Comparing the results $I_n$ and
+//! $I_{2n}$ of the rule with $n$ and $2n$ subintervals gives an estimate of $E(n)$
+//!
+//! ```math
+//! E(n) \approx \frac{2^p}{2^p - 1} \left| I_n - I_{2n} \right|
+//! ```
+//!
+//! from which $C$, and the number of subintervals needed to reach a given accuracy,
+//! can be deduced. [`estimate_n_for`] targets a combined absolute and relative
+//! [`Tolerance`] instead of an absolute one, and [`fit_error_constant`] returns $C$ along
+//! with the extrapolated value of the integral.
+//!
+//! Conversely, the results $I_h$, $I_{h/2}$ and $I_{h/4}$ of a rule with $n$, $2n$ and $4n$
+//! subintervals reveal its observed order, see [`estimate_order`]
+//!
+//! ```math
+//! p \approx \log_2 \frac{I_h - I_{h/2}}{I_{h/2} - I_{h/4}}
+//! ```
+//!
+//! When the exact value of the integral is known, [`integrate_and_report`] measures the
+//! actual absolute and relative errors of a rule instead.
+
+use num::Float;
+
+use crate::method::Method;
+use crate::tolerance::Tolerance;
+
+/// Number of subintervals used by the pilot runs.
+const PILOT_N: usize = 8;
+
+/// Estimates the number of subintervals for which `rule` integrates $f(x)$ from $a$ to $b$
+/// with an error below `tolerance`.
+///
+/// The rule is run with $n$ and $2n$ subintervals, the error constant $C$ is estimated
+/// from the difference of both results and the rule's order $p$, then
+/// $C n^{-p} = \verb|tolerance|$ is solved for $n$.
+///
+/// * `rule` - integration method, must have an algebraic order (see [`Method::order`]).
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `tolerance` - target absolute error, must be positive.
+///
+/// The estimate relies on the asymptotic behavior of the error, it is only as good as
+/// $f$ is smooth enough for the rule to reach its order.
+///
+/// # Examples
+/// ```
+/// use integrate::convergence::estimate_n_for_tolerance;
+/// use integrate::method::Method;
+///
+///
+/// let f = |x: f64| x.exp();
+///
+/// let n = estimate_n_for_tolerance(Method::Simpson, f, 0.0, 1.0, 10e-10);
+///
+/// let integral = Method::Simpson.integrate(f, 0.0, 1.0, n);
+/// ```
+#[must_use]
+pub fn estimate_n_for_tolerance<Func, F1: Float + Sync, F2: Float + Send + Sync>(
+    rule: Method,
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    tolerance: f64,
+) -> usize
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    if tolerance <= 0.0 || tolerance.is_nan() {
+        panic!("tolerance must be positive");
+    }
+
+    estimate_n_for(
+        rule,
+        func,
+        lower_limit,
+        upper_limit,
+        &Tolerance::absolute(tolerance),
+    )
+}
+
+/// Estimates the number of subintervals for which `rule` integrates $f(x)$ from $a$ to $b$
+/// within a combined absolute and relative tolerance.
+///
+/// Same as [`estimate_n_for_tolerance`], the target error being
+/// [`Tolerance::bound`] of the result of the pilot run with $2n$ subintervals.
+///
+/// * `rule` - integration method, must have an algebraic order (see [`Method::order`]).
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `tolerance` - target error.
+///
+/// # Examples
+/// ```
+/// use integrate::convergence::estimate_n_for;
+/// use integrate::method::Method;
+/// use integrate::tolerance::Tolerance;
+///
+///
+/// let f = |x: f64| 1e6 * x.exp();
+///
+/// let n = estimate_n_for(Method::Simpson, f, 0.0, 1.0, &Tolerance::relative(1e-10));
+///
+/// let integral = Method::Simpson.integrate(f, 0.0, 1.0, n);
+/// assert!((integral - 1e6 * (1f64.exp() - 1.0)).abs() < 1e-10 * integral);
+/// ```
+#[must_use]
+pub fn estimate_n_for<Func, F1: Float + Sync, F2: Float + Send + Sync>(
+    rule: Method,
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    tolerance: &Tolerance,
+) -> usize
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    let p = rule
+        .order()
+        .expect("rule has no algebraic order of convergence");
+
+    let i_n = rule.integrate(&func, lower_limit, upper_limit, PILOT_N);
+    let i_2n = rule.integrate(&func, lower_limit, upper_limit, 2 * PILOT_N);
+
+    // estimated error of the pilot run with n subintervals
+    let pow_2_p = 2.0.powi(p);
+    let error = pow_2_p / (pow_2_p - 1.0) * (i_n - i_2n).abs();
+
+    // solving C n^-p = tolerance, where C = error * n^p
+    let n = PILOT_N as f64 * (error / tolerance.bound(i_2n)).powf(1.0 / p as f64);
+
+    n.ceil().max(1.0) as usize
+}
+
+/// Result of a rule compared to the exact value of the integral, see [`integrate_and_report`].
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuadReport {
+    /// Approximation computed by the rule.
+    pub integral: f64,
+    /// Exact value of the integral.
+    pub exact: f64,
+    /// $|I - I_{exact}|$.
+    pub absolute_error: f64,
+    /// $\frac{|I - I_{exact}|}{|I_{exact}|}$, see [`relative_error`].
+    pub relative_error: f64,
+}
+
+/// Returns the relative error $\frac{|I - I_{exact}|}{|I_{exact}|}$ of `computed` with
+/// respect to `exact`.
+///
+/// The relative error is undefined for a zero exact value, the absolute error
+/// $|I - I_{exact}|$ is returned in that case.
+///
+/// * `computed` - approximation of the integral.
+/// * `exact` - exact value of the integral.
+///
+/// # Examples
+/// ```
+/// use integrate::convergence::relative_error;
+///
+/// assert!((relative_error(1.01, 1.0) - 0.01).abs() < 1e-12);
+/// assert_eq!(relative_error(0.5, 0.0), 0.5);
+/// ```
+#[must_use]
+pub fn relative_error(computed: f64, exact: f64) -> f64 {
+    let absolute_error = (computed - exact).abs();
+
+    if exact == 0.0 {
+        absolute_error
+    } else {
+        absolute_error / exact.abs()
+    }
+}
+
+/// Integrates $f(x)$ from $a$ to $b$ with `rule` and `n` steps, and reports the
+/// absolute and relative errors with respect to `exact`.
+///
+/// * `rule` - integration method.
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n` - number of subintervals, or number of points for Gauss-Legendre.
+/// * `exact` - exact value of the integral.
+///
+/// # Examples
+/// ```
+/// use integrate::convergence::integrate_and_report;
+/// use integrate::method::Method;
+///
+///
+/// let report = integrate_and_report(Method::Trapezoidal, |x: f64| x.exp(), 0.0, 1.0, 100, 1f64.exp() - 1.0);
+///
+/// println!("{} ({:e} relative error)", report.integral, report.relative_error);
+/// ```
+pub fn integrate_and_report<Func, F1: Float + Sync, F2: Float + Send + Sync>(
+    rule: Method,
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    n: usize,
+    exact: f64,
+) -> QuadReport
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    let integral = rule.integrate(func, lower_limit, upper_limit, n);
+
+    QuadReport {
+        integral,
+        exact,
+        absolute_error: (integral - exact).abs(),
+        relative_error: relative_error(integral, exact),
+    }
+}
+
+/// Estimates the order $p$ of a rule from its results with subintervals of length $h$,
+/// $\frac{h}{2}$ and $\frac{h}{4}$, as $\log_2 \frac{I_h - I_{h/2}}{I_{h/2} - I_{h/4}}$.
+///
+/// * `i_h` - result with subintervals of length $h$.
+/// * `i_h2` - result with subintervals of length $\frac{h}{2}$.
+/// * `i_h4` - result with subintervals of length $\frac{h}{4}$.
+///
+/// The estimate is `NaN` when both differences have opposite signs, i.e. when the results
+/// are not yet in the asymptotic regime, or are dominated by rounding errors.
+///
+/// # Examples
+/// ```
+/// use integrate::convergence::estimate_order;
+///
+/// // errors 1/16, 1/64, 1/256
+/// let p = estimate_order(1.0 + 1.0 / 16.0, 1.0 + 1.0 / 64.0, 1.0 + 1.0 / 256.0);
+///
+/// assert!((p - 2.0).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn estimate_order(i_h: f64, i_h2: f64, i_h4: f64) -> f64 {
+    ((i_h - i_h2) / (i_h2 - i_h4)).log2()
+}
+
+/// Estimates the observed order of `rule` on $f(x)$ over $\[a,b\]$, running it with
+/// `base_n`, $2$ `base_n` and $4$ `base_n` subintervals, see [`estimate_order`].
+///
+/// * `rule` - integration method.
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `base_n` - number of subintervals of the coarsest run.
+///
+/// # Examples
+/// ```
+/// use integrate::convergence::estimate_order_of_rule;
+/// use integrate::method::Method;
+///
+///
+/// let p = estimate_order_of_rule(Method::Trapezoidal, |x: f64| x.exp(), 0.0, 1.0, 8);
+///
+/// assert!((p - 2.0).abs() < 0.01);
+/// ```
+#[must_use]
+pub fn estimate_order_of_rule<Func, F1: Float + Sync, F2: Float + Send + Sync>(
+    rule: Method,
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    base_n: usize,
+) -> f64
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    let i_h = rule.integrate(&func, lower_limit, upper_limit, base_n);
+    let i_h2 = rule.integrate(&func, lower_limit, upper_limit, 2 * base_n);
+    let i_h4 = rule.integrate(&func, lower_limit, upper_limit, 4 * base_n);
+
+    estimate_order(i_h, i_h2, i_h4)
+}
+
+/// Runs `rule` on $f(x)$ over $\[a,b\]$ with $n$ and $2n$ subintervals, and fits the error
+/// model $I_n - I \approx C h^p$, $h = \frac{b-a}{n}$, to both results.
+///
+/// With $p$ the order of the rule, the two runs determine both unknowns
+///
+/// ```math
+/// I \approx \frac{2^p I_{2n} - I_n}{2^p - 1}, \quad C \approx \frac{I_n - I_{2n}}{(1 - 2^{-p}) h^p}
+/// ```
+///
+/// the first one being Richardson's extrapolation. $C$ then predicts the error of the rule
+/// for any other number of subintervals, as long as $f$ is smooth enough for the rule to
+/// reach its order.
+///
+/// * `rule` - integration method, must have an algebraic order (see [`Method::order`]).
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n` - number of subintervals of the coarser run.
+///
+/// Returns the extrapolated value and $C$.
+///
+/// # Examples
+/// ```
+/// use integrate::convergence::fit_error_constant;
+/// use integrate::method::Method;
+///
+///
+/// let (extrapolated, c) = fit_error_constant(Method::Trapezoidal, |x: f64| x * x, 0.0, 1.0, 4);
+///
+/// // the trapezoidal error on x² is exactly h² / 6
+/// assert!((extrapolated - 1.0 / 3.0).abs() < 1e-14);
+/// assert!((c - 1.0 / 6.0).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn fit_error_constant<Func, F1: Float + Sync, F2: Float + Send + Sync>(
+    rule: Method,
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    n: usize,
+) -> (f64, f64)
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    let p = rule
+        .order()
+        .expect("rule has no algebraic order of convergence");
+
+    let i_n = rule.integrate(&func, lower_limit, upper_limit, n);
+    let i_2n = rule.integrate(&func, lower_limit, upper_limit, 2 * n);
+
+    let pow_2_p = 2.0.powi(p);
+    let extrapolated = (pow_2_p * i_2n - i_n) / (pow_2_p - 1.0);
+
+    let h = (upper_limit - lower_limit).to_f64().unwrap() / n as f64;
+    let c = (i_n - i_2n) / ((1.0 - 1.0 / pow_2_p) * h.powi(p));
+
+    (extrapolated, c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_n_for_combined_tolerance() {
+        let f = |x: f64| 1e8 * x.exp();
+        let exact = 1e8 * (1.0.exp() - 1.0);
+
+        let n_absolute = estimate_n_for(Method::Simpson, f, 0.0, 1.0, &Tolerance::absolute(1e-3));
+        let integral = Method::Simpson.integrate(f, 0.0, 1.0, n_absolute);
+        assert!((integral - exact).abs() < 1e-3);
+
+        let n_relative = estimate_n_for(Method::Simpson, f, 0.0, 1.0, &Tolerance::relative(1e-8));
+        let integral = Method::Simpson.integrate(f, 0.0, 1.0, n_relative);
+        assert!((integral - exact).abs() < 1e-8 * exact);
+
+        // 1e-8 relative is about 1.7 absolute here
+        assert!(n_relative < n_absolute);
+    }
+
+    #[test]
+    fn test_estimate_n_for_tolerance() {
+        let f = |x: f64| x.exp();
+        let exact = 1.0.exp() - 1.0;
+
+        for tolerance in [10e-6, 10e-8, 10e-10] {
+            let n = estimate_n_for_tolerance(Method::Simpson, f, 0.0, 1.0, tolerance);
+
+            let integral = Method::Simpson.integrate(f, 0.0, 1.0, n);
+
+            assert!((integral - exact).abs() < tolerance);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_estimate_n_for_gauss_legendre() {
+        let f = |x: f64| x.exp();
+
+        let _ = estimate_n_for_tolerance(Method::GaussLegendre, f, 0.0, 1.0, 10e-10);
+    }
+
+    #[test]
+    fn test_estimate_order_of_rule() {
+        let f = |x: f64| x.exp();
+
+        for rule in [
+            Method::Rectangle,
+            Method::Trapezoidal,
+            Method::Simpson,
+            Method::Newton,
+        ] {
+            let p = estimate_order_of_rule(rule, f, 0.0, 1.0, 4);
+            let expected = rule.order().unwrap() as f64;
+
+            assert!((p - expected).abs() < 0.05, "{} has order {}", rule, p);
+        }
+    }
+
+    #[test]
+    fn test_integrate_and_report() {
+        let report =
+            integrate_and_report(Method::Simpson, |x: f64| x * x, 0.0, 1.0, 1000, 1.0 / 3.0);
+
+        assert!(report.relative_error < 1e-6);
+        assert_eq!(report.absolute_error, (report.integral - 1.0 / 3.0).abs());
+        assert!((report.relative_error - 3.0 * report.absolute_error).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_fit_error_constant() {
+        let f = |x: f64| x.exp();
+        let exact = 1.0.exp() - 1.0;
+
+        let (extrapolated, c) = fit_error_constant(Method::Simpson, f, 0.0, 1.0, 8);
+        assert!((extrapolated - exact).abs() < 1e-9);
+
+        // Simpson's error with n subintervals is (e - 1) h⁴ / 2880
+        assert!((c - exact / 2880.0).abs() < 1e-3 * c);
+
+        // the fitted constant predicts the error with 40 subintervals
+        let h: f64 = 1.0 / 40.0;
+        let predicted = c * h.powi(4);
+        let actual = Method::Simpson.integrate(f, 0.0, 1.0, 40) - exact;
+        assert!((predicted - actual).abs() < 0.01 * actual.abs());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fit_error_constant_without_order() {
+        let _ = fit_error_constant(Method::GaussLegendre, |x: f64| x, 0.0, 1.0, 4);
+    }
+}
@@ -0,0 +1,76 @@
+//! Errors
+//!
+//! [`QuadError`] gathers the errors reported by the checked variants of the rules of
+//! this crate. In particular, the `try_` variants of the Newton-Cotes rules, such as
+//! [`try_trapezoidal_rule`](crate::newton_cotes::trapezoidal::try_trapezoidal_rule), report
+//! the arguments their panicking counterparts reject as [`QuadError::ZeroSteps`] and
+//! [`QuadError::InfiniteLimit`], rather than through an error type of their own, and
+//! report `NaN` limits as [`QuadError::NanLimit`].
+
+use std::fmt;
+
+/// Error returned by the checked integration functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuadError {
+    /// The integrand returned the same value at every sampled node, which usually
+    /// means that it ignores its argument.
+    ConstantIntegrand,
+    /// The length $h = \frac{b-a}{n}$ of the subintervals underflowed to zero, so that
+    /// all the nodes collapse onto the lower limit.
+    StepUnderflow,
+    /// The number of subintervals is not a multiple of the given number, as required by
+    /// the rule.
+    StepsNotMultiple(usize),
+    /// The number of points is even or less than 3, while the rule needs an odd number of
+    /// points, at least 3.
+    InvalidPointCount,
+    /// The integral of a density is zero, negative or `NaN`, so that it can't be
+    /// normalized.
+    NonPositiveIntegral,
+    /// The length $b - a$ of the integration interval overflows to infinity, e.g. for
+    /// limits of opposite signs near `f64::MAX`.
+    IntervalOverflow,
+    /// The number of subintervals is zero.
+    ZeroSteps,
+    /// One of the limits of integration is infinite, while the rule needs a bounded
+    /// interval.
+    InfiniteLimit,
+    /// One of the limits of integration is `NaN`.
+    NanLimit,
+}
+
+impl fmt::Display for QuadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            QuadError::ConstantIntegrand => write!(
+                f,
+                "the integrand returned the same value at every sampled node, check that it uses its argument"
+            ),
+            QuadError::StepUnderflow => write!(
+                f,
+                "the subinterval length underflowed to zero, use fewer subintervals"
+            ),
+            QuadError::StepsNotMultiple(k) => write!(
+                f,
+                "the number of subintervals must be a multiple of {}",
+                k
+            ),
+            QuadError::InvalidPointCount => {
+                write!(f, "the number of points must be odd and at least 3")
+            }
+            QuadError::NonPositiveIntegral => write!(
+                f,
+                "the integral is not positive, the function can't be normalized into a density"
+            ),
+            QuadError::IntervalOverflow => write!(
+                f,
+                "the length of the integration interval overflows, split it into smaller intervals"
+            ),
+            QuadError::ZeroSteps => write!(f, "number of steps can't be zero"),
+            QuadError::InfiniteLimit => write!(f, "Integral limits a and b can't be infinite"),
+            QuadError::NanLimit => write!(f, "Integral limits a and b can't be NaN"),
+        }
+    }
+}
+
+impl std::error::Error for QuadError {}
@@ -23,7 +23,7 @@
 //! The $n$-point Gauss-Chebyshev quadrature formula, $GC_n(f(x))$, for approximating the integral of $\frac{f(x)}{\sqrt{1 - x^2}}$ over $\[-1, 1\]$,
 //! is given by
 //! $$ GC_n ( f(x) ) = A_1 f(x_1) + ··· + A_n f(x_n) $$
-//! where $x_i$ , $i = 1,...,n$, are the zeros of $T_n$ and $A_i = \frac{\pi}{n}$, $i = 1,...,n$.
+//! where $x_i$, $i = 1,...,n$, are the zeros of $T_n$ and $A_i = \frac{\pi}{n}$, $i = 1,...,n$.
 //!
 //!
 //!
@@ -46,20 +46,30 @@
 //! The $n$-point Gauss-Chebyshev quadrature formula, $GC_n(f(x))$, for approximating the integral of $f(x) * \sqrt{1 - x^2}$ over $\[-1, 1\]$,
 //! is given by
 //! $$ GC_n ( f(x) ) = A_1 f(x_1) + ··· + A_n f(x_n) $$
-//! where $x_i$ , $i = 1,...,n$, are the zeros of $U_n$ and
+//! where $x_i$, $i = 1,...,n$, are the zeros of $U_n$ and
 //! $$A_i = \frac{\pi}{n + 1} * \sin^2(\frac{i*\pi}{n + 1} ) \quad \text{for} \quad i = 1,...,n.$$
-use std::iter::Sum;
+//!
+//! # Chebyshev series
+//!
+//! A function represented by a truncated Chebyshev series $f(x) = \sum_{j=0}^{m} c_j T_j(x)$
+//! is integrated over $\[-1, 1\]$ in closed form: $T_j$ integrates to $0$ for odd $j$ and
+//! ```math
+//! \int_{-1}^{1} T_{2k}(x) dx = \frac{-2}{4k^2 - 1}
+//! ```
+//! so that only the even-index coefficients contribute.
 use std::{f64::consts::PI, marker::PhantomData};
 
 use std::fmt::Debug;
-use std::ops::AddAssign;
 
 use num::{one, Float, Zero};
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
 
 use crate::utils::orthogonal_polynomials::OrthogonalPolynomial;
 
-use super::utils::check_gauss_rule_args;
+use super::utils::{check_gauss_rule_args, validate_gauss_order, GaussError, WarnOnce};
+
+static FIRST_KIND_UNDERFLOW_WARNING: WarnOnce = WarnOnce::new();
+static SECOND_KIND_UNDERFLOW_WARNING: WarnOnce = WarnOnce::new();
 
 #[derive(Clone, Debug)]
 struct ChebyshevFirstKind<F: Float> {
@@ -73,9 +83,7 @@ struct ChebyshevSecondKind<F: Float> {
     _x: PhantomData<F>,
 }
 
-fn roots_first_kind_chebyshev<F: Float + Debug + Sync + Send + AddAssign>(
-    n: usize,
-) -> (Vec<F>, Vec<F>) {
+fn roots_first_kind_chebyshev<F: Float + Debug + Sync + Send>(n: usize) -> (Vec<F>, Vec<F>) {
     let t_n: ChebyshevFirstKind<F> = ChebyshevFirstKind::new(n);
     let zeros = t_n.zeros();
 
@@ -91,25 +99,24 @@ fn roots_first_kind_chebyshev<F: Float + Debug + Sync + Send + AddAssign>(
         .any(|(zero, weight)| (*zero).is_nan() || (*weight).is_nan());
 
     if warn {
-        eprintln!(
-        "Warning: `n` chosen is too big, some values of Chebyshev First Kind Polynomials weights or zeros are too small and may underflow!"
-    );
+        FIRST_KIND_UNDERFLOW_WARNING.warn(
+            "Warning: `n` chosen is too big, some values of Chebyshev First Kind Polynomials weights or zeros are too small and may underflow!",
+        );
     }
 
     (zeros, weights)
 }
 
-fn roots_second_kind_chebyshev<F: Float + Debug + Sync + Send + AddAssign>(
-    n: usize,
-) -> (Vec<F>, Vec<F>) {
+fn roots_second_kind_chebyshev<F: Float + Debug + Sync + Send>(n: usize) -> (Vec<F>, Vec<F>) {
     let u_n: ChebyshevSecondKind<F> = ChebyshevSecondKind::new(n);
     let zeros = u_n.zeros();
 
     let n = F::from(u_n.degree).unwrap();
     let pi = F::from(PI).unwrap();
 
-    let weights: Vec<F> = (1..=u_n.degree)
+    let weights: Vec<F> = (1..u_n.degree + 1)
         .into_par_iter()
+        .rev()
         .map(|i| {
             let i = F::from(i).unwrap();
 
@@ -132,8 +139,8 @@ fn roots_second_kind_chebyshev<F: Float + Debug + Sync + Send + AddAssign>(
         .any(|(zero, weight)| (*zero).is_nan() || (*weight).is_nan());
 
     if warn {
-        eprintln!(
-            "Warning: `n` chosen is too big, some values of Chebyshev Second Kind Polynomials weights or zeros are too small and may underflow!"
+        SECOND_KIND_UNDERFLOW_WARNING.warn(
+            "Warning: `n` chosen is too big, some values of Chebyshev Second Kind Polynomials weights or zeros are too small and may underflow!",
         );
     }
 
@@ -146,6 +153,9 @@ fn roots_second_kind_chebyshev<F: Float + Debug + Sync + Send + AddAssign>(
 /// * `func` - Integrand function of a single variable.
 /// * `n` -  order, number of points used in the rule.
 ///
+/// The integrand may return a float type `F2` other than the node type `F`, its values
+/// are then converted to `F`.
+///
 /// # Examples
 /// ```
 /// use integrate::gauss_quadrature::chebyshev::gauss_first_kind_chebyshev_rule;
@@ -155,12 +165,13 @@ fn roots_second_kind_chebyshev<F: Float + Debug + Sync + Send + AddAssign>(
 /// let n:usize = 100;
 ///
 /// let integral = gauss_first_kind_chebyshev_rule(f, n);
-pub fn gauss_first_kind_chebyshev_rule<Func, F: Float + Debug + Sync + Send + AddAssign + Sum>(
+#[must_use]
+pub fn gauss_first_kind_chebyshev_rule<Func, F: Float + Debug + Sync + Send, F2: Float>(
     func: Func,
     n: usize,
 ) -> F
 where
-    Func: Fn(F) -> F + Sync,
+    Func: Fn(F) -> F2 + Sync,
 {
     check_gauss_rule_args(n);
 
@@ -169,8 +180,34 @@ where
     weights
         .into_par_iter()
         .zip(zeros)
-        .map(|(w, x)| w * func(x))
-        .sum()
+        .map(|(w, x)| w * F::from(func(x)).unwrap())
+        .reduce(F::zero, |sum, term| sum + term)
+}
+
+/// Same as [`gauss_first_kind_chebyshev_rule`], but returns an error instead of panicking when `n` is zero.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `n` -  order, number of points used in the rule.
+///
+/// # Examples
+/// ```
+/// use integrate::gauss_quadrature::chebyshev::try_gauss_first_kind_chebyshev_rule;
+///
+/// let f = |x: f64| 1.0;
+///
+/// assert!(try_gauss_first_kind_chebyshev_rule(f, 0).is_err());
+/// assert!(try_gauss_first_kind_chebyshev_rule(f, 10).is_ok());
+/// ```
+pub fn try_gauss_first_kind_chebyshev_rule<Func, F: Float + Debug + Sync + Send, F2: Float>(
+    func: Func,
+    n: usize,
+) -> Result<F, GaussError>
+where
+    Func: Fn(F) -> F2 + Sync,
+{
+    validate_gauss_order(n)?;
+
+    Ok(gauss_first_kind_chebyshev_rule(func, n))
 }
 
 /// Approximate the integral of $f(x) * \sqrt{1 - x^2}$ from -1 to 1
@@ -179,6 +216,9 @@ where
 /// * `func` - Integrand function of a single variable.
 /// * `n` -  order, number of points used in the rule.
 ///
+/// The integrand may return a float type `F2` other than the node type `F`, its values
+/// are then converted to `F`.
+///
 /// # Examples
 /// ```
 /// use integrate::gauss_quadrature::chebyshev::gauss_second_kind_chebyshev_rule;
@@ -190,12 +230,13 @@ where
 /// let n:usize = 100;
 ///
 /// let integral = gauss_second_kind_chebyshev_rule(f, n);
-pub fn gauss_second_kind_chebyshev_rule<Func, F: Float + Debug + Sync + Send + AddAssign + Sum>(
+#[must_use]
+pub fn gauss_second_kind_chebyshev_rule<Func, F: Float + Debug + Sync + Send, F2: Float>(
     f: Func,
     n: usize,
 ) -> F
 where
-    Func: Fn(F) -> F + Sync,
+    Func: Fn(F) -> F2 + Sync,
 {
     check_gauss_rule_args(n);
 
@@ -204,11 +245,67 @@ where
     weights
         .into_par_iter()
         .zip(zeros)
-        .map(|(w, x)| w * f(x))
-        .sum()
+        .map(|(w, x)| w * F::from(f(x)).unwrap())
+        .reduce(F::zero, |sum, term| sum + term)
 }
 
-impl<F: Float + Debug + AddAssign + Send + Sync> OrthogonalPolynomial<F> for ChebyshevFirstKind<F> {
+/// Same as [`gauss_second_kind_chebyshev_rule`], but returns an error instead of panicking when `n` is zero.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `n` -  order, number of points used in the rule.
+///
+/// # Examples
+/// ```
+/// use integrate::gauss_quadrature::chebyshev::try_gauss_second_kind_chebyshev_rule;
+///
+/// let f = |x: f64| 1.0;
+///
+/// assert!(try_gauss_second_kind_chebyshev_rule(f, 0).is_err());
+/// assert!(try_gauss_second_kind_chebyshev_rule(f, 10).is_ok());
+/// ```
+pub fn try_gauss_second_kind_chebyshev_rule<Func, F: Float + Debug + Sync + Send, F2: Float>(
+    func: Func,
+    n: usize,
+) -> Result<F, GaussError>
+where
+    Func: Fn(F) -> F2 + Sync,
+{
+    validate_gauss_order(n)?;
+
+    Ok(gauss_second_kind_chebyshev_rule(func, n))
+}
+
+/// Integrates a truncated Chebyshev series $\sum_{j} c_j T_j(x)$ exactly over $\[-1, 1\]$.
+///
+/// * `coeffs` - coefficients $c_0, c_1, ...$ of the series.
+///
+/// Returns $\sum_{k} c_{2k} \frac{-2}{4k^2 - 1}$, zero for an empty series.
+///
+/// # Examples
+/// ```
+/// use integrate::gauss_quadrature::chebyshev::integrate_chebyshev_series;
+///
+/// // x² = (T_0(x) + T_2(x)) / 2
+/// let integral: f64 = integrate_chebyshev_series(&[0.5, 0.0, 0.5]);
+///
+/// assert!((integral - 2.0 / 3.0).abs() < 1e-15);
+/// ```
+#[must_use]
+pub fn integrate_chebyshev_series<F: Float>(coeffs: &[F]) -> F {
+    let two = F::one() + F::one();
+    let four = two + two;
+
+    coeffs
+        .iter()
+        .step_by(2)
+        .enumerate()
+        .fold(F::zero(), |integral, (k, &c)| {
+            let k = F::from(k).unwrap();
+            integral - two * c / (four * k * k - F::one())
+        })
+}
+
+impl<F: Float + Debug + Send + Sync> OrthogonalPolynomial<F> for ChebyshevFirstKind<F> {
     fn new(degree: usize) -> Self {
         ChebyshevFirstKind {
             degree,
@@ -232,8 +329,10 @@ impl<F: Float + Debug + AddAssign + Send + Sync> OrthogonalPolynomial<F> for Che
         let pi = F::from(PI).unwrap();
         let two = F::one() + F::one();
 
-        let zeros: Vec<F> = (1..=self.degree)
+        // cos is decreasing on [0, π], so that decreasing indices give ascending zeros
+        let zeros: Vec<F> = (1..self.degree + 1)
             .into_par_iter()
+            .rev()
             .map(|i| {
                 let i = F::from(i).unwrap();
 
@@ -250,9 +349,7 @@ impl<F: Float + Debug + AddAssign + Send + Sync> OrthogonalPolynomial<F> for Che
     }
 }
 
-impl<F: Float + Debug + AddAssign + Send + Sync> OrthogonalPolynomial<F>
-    for ChebyshevSecondKind<F>
-{
+impl<F: Float + Debug + Send + Sync> OrthogonalPolynomial<F> for ChebyshevSecondKind<F> {
     fn new(degree: usize) -> Self {
         ChebyshevSecondKind {
             degree,
@@ -278,8 +375,10 @@ impl<F: Float + Debug + AddAssign + Send + Sync> OrthogonalPolynomial<F>
         let n = F::from(self.degree).unwrap();
         let pi = F::from(PI).unwrap();
 
-        let zeros: Vec<F> = (1..=self.degree)
+        // cos is decreasing on [0, π], so that decreasing indices give ascending zeros
+        let zeros: Vec<F> = (1..self.degree + 1)
             .into_par_iter()
+            .rev()
             .map(|i| {
                 let i = F::from(i).unwrap();
 
@@ -303,8 +402,8 @@ mod tests {
     use crate::{
         gauss_quadrature::chebyshev::{
             gauss_first_kind_chebyshev_rule, gauss_second_kind_chebyshev_rule,
-            roots_first_kind_chebyshev, roots_second_kind_chebyshev, ChebyshevFirstKind,
-            ChebyshevSecondKind,
+            integrate_chebyshev_series, roots_first_kind_chebyshev, roots_second_kind_chebyshev,
+            ChebyshevFirstKind, ChebyshevSecondKind,
         },
         utils::orthogonal_polynomials::OrthogonalPolynomial,
     };
@@ -469,20 +568,15 @@ mod tests {
         let t8: ChebyshevFirstKind<f64> = ChebyshevFirstKind::new(8);
         let t16: ChebyshevFirstKind<f64> = ChebyshevFirstKind::new(16);
 
-        let mut t1_zeros = t1.zeros();
-        t1_zeros.reverse();
+        let t1_zeros = t1.zeros();
 
-        let mut t2_zeros = t2.zeros();
-        t2_zeros.reverse();
+        let t2_zeros = t2.zeros();
 
-        let mut t4_zeros = t4.zeros();
-        t4_zeros.reverse();
+        let t4_zeros = t4.zeros();
 
-        let mut t8_zeros = t8.zeros();
-        t8_zeros.reverse();
+        let t8_zeros = t8.zeros();
 
-        let mut t16_zeros = t16.zeros();
-        t16_zeros.reverse();
+        let t16_zeros = t16.zeros();
 
         let t1_test = t1_zeros
             .iter()
@@ -573,20 +667,15 @@ mod tests {
         let u8: ChebyshevSecondKind<f64> = ChebyshevSecondKind::new(8);
         let u16: ChebyshevSecondKind<f64> = ChebyshevSecondKind::new(16);
 
-        let mut u1_zeros = u1.zeros();
-        u1_zeros.reverse();
+        let u1_zeros = u1.zeros();
 
-        let mut u2_zeros = u2.zeros();
-        u2_zeros.reverse();
+        let u2_zeros = u2.zeros();
 
-        let mut u4_zeros = u4.zeros();
-        u4_zeros.reverse();
+        let u4_zeros = u4.zeros();
 
-        let mut u8_zeros = u8.zeros();
-        u8_zeros.reverse();
+        let u8_zeros = u8.zeros();
 
-        let mut u16_zeros = u16.zeros();
-        u16_zeros.reverse();
+        let u16_zeros = u16.zeros();
 
         let u1_test = u1_zeros
             .iter()
@@ -724,4 +813,28 @@ mod tests {
             assert!((integral - exact).abs() < EPSILON);
         }
     }
+
+    #[test]
+    fn test_integrate_chebyshev_series() {
+        use crate::gauss_quadrature::legendre::legendre_rule;
+
+        // x² = (T_0(x) + T_2(x)) / 2
+        let integral: f64 = integrate_chebyshev_series(&[0.5, 0.0, 0.5]);
+        assert!((integral - 2.0 / 3.0).abs() < 1e-15);
+
+        assert_eq!(integrate_chebyshev_series::<f64>(&[]), 0.0);
+
+        // validate against the quadrature of the series evaluated through T_j
+        let coeffs: [f64; 7] = [1.0, -0.5, 0.25, 2.0, -0.75, 0.1, 0.3];
+        let series = |x: f64| {
+            coeffs
+                .iter()
+                .enumerate()
+                .map(|(j, c)| c * ChebyshevFirstKind::<f64>::new(j).eval(x))
+                .sum::<f64>()
+        };
+
+        let quadrature = legendre_rule(series, -1.0, 1.0, 20_usize);
+        assert!((integrate_chebyshev_series(&coeffs) - quadrature).abs() < 1e-12);
+    }
 }
@@ -0,0 +1,130 @@
+//! Gauss families
+//!
+//! [`GaussFamily`] names the Gauss quadrature families of this crate, each one integrating
+//! against its own weight function $w(x)$ over its own domain.
+//!
+//! The weights $w_i$ of an $n$ point rule integrate the constant $1$ exactly, so that they
+//! add up to the integral of the weight function
+//!
+//! ```math
+//! \sum_{i=1}^{n} w_i = \int w(x) dx
+//! ```
+//!
+//! which is $2$ for Gauss-Legendre, $1$ for Gauss-Laguerre, $\sqrt{\pi}$ for Gauss-Hermite,
+//! $\pi$ for Gauss-Chebyshev of the first kind and $\frac{\pi}{2}$ for the second kind.
+//! [`weight_sum`] computes the left hand side, a quick check against systematic errors in
+//! the weights.
+
+use std::f64::consts::PI;
+use std::fmt::Debug;
+
+use num::Float;
+
+use super::chebyshev::{gauss_first_kind_chebyshev_rule, gauss_second_kind_chebyshev_rule};
+use super::hermite::gauss_hermite_rule;
+use super::laguerre::gauss_laguerre_rule;
+use super::legendre::legendre_rule;
+
+/// A family of Gauss quadrature rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GaussFamily {
+    /// Gauss-Legendre, $w(x) = 1$ over $\[-1, 1\]$.
+    Legendre,
+    /// Gauss-Laguerre, $w(x) = e^{-x}$ over $\[0, \infty)$.
+    Laguerre,
+    /// Gauss-Hermite, $w(x) = e^{-x^2}$ over $(-\infty, \infty)$.
+    Hermite,
+    /// Gauss-Chebyshev of the first kind, $w(x) = \frac{1}{\sqrt{1 - x^2}}$ over $\[-1, 1\]$.
+    ChebyshevFirstKind,
+    /// Gauss-Chebyshev of the second kind, $w(x) = \sqrt{1 - x^2}$ over $\[-1, 1\]$.
+    ChebyshevSecondKind,
+}
+
+impl GaussFamily {
+    /// All the families.
+    pub const ALL: [GaussFamily; 5] = [
+        GaussFamily::Legendre,
+        GaussFamily::Laguerre,
+        GaussFamily::Hermite,
+        GaussFamily::ChebyshevFirstKind,
+        GaussFamily::ChebyshevSecondKind,
+    ];
+
+    /// Integral of the weight function of the family over its domain.
+    ///
+    /// # Examples
+    /// ```
+    /// use integrate::gauss_quadrature::family::GaussFamily;
+    ///
+    ///
+    /// assert_eq!(GaussFamily::Legendre.total_weight::<f64>(), 2.0);
+    /// ```
+    #[must_use]
+    pub fn total_weight<F: Float>(&self) -> F {
+        let total = match self {
+            GaussFamily::Legendre => 2.0,
+            GaussFamily::Laguerre => 1.0,
+            GaussFamily::Hermite => PI.sqrt(),
+            GaussFamily::ChebyshevFirstKind => PI,
+            GaussFamily::ChebyshevSecondKind => PI / 2.0,
+        };
+
+        F::from(total).unwrap()
+    }
+}
+
+/// Sum of the weights of the $n$ point rule of a Gauss family, which should be equal to
+/// [`GaussFamily::total_weight`].
+///
+/// * `family` - Gauss family.
+/// * `n` - order, number of points used in the rule.
+///
+/// # Examples
+/// ```
+/// use integrate::gauss_quadrature::family::{weight_sum, GaussFamily};
+///
+///
+/// let sum: f64 = weight_sum(GaussFamily::ChebyshevFirstKind, 10);
+///
+/// assert!((sum - std::f64::consts::PI).abs() < 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `n` is zero.
+#[must_use]
+pub fn weight_sum<F: Float + Debug + Sync + Send>(family: GaussFamily, n: usize) -> F {
+    let one = |_: F| F::one();
+
+    match family {
+        GaussFamily::Legendre => F::from(legendre_rule(one, -F::one(), F::one(), n)).unwrap(),
+        GaussFamily::Laguerre => gauss_laguerre_rule(one, n),
+        GaussFamily::Hermite => gauss_hermite_rule(one, n),
+        GaussFamily::ChebyshevFirstKind => gauss_first_kind_chebyshev_rule(one, n),
+        GaussFamily::ChebyshevSecondKind => gauss_second_kind_chebyshev_rule(one, n),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weight_sum() {
+        for family in GaussFamily::ALL {
+            for n in [1_usize, 2, 5, 10, 20, 50, 100] {
+                let sum: f64 = weight_sum(family, n);
+                let exact: f64 = family.total_weight();
+
+                assert!(
+                    (sum - exact).abs() < 1e-10,
+                    "{:?} with n = {}: {} instead of {}",
+                    family,
+                    n,
+                    sum,
+                    exact
+                );
+            }
+        }
+    }
+}
@@ -28,24 +28,25 @@
 //! A_i = \frac{2^{n+1} * n! * \sqrt{\pi}}{H_{n-1} (x_i)^2} \quad \text{for} \quad i = 1,...,n
 //! ```
 
-use std::f64::consts::PI;
+use std::f64::consts::{LN_2, PI};
 use std::fmt::Debug;
-use std::iter::Sum;
 use std::ops::Mul;
 
-use std::{marker::PhantomData, ops::AddAssign};
+use std::marker::PhantomData;
 
 use num::bigint::ToBigInt;
-use num::{BigRational, BigUint, Float, One, Zero};
+use num::{BigRational, BigUint, Complex, Float, One, Zero};
 use rayon::iter::{
     IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator, ParallelExtend,
     ParallelIterator,
 };
 
-use crate::utils::matrix::TridiagonalSymmetricFloatMatrix;
+use crate::linalg::TridiagonalSymmetricFloatMatrix;
 use crate::utils::orthogonal_polynomials::OrthogonalPolynomial;
 
-use super::utils::check_gauss_rule_args;
+use super::utils::{check_gauss_rule_args, validate_gauss_order, GaussError, WarnOnce};
+
+static HERMITE_UNDERFLOW_WARNING: WarnOnce = WarnOnce::new();
 
 #[derive(Clone, Debug)]
 struct Hermite<F: Float> {
@@ -53,7 +54,7 @@ struct Hermite<F: Float> {
     _x: PhantomData<F>,
 }
 
-impl<F: Float + Sync + Send + AddAssign + Debug> OrthogonalPolynomial<F> for Hermite<F> {
+impl<F: Float + Sync + Send + Debug> OrthogonalPolynomial<F> for Hermite<F> {
     fn new(degree: usize) -> Self {
         Hermite {
             degree,
@@ -108,17 +109,72 @@ impl<F: Float + Sync + Send + AddAssign + Debug> OrthogonalPolynomial<F> for Her
 
         let matrix = TridiagonalSymmetricFloatMatrix::new(diagonal, offdiagonal);
 
-        matrix.eigenvalues()
+        // eigenvalues come in decreasing order
+        let mut zeros = matrix.eigenvalues();
+        zeros.reverse();
+        zeros
     }
 }
 
-// weights formula : https://wikimedia.org/api/rest_v1/media/math/render/svg/2e6f152a1e9ecd4ab8ddf912aaa69bb8d0e66a3c
-fn roots_hermite<F: Float + Debug + AddAssign + Sync + Send + ToBigInt>(
-    n: usize,
-) -> (Vec<F>, Vec<F>) {
+/// Largest order for which the weights are computed in log-space, in `f64`.
+const LOG_WEIGHTS_MAX_N: usize = 100;
+
+/// Whether the nodes and weights of the `n` point rule may contain `NaN`, so that they have
+/// to be scanned for the underflow warning. The weights computed in log-space never are,
+/// whether `F` is `f32` or `f64`.
+fn underflow_possible(n: usize) -> bool {
+    n > LOG_WEIGHTS_MAX_N
+}
+
+fn roots_hermite<F: Float + Debug + Sync + Send>(n: usize) -> (Vec<F>, Vec<F>) {
     let h_n: Hermite<F> = Hermite::new(n); // H_n
     let zeros = h_n.zeros();
 
+    let weights = if n <= LOG_WEIGHTS_MAX_N {
+        log_weights_hermite(&zeros, n)
+    } else {
+        exact_weights_hermite(&zeros, n)
+    };
+
+    let warn = underflow_possible(n)
+        && zeros
+            .as_slice()
+            .into_par_iter()
+            .zip(weights.as_slice())
+            .any(|(zero, weight)| (*zero).is_nan() || (*weight).is_nan());
+
+    if warn {
+        HERMITE_UNDERFLOW_WARNING.warn(
+            "Warning: `n` chosen is too big, some values of Hermite Polynomials weights or zeros are too small and may underflow!",
+        );
+    }
+
+    (zeros, weights)
+}
+
+// weights formula : https://wikimedia.org/api/rest_v1/media/math/render/svg/2e6f152a1e9ecd4ab8ddf912aaa69bb8d0e66a3c
+// computed in log-space, so that 2^(n-1) n! never needs to be represented:
+// log w_i = (n-1) ln 2 + ln n! + ln(π)/2 - 2 ln n - 2 ln |H_{n-1}(x_i)|
+fn log_weights_hermite<F: Float + Debug + Sync + Send>(zeros: &[F], n: usize) -> Vec<F> {
+    let h: Hermite<f64> = Hermite::new(n - 1); // H_{n-1}
+
+    let ln_n_fact: f64 = (2..=n).map(|k| (k as f64).ln()).sum();
+    let n = n as f64;
+
+    let log_numerator = (n - 1.0) * LN_2 + ln_n_fact + 0.5 * PI.ln() - 2.0 * n.ln();
+
+    zeros
+        .par_iter()
+        .map(|x_i| {
+            let h_x = h.eval(x_i.to_f64().unwrap()); // H_{n-1}(x_i)
+
+            F::from((log_numerator - 2.0 * h_x.abs().ln()).exp()).unwrap()
+        })
+        .collect()
+}
+
+// weights formula : https://wikimedia.org/api/rest_v1/media/math/render/svg/2e6f152a1e9ecd4ab8ddf912aaa69bb8d0e66a3c
+fn exact_weights_hermite<F: Float + Debug + Sync + Send>(zeros: &[F], n: usize) -> Vec<F> {
     let h: Hermite<F> = Hermite::new(n - 1); // H_{n-1}
 
     // params used in weights formula
@@ -132,7 +188,7 @@ fn roots_hermite<F: Float + Debug + AddAssign + Sync + Send + ToBigInt>(
 
     let two_pow = two.powf(n - F::one());
 
-    let weights: Vec<F> = zeros
+    zeros
         .par_iter()
         .map(|x_i| {
             let h_x = h.eval(*x_i); // H_{n-1}(x_i)
@@ -142,9 +198,11 @@ fn roots_hermite<F: Float + Debug + AddAssign + Sync + Send + ToBigInt>(
             let denominator = n_squared * h_x * h_x;
 
             if denominator.is_infinite() || numerator.is_infinite() {
-                // switching everything to BigInt
-                let numer = two_pow.to_bigint().unwrap() * n_fact.to_bigint().unwrap();
-                let denom = h_x.abs().to_bigint().unwrap().pow(2) * n_squared.to_bigint().unwrap();
+                // switching everything to BigInt, through f64 so that F needs no ToBigInt
+                let to_bigint = |x: F| x.to_f64().unwrap().to_bigint().unwrap();
+
+                let numer = to_bigint(two_pow) * to_bigint(n_fact);
+                let denom = to_bigint(h_x.abs()).pow(2) * to_bigint(n_squared);
                 let ratio = BigRational::new(numer, denom);
 
                 F::from(ratio).unwrap() * sqrt_pi
@@ -152,21 +210,7 @@ fn roots_hermite<F: Float + Debug + AddAssign + Sync + Send + ToBigInt>(
                 numerator / denominator
             }
         })
-        .collect();
-
-    let warn = zeros
-        .as_slice()
-        .into_par_iter()
-        .zip(weights.as_slice())
-        .any(|(zero, weight)| (*zero).is_nan() || (*weight).is_nan());
-
-    if warn {
-        eprintln!(
-            "Warning: `n` chosen is too big, some values of Hermite Polynomials weights or zeros are too small and may underflow!"
-        )
-    }
-
-    (zeros, weights)
+        .collect()
 }
 
 /// Approximate the integral of $f(x) e^{-x^2}$ from $-\infty$ to $+\infty$
@@ -191,6 +235,9 @@ fn roots_hermite<F: Float + Debug + AddAssign + Sync + Send + ToBigInt>(
 /// * `func` - Integrand function of a single variable.
 /// * `n` -  order, number of points used in the rule.  
 ///
+/// The integrand may return a float type `F2` other than the node type `F`, its values
+/// are then converted to `F`.
+///
 /// # Examples
 /// ```
 /// use integrate::gauss_quadrature::hermite::gauss_hermite_rule;
@@ -201,12 +248,13 @@ fn roots_hermite<F: Float + Debug + AddAssign + Sync + Send + ToBigInt>(
 ///
 /// let integral = gauss_hermite_rule(f, n);
 /// ```
-pub fn gauss_hermite_rule<Func, F: Float + Debug + Sync + Send + AddAssign + Sum + ToBigInt>(
+#[must_use]
+pub fn gauss_hermite_rule<Func, F: Float + Debug + Sync + Send, F2: Float>(
     func: Func,
     n: usize,
 ) -> F
 where
-    Func: Fn(F) -> F + Sync,
+    Func: Fn(F) -> F2 + Sync,
 {
     check_gauss_rule_args(n);
 
@@ -215,10 +263,75 @@ where
     weights
         .into_par_iter()
         .zip(zeros)
-        .map(|(w, x)| w * func(x))
+        .map(|(w, x)| w * F::from(func(x)).unwrap())
+        .reduce(F::zero, |sum, term| sum + term)
+}
+
+/// Approximate the integral of $f(x) e^{-x^2}$ from -infinity to +infinity for a complex
+/// valued $f$, using the $n$ point Gauss-Hermite integral approximation formula.
+///
+/// The nodes and the real weights are those of [`gauss_hermite_rule`], the complex values
+/// of $f$ are summed with them, which amounts to integrating the real and imaginary parts
+/// of $f$ separately.
+///
+/// * `func` - Complex valued integrand function of a single real variable.
+/// * `n` -  order, number of points used in the rule.
+///
+/// # Examples
+/// ```
+/// use integrate::gauss_quadrature::hermite::gauss_hermite_rule_complex;
+/// use num::Complex;
+///
+/// // ∫ e^{-x²} e^{ix} dx = √π e^{-1/4}
+/// let f = |x: f64| Complex::new(0.0, x).exp();
+///
+/// let integral = gauss_hermite_rule_complex(f, 20);
+///
+/// let exact = std::f64::consts::PI.sqrt() * (-0.25_f64).exp();
+/// assert!((integral - exact).norm() < 1e-6);
+/// ```
+#[must_use]
+pub fn gauss_hermite_rule_complex<Func>(func: Func, n: usize) -> Complex<f64>
+where
+    Func: Fn(f64) -> Complex<f64> + Sync,
+{
+    check_gauss_rule_args(n);
+
+    let (zeros, weights) = roots_hermite::<f64>(n);
+
+    weights
+        .into_par_iter()
+        .zip(zeros)
+        .map(|(w, x)| func(x) * w)
         .sum()
 }
 
+/// Same as [`gauss_hermite_rule`], but returns an error instead of panicking when `n` is zero.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `n` -  order, number of points used in the rule.
+///
+/// # Examples
+/// ```
+/// use integrate::gauss_quadrature::hermite::try_gauss_hermite_rule;
+///
+/// let f = |x: f64| 1.0;
+///
+/// assert!(try_gauss_hermite_rule(f, 0).is_err());
+/// assert!(try_gauss_hermite_rule(f, 10).is_ok());
+/// ```
+pub fn try_gauss_hermite_rule<Func, F: Float + Debug + Sync + Send, F2: Float>(
+    func: Func,
+    n: usize,
+) -> Result<F, GaussError>
+where
+    Func: Fn(F) -> F2 + Sync,
+{
+    validate_gauss_order(n)?;
+
+    Ok(gauss_hermite_rule(func, n))
+}
+
 fn factorial(n: usize) -> BigUint {
     (1..n + 1)
         .into_par_iter()
@@ -230,10 +343,14 @@ fn factorial(n: usize) -> BigUint {
 
 #[cfg(test)]
 mod tests {
-    use std::f64::consts::FRAC_1_SQRT_2;
+    use std::f64::consts::{FRAC_1_SQRT_2, PI};
 
     use crate::{
-        gauss_quadrature::hermite::Hermite, utils::orthogonal_polynomials::OrthogonalPolynomial,
+        gauss_quadrature::hermite::{
+            gauss_hermite_rule_complex, roots_hermite, underflow_possible, Hermite,
+            LOG_WEIGHTS_MAX_N,
+        },
+        utils::orthogonal_polynomials::OrthogonalPolynomial,
     };
 
     const EPSILON: f64 = 10e-7;
@@ -289,20 +406,15 @@ mod tests {
         let h4: Hermite<f64> = Hermite::new(4);
         let h5: Hermite<f64> = Hermite::new(5);
 
-        let mut h1_zeros = h1.zeros();
-        h1_zeros.reverse();
+        let h1_zeros = h1.zeros();
 
-        let mut h2_zeros = h2.zeros();
-        h2_zeros.reverse();
+        let h2_zeros = h2.zeros();
 
-        let mut h3_zeros = h3.zeros();
-        h3_zeros.reverse();
+        let h3_zeros = h3.zeros();
 
-        let mut h4_zeros = h4.zeros();
-        h4_zeros.reverse();
+        let h4_zeros = h4.zeros();
 
-        let mut h5_zeros = h5.zeros();
-        h5_zeros.reverse();
+        let h5_zeros = h5.zeros();
 
         let h1_test = h1_zeros
             .iter()
@@ -339,4 +451,82 @@ mod tests {
 
         assert!(h5_test)
     }
+
+    #[test]
+    fn test_log_weights() {
+        use super::{exact_weights_hermite, log_weights_hermite};
+
+        for n in [1_usize, 5, 50, 100] {
+            let zeros = Hermite::<f64>::new(n).zeros();
+
+            let log_weights: Vec<f64> = log_weights_hermite(&zeros, n);
+            let exact_weights: Vec<f64> = exact_weights_hermite(&zeros, n);
+
+            for (w_log, w_exact) in log_weights.iter().zip(exact_weights.iter()) {
+                assert!((w_log - w_exact).abs() <= 1e-10 * w_exact.abs());
+            }
+        }
+    }
+
+    #[test]
+    fn test_gauss_hermite_rule_complex() {
+        use num::Complex;
+
+        for k in [0.5_f64, 1.0, 2.0] {
+            let f = |x: f64| Complex::new(0.0, k * x).exp();
+
+            let integral = gauss_hermite_rule_complex(f, 30);
+
+            // the accuracy is limited by the eigenvalue solver computing the nodes,
+            // the imaginary part vanishes by symmetry
+            let exact = PI.sqrt() * (-k * k / 4.0).exp();
+            assert!((integral.re - exact).abs() < 1e-6);
+            assert!(integral.im.abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_underflow_scan_skipped() {
+        assert!(!underflow_possible(LOG_WEIGHTS_MAX_N));
+        assert!(underflow_possible(LOG_WEIGHTS_MAX_N + 1));
+
+        // below the threshold, skipping the scan cannot hide a NaN
+        for n in 1..=LOG_WEIGHTS_MAX_N {
+            let (zeros, weights) = roots_hermite::<f32>(n);
+            assert!(zeros.iter().chain(weights.iter()).all(|v| !v.is_nan()));
+
+            let (zeros, weights) = roots_hermite::<f64>(n);
+            assert!(zeros.iter().chain(weights.iter()).all(|v| !v.is_nan()));
+        }
+    }
+
+    #[test]
+    fn test_ascending_nodes() {
+        use super::roots_hermite;
+
+        let (nodes, weights) = roots_hermite::<f64>(5);
+
+        assert!(nodes.windows(2).all(|x| x[0] < x[1]));
+
+        // the 5 point rule is exact for x^(2k), k < 5, whose integral against e^{-x²}
+        // is Γ(k + 1/2) = (2k - 1)!! √π / 2^k, up to the accuracy of the eigenvalue solver
+        let mut moment = PI.sqrt();
+        for k in 0..5 {
+            if k > 0 {
+                moment *= (2 * k - 1) as f64 / 2.0;
+            }
+
+            let integral: f64 = nodes
+                .iter()
+                .zip(weights.iter())
+                .map(|(x, w)| w * x.powi(2 * k))
+                .sum();
+
+            assert!((integral - moment).abs() < 1e-6 * moment);
+        }
+
+        // odd moments vanish only if the weights are paired with the symmetric nodes
+        let odd: f64 = nodes.iter().zip(weights.iter()).map(|(x, w)| w * x).sum();
+        assert!(odd.abs() < 1e-14);
+    }
 }
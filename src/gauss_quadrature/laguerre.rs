@@ -17,25 +17,54 @@
 //!
 //! $$ GL_n ( f(x) ) = A_1 f(x_1) + ··· + A_n f(x_n) $$
 //!
-//! where $xi$ , $i = 1,...,n$, are the zeros of $L_n$ and
+//! where $xi$, $i = 1,...,n$, are the zeros of $L_n$ and
 //!
 //! ```math
 //! A_i = \dfrac{n!^2}{ x_i  L_{n-1} (x_i)^2} \quad \text{for} \quad i = 1,...,n
 //! ```
 //!
+//! The rule assumes that $f(x)$ grows slower than $e^{x}$. For a slowly decaying
+//! $f(x) e^{-x}$, the nodes, the largest of which is about $4n$, don't reach far enough
+//! to capture the tail of the integrand, and the estimate is poor without any sign of it:
+//! [`gauss_laguerre_rule_checked`] reports such integrands.
+//!
 
-use std::{fmt::Debug, iter::Sum, marker::PhantomData, ops::AddAssign};
+use std::{fmt::Debug, marker::PhantomData};
 
 use num::{one, Float, One, Zero};
 use rayon::iter::{
     IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator, ParallelIterator,
 };
 
-use crate::utils::{
-    matrix::TridiagonalSymmetricFloatMatrix, orthogonal_polynomials::OrthogonalPolynomial,
+use crate::{
+    linalg::TridiagonalSymmetricFloatMatrix, utils::orthogonal_polynomials::OrthogonalPolynomial,
 };
 
-use super::utils::check_gauss_rule_args;
+use super::utils::{check_gauss_rule_args, validate_gauss_order, GaussError, WarnOnce};
+
+static LAGUERRE_UNDERFLOW_WARNING: WarnOnce = WarnOnce::new();
+
+/// Largest value of $f(x) e^{-x}$ at the largest node, relative to the integral of
+/// $\left| f(x) \right| e^{-x}$, for [`gauss_laguerre_rule_checked`] to consider that the
+/// tail is captured.
+pub const TAIL_TOLERANCE: f64 = 1e-10;
+
+/// Orders below which no node or weight is `NaN`, for `f32` and for types with at least the
+/// range of `f64`. The first `NaN` shows up at $n = 48$ and $n = 363$ respectively.
+const F32_SAFE_N: usize = 40;
+const F64_SAFE_N: usize = 300;
+
+/// Whether the nodes and weights of the `n` point rule may contain `NaN`, so that they have
+/// to be scanned for the underflow warning.
+fn underflow_possible<F: Float>(n: usize) -> bool {
+    let safe_n = if F::max_value().to_f64().unwrap() < f64::MAX {
+        F32_SAFE_N
+    } else {
+        F64_SAFE_N
+    };
+
+    n >= safe_n
+}
 
 #[derive(Clone, Debug)]
 struct Laguerre<F: Float> {
@@ -43,7 +72,7 @@ struct Laguerre<F: Float> {
     _x: PhantomData<F>,
 }
 
-impl<F: Float + Sync + Send + AddAssign + Debug> OrthogonalPolynomial<F> for Laguerre<F> {
+impl<F: Float + Sync + Send + Debug> OrthogonalPolynomial<F> for Laguerre<F> {
     fn new(degree: usize) -> Self {
         Laguerre {
             degree,
@@ -118,16 +147,21 @@ impl<F: Float + Sync + Send + AddAssign + Debug> OrthogonalPolynomial<F> for Lag
 
         let matrix = TridiagonalSymmetricFloatMatrix::new(diagonal, offdiagonal);
 
-        matrix.eigenvalues()
+        // eigenvalues come in decreasing order
+        let mut zeros = matrix.eigenvalues();
+        zeros.reverse();
+        zeros
     }
 }
 
-fn roots_laguerre<F: Float + Debug + Sync + Send + AddAssign>(n: usize) -> (Vec<F>, Vec<F>) {
+fn roots_laguerre<F: Float + Debug + Sync + Send>(n: usize) -> (Vec<F>, Vec<F>) {
     let l_n: Laguerre<F> = Laguerre::new(n);
     let l_n_plus_1: Laguerre<F> = Laguerre::new(n + 1);
 
     let zeros = l_n.zeros();
 
+    let check_underflow = underflow_possible::<F>(n);
+
     let n = F::from(n).unwrap();
     let two = F::one() + F::one();
 
@@ -141,16 +175,17 @@ fn roots_laguerre<F: Float + Debug + Sync + Send + AddAssign>(n: usize) -> (Vec<
         })
         .collect();
 
-    let warn = zeros
-        .as_slice()
-        .into_par_iter()
-        .zip(weights.as_slice())
-        .any(|(zero, weight)| (*zero).is_nan() || (*weight).is_nan());
+    let warn = check_underflow
+        && zeros
+            .as_slice()
+            .into_par_iter()
+            .zip(weights.as_slice())
+            .any(|(zero, weight)| (*zero).is_nan() || (*weight).is_nan());
 
     if warn {
-        eprintln!(
-            "Warning: `n` chosen is too big, some values of Laguerre Polynomials weights or zeros are too small and may underflow!"
-        )
+        LAGUERRE_UNDERFLOW_WARNING.warn(
+            "Warning: `n` chosen is too big, some values of Laguerre Polynomials weights or zeros are too small and may underflow!",
+        );
     }
 
     (zeros, weights)
@@ -162,6 +197,9 @@ fn roots_laguerre<F: Float + Debug + Sync + Send + AddAssign>(n: usize) -> (Vec<
 /// * `func` - Integrand function of a single variable.
 /// * `n` -  order, number of points used in the rule.  
 ///
+/// The integrand may return a float type `F2` other than the node type `F`, its values
+/// are then converted to `F`.
+///
 /// # Examples
 /// ```
 /// use integrate::gauss_quadrature::laguerre::gauss_laguerre_rule;
@@ -172,12 +210,13 @@ fn roots_laguerre<F: Float + Debug + Sync + Send + AddAssign>(n: usize) -> (Vec<
 ///
 /// let integral = gauss_laguerre_rule(f, n);
 /// ```
-pub fn gauss_laguerre_rule<Func, F: Float + Debug + Sync + Send + AddAssign + Sum>(
+#[must_use]
+pub fn gauss_laguerre_rule<Func, F: Float + Debug + Sync + Send, F2: Float>(
     func: Func,
     n: usize,
 ) -> F
 where
-    Func: Fn(F) -> F + Sync,
+    Func: Fn(F) -> F2 + Sync,
 {
     check_gauss_rule_args(n);
     let (zeros, weights) = roots_laguerre::<F>(n);
@@ -185,8 +224,109 @@ where
     weights
         .into_par_iter()
         .zip(zeros)
-        .map(|(w, x)| w * func(x))
-        .sum()
+        .map(|(w, x)| w * F::from(func(x)).unwrap())
+        .reduce(F::zero, |sum, term| sum + term)
+}
+
+/// Same as [`gauss_laguerre_rule`], but returns an error instead of panicking when `n` is zero.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `n` -  order, number of points used in the rule.
+///
+/// # Examples
+/// ```
+/// use integrate::gauss_quadrature::laguerre::try_gauss_laguerre_rule;
+///
+/// let f = |x: f64| 1.0;
+///
+/// assert!(try_gauss_laguerre_rule(f, 0).is_err());
+/// assert!(try_gauss_laguerre_rule(f, 10).is_ok());
+/// ```
+pub fn try_gauss_laguerre_rule<Func, F: Float + Debug + Sync + Send, F2: Float>(
+    func: Func,
+    n: usize,
+) -> Result<F, GaussError>
+where
+    Func: Fn(F) -> F2 + Sync,
+{
+    validate_gauss_order(n)?;
+
+    Ok(gauss_laguerre_rule(func, n))
+}
+
+/// Same as [`gauss_laguerre_rule`], but checks that the integrand $f(x) e^{-x}$ has decayed
+/// at the largest node $x_n$ of the rule.
+///
+/// If $\left| f(x_n) \right| e^{-x_n}$ exceeds [`TAIL_TOLERANCE`] times the approximation of
+/// the integral of $\left| f(x) \right| e^{-x}$, the integrand is still significant where the
+/// rule stops sampling it: either $f$ grows too fast, close to $e^{x}$, or more points are
+/// needed to reach its tail. The tail is still considered captured when the rule of order
+/// $n - 1$ agrees with the rule of order $n$ to the same tolerance, as it does for the
+/// polynomials both rules integrate exactly.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `n` -  order, number of points used in the rule.
+///
+/// # Examples
+/// ```
+/// use integrate::gauss_quadrature::laguerre::gauss_laguerre_rule_checked;
+/// use integrate::gauss_quadrature::GaussError;
+///
+///
+/// let integral: f64 = gauss_laguerre_rule_checked(|x: f64| x * x, 10).unwrap();
+/// assert!((integral - 2.0).abs() < 1e-10);
+///
+/// let slow = |x: f64| (0.9 * x).exp();
+/// assert_eq!(gauss_laguerre_rule_checked::<_, f64, f64>(slow, 10), Err(GaussError::TailNotCaptured));
+/// ```
+///
+/// # Errors
+///
+/// Returns [`GaussError::ZeroOrder`] if `n` is zero, and [`GaussError::TailNotCaptured`]
+/// if the integrand has not decayed at the largest node.
+pub fn gauss_laguerre_rule_checked<Func, F: Float + Debug + Sync + Send, F2: Float>(
+    func: Func,
+    n: usize,
+) -> Result<F, GaussError>
+where
+    Func: Fn(F) -> F2 + Sync,
+{
+    validate_gauss_order(n)?;
+    let integral: F = gauss_laguerre_rule(&func, n);
+    // approximation of the integral of |f(x)| e^{-x}, which is not small when f changes sign
+    let magnitude: F = gauss_laguerre_rule(|x: F| func(x).abs(), n);
+
+    let scale = integral.abs().max(magnitude).to_f64().unwrap();
+    if scale == 0.0 {
+        return Ok(integral);
+    }
+
+    let (zeros, _) = roots_laguerre::<F>(n);
+    let x_max = zeros
+        .iter()
+        .fold(F::zero(), |max, &x| if x > max { x } else { max })
+        .to_f64()
+        .unwrap();
+
+    // logarithm of |f(x_max)| e^{-x_max}, which neither overflows nor underflows
+    let log_tail = func(F::from(x_max).unwrap()).to_f64().unwrap().abs().ln() - x_max;
+    let log_bound = (TAIL_TOLERANCE * scale).ln();
+
+    if !log_tail.is_nan() && log_tail <= log_bound {
+        return Ok(integral);
+    }
+
+    // the integrand has not decayed at x_max, which is fine when the rule is exact anyway,
+    // e.g. for a polynomial: the rule of order n - 1 then gives the same approximation
+    if n > 1 {
+        let previous: F = gauss_laguerre_rule(&func, n - 1);
+
+        if (integral - previous).abs().to_f64().unwrap() <= TAIL_TOLERANCE * scale {
+            return Ok(integral);
+        }
+    }
+
+    Err(GaussError::TailNotCaptured)
 }
 
 #[cfg(test)]
@@ -461,8 +601,10 @@ mod tests {
 
         let lag_zeros = lag.zeros();
 
+        // tabulated in descending order
         FIRST_100_LAGUERRE_ROOTS
             .into_par_iter()
+            .rev()
             .zip(lag_zeros)
             .for_each(|(test_zero, zero)| assert!((test_zero - zero).abs() < EPSILON))
     }
@@ -476,6 +618,7 @@ mod tests {
 
         FIRST_100_LAGUERRE_WEIGHTS
             .into_par_iter()
+            .rev()
             .zip(weights)
             .for_each(|(test_weight, weight)| assert!((test_weight - weight).abs() < EPSILON))
     }
@@ -514,4 +657,93 @@ mod tests {
     //         roots_laguerre::<f64>(n);
     //     })
     // }
+
+    #[test]
+    fn test_f32_integrand_with_f64_nodes() {
+        // ∫ x² e^{-x} dx over [0, ∞) = 2
+        let square = |x: f64| (x as f32).powi(2);
+
+        let integral: f64 = gauss_laguerre_rule(square, 10);
+
+        assert!((integral - 2.0).abs() < 10e-5);
+    }
+
+    #[test]
+    fn test_underflow_scan_skipped() {
+        assert!(!underflow_possible::<f32>(F32_SAFE_N - 1));
+        assert!(underflow_possible::<f32>(F32_SAFE_N));
+        assert!(!underflow_possible::<f64>(F64_SAFE_N - 1));
+        assert!(underflow_possible::<f64>(F64_SAFE_N));
+
+        // below the thresholds, skipping the scan cannot hide a NaN
+        for n in 1..F32_SAFE_N {
+            let (zeros, weights) = roots_laguerre::<f32>(n);
+            assert!(zeros.iter().chain(weights.iter()).all(|v| !v.is_nan()));
+        }
+        for n in (1..F64_SAFE_N).step_by(37).chain([F64_SAFE_N - 1]) {
+            let (zeros, weights) = roots_laguerre::<f64>(n);
+            assert!(zeros.iter().chain(weights.iter()).all(|v| !v.is_nan()));
+        }
+    }
+
+    #[test]
+    fn test_ascending_nodes() {
+        let (nodes, weights) = roots_laguerre::<f64>(5);
+
+        assert!(nodes.windows(2).all(|x| x[0] < x[1]));
+
+        // the 5 point rule is exact for x^k, k < 10, whose integral against e^{-x} is k!,
+        // up to the accuracy of the eigenvalue solver
+        let mut factorial = 1.0;
+        for k in 0..10 {
+            if k > 0 {
+                factorial *= k as f64;
+            }
+
+            let integral: f64 = nodes
+                .iter()
+                .zip(weights.iter())
+                .map(|(x, w)| w * x.powi(k))
+                .sum();
+
+            assert!((integral - factorial).abs() < 1e-6 * factorial);
+        }
+    }
+
+    #[test]
+    fn test_checked_tail() {
+        // ∫ x^{10} e^{-x} dx = 10!, a polynomial of degree 2n - 1 at most is integrated
+        // exactly and decays long before the largest node
+        let polynomial: f64 = gauss_laguerre_rule_checked(|x: f64| x.powi(10), 20).unwrap();
+        assert!((polynomial / 3_628_800.0 - 1.0).abs() < 1e-10);
+
+        // ∫ e^{0.9x} e^{-x} dx = 10, the integrand decays like e^{-0.1x} only
+        let slow = |x: f64| (0.9 * x).exp();
+        let result: Result<f64, GaussError> = gauss_laguerre_rule_checked(slow, 20);
+        assert_eq!(result, Err(GaussError::TailNotCaptured));
+
+        // while the unchecked rule silently returns a poor estimate
+        let estimate: f64 = gauss_laguerre_rule(slow, 20);
+        assert!((estimate - 10.0).abs() > 1e-3);
+    }
+
+    #[test]
+    fn test_checked_exact_polynomials() {
+        // f = 1 has not decayed at the largest node of the low order rules, which are exact
+        for n in [2, 5, 8] {
+            let integral: f64 = gauss_laguerre_rule_checked(|_| 1.0, n).unwrap();
+            assert!((integral - 1.0).abs() < 1e-12);
+        }
+
+        // ∫ (x - 1) e^{-x} dx = 0, the bound must not shrink with the integral
+        for n in 1..=20 {
+            let integral: f64 = gauss_laguerre_rule_checked(|x: f64| x - 1.0, n).unwrap();
+            assert!(integral.abs() < 1e-12);
+        }
+
+        // ∫ (x^2 - 2x + 1e-6) e^{-x} dx = 1e-6
+        let integral: f64 =
+            gauss_laguerre_rule_checked(|x: f64| x * x - 2.0 * x + 1e-6, 3).unwrap();
+        assert!((integral - 1e-6).abs() < 1e-12);
+    }
 }
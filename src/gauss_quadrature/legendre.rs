@@ -92,12 +92,16 @@
 // The required theta values for the Legendre nodes for l <= 100
 // extern crate test;
 
+use std::sync::{Arc, Mutex};
 use std::{cmp::Ordering, f64::consts::PI};
 
 use num::{one, zero, Float, Integer, ToPrimitive, Unsigned};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
 use super::bessel::{bessel_j0_zeros, bessel_j1_squared};
+use super::utils::{check_gauss_rule_args, GaussError};
+use crate::tolerance::Tolerance;
+use crate::utils::affine::AffineMap;
 
 const EVEN_THETA_ZERO_1: &[f64] = &[9.553_166_181_245_093E-1];
 
@@ -6149,6 +6153,7 @@ fn glpair_tabulated<U: Unsigned + ToPrimitive + PartialOrd + Copy>(l: U, k: U) -
 ///
 /// let integral = legendre_rule(square, a, b, num_steps);
 /// ```
+#[must_use]
 pub fn legendre_rule<
     Func,
     F1: Float + Sync,
@@ -6163,10 +6168,8 @@ pub fn legendre_rule<
 where
     Func: Fn(F1) -> F2 + Sync,
 {
-    let two = F1::one() + F1::one();
-
-    let c = (upper_limit - lower_limit) / two;
-    let d = (upper_limit + lower_limit) / two;
+    let map = AffineMap::new(lower_limit, upper_limit);
+    let jacobian = map.jacobian().to_f64().unwrap();
 
     let n = n.to_usize().unwrap();
 
@@ -6180,12 +6183,426 @@ where
             let x = F1::from(x).unwrap();
 
             // interval change formula
-            weight * func(c * x + d).to_f64().unwrap() * c.to_f64().unwrap()
+            weight * func(map.map(x)).to_f64().unwrap() * jacobian
         })
         .sum();
     integral
 }
 
+/// Approximate the integral of $f(x)$ from $a$ to $b$ using the composite Gauss-Legendre rule.
+///
+/// The interval $\[a, b\]$ is divided into `n_panels` subintervals of equal length
+/// and the `points_per_panel` point Gauss-Legendre integral approximation formula is
+/// applied to each subinterval, the results are then summed.
+///
+/// Over long intervals, a moderate order rule applied on each panel is often more
+/// accurate than a single high order rule, and the nodes needed by the moderate order
+/// rule are tabulated (see [`legendre_rule`]).
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of integration.
+/// * `upper_limit` - upper limit of integration.
+/// * `n_panels` - number of subintervals.
+/// * `points_per_panel` - number of points used by the Gauss-Legendre rule on each subinterval.
+///
+/// # Examples
+///
+/// ```
+/// use integrate::gauss_quadrature::legendre::composite_legendre_rule;
+///
+///
+/// let square = |x: f64| x * x;
+///
+/// let a = 0.0;
+/// let b = 10.0;
+///
+/// let n_panels: usize = 10;
+/// let points_per_panel: usize = 5;
+///
+/// let integral = composite_legendre_rule(square, a, b, n_panels, points_per_panel);
+/// ```
+#[must_use]
+pub fn composite_legendre_rule<
+    Func,
+    F1: Float + Sync,
+    F2: Float,
+    U: Unsigned + ToPrimitive + Copy + PartialOrd + Sync,
+>(
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    n_panels: U,
+    points_per_panel: U,
+) -> f64
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    if n_panels.is_zero() {
+        panic!("number of panels can't be zero");
+    }
+
+    // length of each panel
+    let h: F1 = (upper_limit - lower_limit)
+        / F1::from(n_panels).expect("failed to convert length of panel h");
+
+    (0..n_panels.to_usize().unwrap())
+        .into_par_iter()
+        .map(|i| {
+            // panel index (as real)
+            let i = F1::from(i).expect("failed to convert panel index i");
+
+            let a = lower_limit + i * h;
+            let b = a + h;
+
+            legendre_rule(&func, a, b, points_per_panel)
+        })
+        .sum()
+}
+
+/// Approximate the integral of $f(x)$ from $a$ to $b$, where $0 < a < b$, using the n point
+/// Gauss-Legendre integral approximation formula after the substitution $x = e^u$
+///
+/// ```math
+/// \int_{a}^{b} f(x) dx = \int_{\ln a}^{\ln b} f(e^u) e^u du
+/// ```
+///
+/// The nodes are then spread evenly over the decades of $\[a, b\]$ instead of being
+/// concentrated where $x$ is large, which suits integrands behaving like powers of $x$ over
+/// several orders of magnitude, such as $\frac{1}{x}$.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of integration, must be positive.
+/// * `upper_limit` - upper limit of integration, must be greater than `lower_limit`.
+/// * `n` - number of points to use for Gauss-Legendre integral approximation formula.
+///
+/// # Examples
+///
+/// ```
+/// use integrate::gauss_quadrature::legendre::legendre_rule_logspace;
+///
+///
+/// let f = |x: f64| 1.0 / x.sqrt();
+///
+/// let integral = legendre_rule_logspace(f, 1e-6, 1.0, 20_usize);
+///
+/// assert!((integral - 2.0 * (1.0 - 1e-3)).abs() < 1e-9);
+/// ```
+///
+/// # Panics
+///
+/// Panics unless `0 < lower_limit < upper_limit`.
+#[must_use]
+pub fn legendre_rule_logspace<
+    Func,
+    F1: Float + Sync,
+    F2: Float,
+    U: Unsigned + ToPrimitive + Copy + PartialOrd + Sync,
+>(
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    n: U,
+) -> f64
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    if !(F1::zero() < lower_limit && lower_limit < upper_limit) {
+        panic!("logspace integration requires 0 < lower_limit < upper_limit");
+    }
+
+    let integrand = |u: F1| {
+        let x = u.exp();
+        func(x).to_f64().unwrap() * x.to_f64().unwrap()
+    };
+
+    legendre_rule(integrand, lower_limit.ln(), upper_limit.ln(), n)
+}
+
+/// Largest order tried by [`gauss_legendre_auto_tol`].
+pub const MAX_AUTO_ORDER: usize = 4_096;
+
+/// Rules of orders $2, 4, 8, \dots,$ [`MAX_AUTO_ORDER`] already computed by
+/// [`gauss_legendre_auto_tol`], the rule of order $2^{k+1}$ at index $k$, shared by all its
+/// calls.
+static AUTO_RULES: Mutex<Vec<Arc<GaussLegendre>>> = Mutex::new(Vec::new());
+
+/// Rule of order `n`, a power of two, from [`AUTO_RULES`], computing it and the lower
+/// orders missing from the cache first.
+fn cached_auto_rule(n: usize) -> Arc<GaussLegendre> {
+    let index = n.trailing_zeros() as usize - 1;
+
+    let mut rules = AUTO_RULES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    while rules.len() <= index {
+        let order = 2_usize << rules.len();
+        rules.push(Arc::new(GaussLegendre::new(order)));
+    }
+
+    Arc::clone(&rules[index])
+}
+
+/// Approximates the integral of $f(x)$ from $a$ to $b$ with Gauss-Legendre rules of
+/// increasing order, until two consecutive estimates agree within `tolerance`.
+///
+/// For an integrand analytic over $\[a, b\]$ the error of the n point rule decreases
+/// geometrically with $n$, so that the orders $2, 4, 8, \dots$ are tried, and the difference
+/// between the estimates of orders $n$ and $2n$ is essentially the error of the former. The
+/// method stops once that difference satisfies `tolerance` at the estimate of order $2n$,
+/// and returns that estimate along with its order.
+///
+/// The rules are cached across calls, so that only the orders never used before are
+/// computed. Before using a rule, its weights are checked to add up to $2$, see
+/// [`GaussLegendre::validate`]: this costs $n$ additions, much less than evaluating $f$, and
+/// a rule failing it can't be trusted to refine the previous estimate.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `a` - lower limit of the integration interval.
+/// * `b` - upper limit of the integration interval.
+/// * `tolerance` - combined absolute and relative tolerance on the difference between
+///   consecutive estimates.
+///
+/// # Examples
+///
+/// ```
+/// use integrate::gauss_quadrature::legendre::gauss_legendre_auto_tol;
+/// use integrate::tolerance::Tolerance;
+///
+///
+/// let tolerance = Tolerance::absolute(1e-12);
+/// let (integral, n) = gauss_legendre_auto_tol(|x: f64| x.exp(), 0.0, 1.0, &tolerance).unwrap();
+///
+/// assert!((integral - (1f64.exp() - 1.0)).abs() < 1e-12);
+/// assert!(n <= 16);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`GaussError::NotConverged`] if the estimates didn't agree within `tolerance` up
+/// to the order [`MAX_AUTO_ORDER`], and the error of [`GaussLegendre::validate`] if the
+/// weights of a rule don't add up to $2$.
+pub fn gauss_legendre_auto_tol<Func>(
+    func: Func,
+    a: f64,
+    b: f64,
+    tolerance: &Tolerance,
+) -> Result<(f64, usize), GaussError>
+where
+    Func: Fn(f64) -> f64,
+{
+    let mut n = 2;
+    let rule = cached_auto_rule(n);
+    rule.validate()?;
+    let mut previous = rule.integrate(&func, a, b);
+
+    while 2 * n <= MAX_AUTO_ORDER {
+        n *= 2;
+        let rule = cached_auto_rule(n);
+        rule.validate()?;
+        let estimate = rule.integrate(&func, a, b);
+
+        if tolerance.satisfied((estimate - previous).abs(), estimate) {
+            return Ok((estimate, n));
+        }
+
+        previous = estimate;
+    }
+
+    Err(GaussError::NotConverged)
+}
+
+/// Bound on the error of the n point Gauss-Legendre rule on $\[a, b\]$, given a bound $M$ on
+/// the $2n^{th}$ derivative of the integrand over $\[a, b\]$.
+///
+/// The remainder of the rule is
+///
+/// ```math
+/// \int_{a}^{b} f(x) dx - GL_n(f, a, b) = \frac{(b-a)^{2n+1} (n!)^4}{(2n+1) \left[(2n)!\right]^3} f^{(2n)}(c)
+/// ```
+///
+/// for some $c$ in $\[a, b\]$, so that its absolute value is at most the constant times $M$.
+/// The constant is computed from the logarithms of the factorials, which would overflow
+/// for moderate $n$.
+///
+/// * `n` - number of points of the rule.
+/// * `a` - lower limit of the integration interval.
+/// * `b` - upper limit of the integration interval.
+/// * `max_2nth_derivative` - bound $M$ on $\left| f^{(2n)}(x) \right|$ over $\[a, b\]$.
+///
+/// # Examples
+///
+/// ```
+/// use integrate::gauss_quadrature::legendre::{legendre_error_bound, legendre_rule};
+///
+///
+/// // all the derivatives of e^x are at most e over [0, 1]
+/// let bound = legendre_error_bound(4, 0.0, 1.0, 1f64.exp());
+///
+/// let error = (legendre_rule(|x: f64| x.exp(), 0.0, 1.0, 4_usize) - (1f64.exp() - 1.0)).abs();
+///
+/// assert!(error <= bound);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `n` is zero or if `max_2nth_derivative` is negative or `NaN`.
+#[must_use]
+pub fn legendre_error_bound(n: usize, a: f64, b: f64, max_2nth_derivative: f64) -> f64 {
+    check_gauss_rule_args(n);
+
+    if max_2nth_derivative.is_nan() || max_2nth_derivative < 0.0 {
+        panic!("the bound on the derivative must be non-negative.");
+    }
+
+    if max_2nth_derivative == 0.0 {
+        return 0.0;
+    }
+
+    let ln_factorial = |m: usize| (2..=m).map(|k| (k as f64).ln()).sum::<f64>();
+
+    let ln_constant = (2 * n + 1) as f64 * (b - a).abs().ln() + 4.0 * ln_factorial(n)
+        - ((2 * n + 1) as f64).ln()
+        - 3.0 * ln_factorial(2 * n);
+
+    ln_constant.exp() * max_2nth_derivative
+}
+
+/// Precomputed n point Gauss-Legendre rule on $\[-1, 1\]$.
+///
+/// Computing the nodes and weights of a high order rule is the expensive part of
+/// [`legendre_rule`], a `GaussLegendre` computes them once and can then be applied to
+/// any number of integrands and intervals. With the `serde` feature enabled the rule can
+/// be serialized, and restored with [`GaussLegendre::from_nodes_weights`] or deserialized directly.
+///
+/// # Examples
+///
+/// ```
+/// use integrate::gauss_quadrature::legendre::GaussLegendre;
+///
+/// let rule = GaussLegendre::new(10_usize);
+///
+/// let integral = rule.integrate(|x: f64| x.exp(), 0.0, 1.0);
+/// assert!((integral - (1.0_f64.exp() - 1.0)).abs() < 1e-14);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GaussLegendre {
+    nodes: Vec<f64>,
+    weights: Vec<f64>,
+}
+
+impl GaussLegendre {
+    /// Computes the nodes and weights of the n point Gauss-Legendre rule.
+    /// * `n` - number of points of the rule.
+    pub fn new<U: Unsigned + ToPrimitive + Copy + PartialOrd>(n: U) -> Self {
+        let n = n.to_usize().unwrap();
+
+        check_gauss_rule_args(n);
+
+        // glpair enumerates the nodes in decreasing order
+        let (nodes, weights) = (1..=n)
+            .rev()
+            .map(|k| {
+                let (_, weight, x) = glpair(n, k);
+                (x, weight)
+            })
+            .unzip();
+
+        let rule = GaussLegendre { nodes, weights };
+        debug_assert_eq!(rule.validate(), Ok(()));
+
+        rule
+    }
+
+    /// Builds a rule from nodes and weights on $\[-1, 1\]$, typically obtained from a
+    /// previous call to [`GaussLegendre::new`].
+    /// * `nodes` - nodes of the rule.
+    /// * `weights` - weights of the rule, `weights[i]` being associated with `nodes[i]`.
+    pub fn from_nodes_weights(nodes: Vec<f64>, weights: Vec<f64>) -> Self {
+        if nodes.is_empty() {
+            panic!("a Gauss-Legendre rule needs at least one node.");
+        }
+
+        if nodes.len() != weights.len() {
+            panic!(
+                "nodes and weights must have the same length, got {} and {}.",
+                nodes.len(),
+                weights.len()
+            );
+        }
+
+        GaussLegendre { nodes, weights }
+    }
+
+    /// Nodes of the rule on $\[-1, 1\]$, in ascending order for rules built by [`GaussLegendre::new`].
+    pub fn nodes(&self) -> &[f64] {
+        &self.nodes
+    }
+
+    /// Weights of the rule.
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    /// Checks that all the weights are strictly positive and add up to $2$, the length of
+    /// $\[-1, 1\]$, so that once mapped to $\[a, b\]$ they add up to $b - a$.
+    ///
+    /// Both properties hold for any Gauss-Legendre rule, a rule failing them points to an
+    /// error in the computation of its nodes and weights.
+    ///
+    /// # Examples
+    /// ```
+    /// use integrate::gauss_quadrature::legendre::GaussLegendre;
+    /// use integrate::gauss_quadrature::GaussError;
+    ///
+    ///
+    /// assert_eq!(GaussLegendre::new(16_usize).validate(), Ok(()));
+    ///
+    /// let rule = GaussLegendre::from_nodes_weights(vec![-0.5, 0.5], vec![2.5, -0.5]);
+    /// assert_eq!(rule.validate(), Err(GaussError::NonPositiveWeight(1)));
+    /// ```
+    pub fn validate(&self) -> Result<(), GaussError> {
+        if let Some(i) = self.weights.iter().position(|&weight| weight <= 0.0) {
+            return Err(GaussError::NonPositiveWeight(i));
+        }
+
+        let sum: f64 = self.weights.iter().sum();
+        if (sum - 2.0).abs() > 1e-12 {
+            return Err(GaussError::WeightSum);
+        }
+
+        Ok(())
+    }
+
+    /// Approximate the integral of $f(x)$ from $a$ to $b$ with this rule.
+    /// * `func` - Integrand function of a single variable.
+    /// * `lower_limit` - lower limit of integration.
+    /// * `upper_limit` - upper limit of integration.
+    #[must_use]
+    pub fn integrate<Func, F1: Float, F2: Float>(
+        &self,
+        func: Func,
+        lower_limit: F1,
+        upper_limit: F1,
+    ) -> f64
+    where
+        Func: Fn(F1) -> F2,
+    {
+        let map = AffineMap::new(lower_limit, upper_limit);
+        let jacobian = map.jacobian().to_f64().unwrap();
+
+        // summed in node order, so that equal rules give bitwise equal results
+        self.nodes
+            .iter()
+            .zip(self.weights.iter())
+            .map(|(&x, &weight)| {
+                let x = F1::from(x).unwrap();
+                weight * func(map.map(x)).to_f64().unwrap() * jacobian
+            })
+            .sum()
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -6319,6 +6736,38 @@ mod tests {
         }
     }
 
+    // Test the numerical integration of sin(x)/x over the range [ε,10]
+    // exact value of the numerical integration is Si(10).
+    #[test]
+    fn test_composite_legendre_rule() {
+        let f = |x: f64| x.sin() / x;
+        let exact = 1.658_347_594_218_874;
+
+        let integral = composite_legendre_rule(f, f64::EPSILON, 10.0, 10_usize, 10_usize);
+
+        assert!((integral - exact).abs() < 10e-12);
+    }
+
+    // Test the numerical integration of |x - 5| over the range [0,10],
+    // exact value of the numerical integration is 25.
+    // The kink at x = 5 limits the accuracy of a single rule, while it falls on a
+    // panel boundary of the composite rule. Note that for an entire integrand such as
+    // sin(x)/x a single rule is at least as accurate at equal total points.
+    #[test]
+    fn test_composite_legendre_rule_accuracy() {
+        let f = |x: f64| (x - 5.0).abs();
+        let exact = 25.0;
+
+        let n_panels: usize = 4;
+        let points_per_panel: usize = 5;
+
+        let single = legendre_rule(f, 0.0, 10.0, n_panels * points_per_panel);
+        let composite = composite_legendre_rule(f, 0.0, 10.0, n_panels, points_per_panel);
+
+        assert!((composite - exact).abs() < (single - exact).abs());
+        assert!((composite - exact).abs() < 10e-12);
+    }
+
     // Test the numerical integration of exp(x) over the range [-1,1]
     // for varying number of Gauss-Legendre quadrature nodes l.
     // exact value of the numerical integration is e - 1/e
@@ -6345,4 +6794,142 @@ mod tests {
             assert!((integral - exact).abs() < EPSILON);
         }
     }
+
+    #[test]
+    fn test_gauss_legendre_struct() {
+        let exact = 1.0.exp() - (-1.0).exp();
+
+        for n in [10_usize, 20, 150] {
+            let rule = GaussLegendre::new(n);
+            assert_eq!(rule.nodes().len(), n);
+
+            let integral = rule.integrate(|x: f64| x.exp(), -1.0, 1.0);
+            assert!((integral - exact).abs() < 1e-13);
+            assert!((integral - legendre_rule(|x: f64| x.exp(), -1.0, 1.0, n)).abs() < 1e-13);
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        for n in [5_usize, 16, 64, 256] {
+            let rule = GaussLegendre::new(n);
+            assert_eq!(rule.validate(), Ok(()));
+
+            assert!(rule.weights().iter().all(|&weight| weight > 0.0));
+
+            // the mapped weights add up to b - a
+            let integral = rule.integrate(|_: f64| 1.0, -2.0, 5.0);
+            assert!((integral - 7.0).abs() < 1e-12);
+        }
+
+        let rule = GaussLegendre::from_nodes_weights(vec![-0.5, 0.5], vec![1.0, 0.5]);
+        assert_eq!(rule.validate(), Err(GaussError::WeightSum));
+    }
+
+    #[test]
+    fn test_from_nodes_weights() {
+        let rule = GaussLegendre::new(30_usize);
+        let copy =
+            GaussLegendre::from_nodes_weights(rule.nodes().to_vec(), rule.weights().to_vec());
+
+        assert_eq!(rule, copy);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_nodes_weights_mismatch() {
+        GaussLegendre::from_nodes_weights(vec![0.0, 0.5], vec![1.0]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let rule = GaussLegendre::new(200_usize);
+
+        let json = serde_json::to_string(&rule).unwrap();
+        let restored: GaussLegendre = serde_json::from_str(&json).unwrap();
+
+        let f = |x: f64| x.sin() / (1.0 + x * x);
+        assert_eq!(
+            rule.integrate(f, 0.0, 10.0).to_bits(),
+            restored.integrate(f, 0.0, 10.0).to_bits()
+        );
+    }
+
+    #[test]
+    fn test_legendre_rule_logspace() {
+        let f = |x: f64| 1.0 / x;
+        let (a, b) = (0.01, 1.0);
+        let exact = 100.0.ln();
+
+        let n: usize = 10;
+        let logspace_error = (legendre_rule_logspace(f, a, b, n) - exact).abs();
+        let linear_error = (legendre_rule(f, a, b, n) - exact).abs();
+
+        assert!(logspace_error < 1e-13);
+        assert!(linear_error > 1e-3);
+    }
+
+    #[test]
+    fn test_legendre_error_bound() {
+        // the 2n-th derivative of a polynomial of degree below 2n vanishes
+        assert_eq!(legendre_error_bound(3, -2.0, 5.0, 0.0), 0.0);
+        let cubic = |x: f64| x * x * x - 2.0 * x;
+        let integral = legendre_rule(cubic, -2.0, 5.0, 2_usize);
+        assert!((integral - (5.0_f64.powi(4) - 16.0) / 4.0 + (25.0 - 4.0)).abs() < 1e-12);
+
+        // e^x over [0, 1], with f^(2n) between 1 and e, the error lies between the constant
+        // and e times the constant
+        let e = 1f64.exp();
+        for n in [2_usize, 3, 4] {
+            let bound = legendre_error_bound(n, 0.0, 1.0, e);
+            let error = (legendre_rule(|x: f64| x.exp(), 0.0, 1.0, n) - (e - 1.0)).abs();
+
+            assert!(error <= bound, "n = {}: {} > {}", n, error, bound);
+            assert!(error >= bound / e * (1.0 - 1e-6), "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn test_gauss_legendre_auto_tol() {
+        let tol = 1e-10;
+        let tolerance = Tolerance::absolute(tol);
+
+        // exact from 2 points on, confirmed by the 4 point rule
+        let (integral, n_square) =
+            gauss_legendre_auto_tol(|x: f64| x * x, 0.0, 1.0, &tolerance).unwrap();
+        assert!((integral - 1.0 / 3.0).abs() < tol);
+        assert_eq!(n_square, 4);
+
+        // Runge's function, with poles at ±i/5 close to [-1, 1]
+        let runge = |x: f64| 1.0 / (1.0 + 25.0 * x * x);
+        let (integral, n_runge) = gauss_legendre_auto_tol(runge, -1.0, 1.0, &tolerance).unwrap();
+        assert!((integral - 0.4 * 5.0_f64.atan()).abs() < tol);
+        assert!(n_runge > n_square);
+
+        // a relative tolerance on a large integral
+        let (integral, _) = gauss_legendre_auto_tol(
+            |x: f64| 1e9 * runge(x),
+            -1.0,
+            1.0,
+            &Tolerance::relative(tol),
+        )
+        .unwrap();
+        assert!((integral - 1e9 * 0.4 * 5.0_f64.atan()).abs() < tol * integral);
+
+        let result = gauss_legendre_auto_tol(
+            |x: f64| x.abs().sqrt(),
+            -1.0,
+            1.0,
+            &Tolerance::absolute(1e-300),
+        );
+        assert_eq!(result, Err(GaussError::NotConverged));
+
+        // the rules are cached up to the largest order used
+        assert_eq!(cached_auto_rule(8), Arc::new(GaussLegendre::new(8_usize)));
+        assert_eq!(
+            AUTO_RULES.lock().unwrap().len(),
+            MAX_AUTO_ORDER.trailing_zeros() as usize
+        );
+    }
 }
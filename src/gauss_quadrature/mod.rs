@@ -12,10 +12,23 @@
 //! - Gauss-Hermite used to integrate a function of the form $f(x) e^{-x^2}$ over the entire x-axis, $\lbrace x \in \mathbb{R} : -\infty < x < \infty \rbrace$.
 //! - Gauss-Chebyshev First Kind used to integrate a function of the form $\frac{f(x)}{\sqrt( 1-x^2 )}$ over the interval $\[-1,1\]$.
 //! - Gauss-Chebyshev Second Kind used to integrate a function of the form $f(x) * \sqrt{ 1-x^2 }$ over the interval $\[-1,1\]$.
+//!
+//! [`triangle::integrate_triangle`] applies the Gauss-Legendre rule to the integration over a
+//! triangle, through the Duffy transformation.
+//!
+//! For every family, the nodes of a rule are generated in ascending order, and each weight
+//! stays paired with its node.
+//!
+//! When `n` is so large that some nodes or weights underflow, a warning is printed to the
+//! standard error, at most once per process for each family.
 
 mod bessel;
 pub mod chebyshev;
+pub mod family;
 pub mod hermite;
 pub mod laguerre;
 pub mod legendre;
-mod utils;
+pub mod triangle;
+pub(crate) mod utils;
+
+pub use utils::{validate_gauss_order, GaussError};
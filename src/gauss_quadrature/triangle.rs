@@ -0,0 +1,113 @@
+//! Gauss quadrature over a triangle
+//!
+//! The triangle with vertices $P_0$, $P_1$, $P_2$ is the image of the reference triangle
+//! $\lbrace (u, v) : u, v \geq 0, u + v \leq 1 \rbrace$ by the affine map
+//!
+//! ```math
+//! (x, y) = P_0 + u (P_1 - P_0) + v (P_2 - P_0)
+//! ```
+//!
+//! whose Jacobian determinant $J$ is twice the signed area of the triangle. The Duffy
+//! transformation $u = s$, $v = t (1 - s)$ then collapses the unit square
+//! $\[0, 1\] \times \[0, 1\]$ onto the reference triangle, the edge $s = 1$ being collapsed
+//! onto the vertex $(1, 0)$, with Jacobian $1 - s$, so that
+//!
+//! ```math
+//! \iint_{T} f(x, y) dx dy = |J| \int_{0}^{1} \int_{0}^{1} f\left(x(s, t), y(s, t)\right) (1 - s) dt ds
+//! ```
+//!
+//! which is approximated by the tensor product of the $n$ point Gauss-Legendre rule with
+//! itself. The rule is exact for polynomials of degree up to $2n - 2$ in $(x, y)$.
+
+use super::legendre::GaussLegendre;
+
+/// Integrates $f(x, y)$ over the triangle with the given vertices, using the Duffy
+/// transformation and the tensor product of the $n$ point Gauss-Legendre rule.
+///
+/// * `func` - Integrand function of two variables.
+/// * `vertices` - vertices of the triangle, in any order.
+/// * `n` - number of Gauss-Legendre points in each direction.
+///
+/// # Examples
+/// ```
+/// use integrate::gauss_quadrature::triangle::integrate_triangle;
+///
+///
+/// let f = |x: f64, y: f64| x * y;
+///
+/// let integral = integrate_triangle(f, [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)], 4);
+///
+/// assert!((integral - 1.0 / 24.0).abs() < 1e-15);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `n` is zero.
+#[must_use]
+pub fn integrate_triangle<Func>(func: Func, vertices: [(f64, f64); 3], n: usize) -> f64
+where
+    Func: Fn(f64, f64) -> f64,
+{
+    let rule = GaussLegendre::new(n);
+
+    let [(x0, y0), (x1, y1), (x2, y2)] = vertices;
+    let determinant = ((x1 - x0) * (y2 - y0) - (x2 - x0) * (y1 - y0)).abs();
+
+    let collapsed = |s: f64| {
+        let inner = rule.integrate(
+            |t: f64| {
+                let (u, v) = (s, t * (1.0 - s));
+                func(
+                    x0 + u * (x1 - x0) + v * (x2 - x0),
+                    y0 + u * (y1 - y0) + v * (y2 - y0),
+                )
+            },
+            0.0,
+            1.0,
+        );
+
+        (1.0 - s) * inner
+    };
+
+    determinant * rule.integrate(collapsed, 0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_area_and_centroid() {
+        let vertices = [(1.0, -1.0), (4.0, 0.5), (2.0, 3.0)];
+
+        // half the absolute value of the cross product of two edges
+        let area = 0.5 * ((3.0_f64 * 4.0) - (1.0 * 1.5)).abs();
+        let centroid_x = (1.0 + 4.0 + 2.0) / 3.0;
+
+        for n in [2_usize, 3, 5] {
+            let integral = integrate_triangle(|_, _| 1.0, vertices, n);
+            assert!((integral - area).abs() < 1e-12);
+
+            // the integral of x is the area times the abscissa of the centroid
+            let integral = integrate_triangle(|x, _| x, vertices, n);
+            assert!((integral - area * centroid_x).abs() < 1e-12);
+        }
+
+        // listing the vertices clockwise doesn't change the sign
+        let reversed = [vertices[2], vertices[1], vertices[0]];
+        let integral = integrate_triangle(|_, _| 1.0, reversed, 3);
+        assert!((integral - area).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_smooth_integrand() {
+        // ∫∫ e^{x + y} over the unit reference triangle = 1
+        let integral = integrate_triangle(
+            |x, y| (x + y).exp(),
+            [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)],
+            10,
+        );
+
+        assert!((integral - 1.0).abs() < 1e-14);
+    }
+}
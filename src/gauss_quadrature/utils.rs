@@ -1,10 +1,126 @@
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use num::Zero;
 
+/// Error returned by the `try_*` Gauss quadrature rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GaussError {
+    /// The rule was requested with zero points.
+    ZeroOrder,
+    /// The weight of the given index is not strictly positive.
+    NonPositiveWeight(usize),
+    /// The weights do not add up to the length of the interval of the rule.
+    WeightSum,
+    /// The Gauss-Laguerre integrand $f(x) e^{-x}$ is still significant at the largest
+    /// node, so that the rule misses part of its tail.
+    TailNotCaptured,
+    /// The estimates didn't stabilize within the tolerance before the maximum order.
+    NotConverged,
+}
+
+impl fmt::Display for GaussError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GaussError::ZeroOrder => write!(f, "number of steps can't be zero"),
+            GaussError::NonPositiveWeight(i) => write!(f, "weight {} is not positive", i),
+            GaussError::WeightSum => {
+                write!(f, "weights do not add up to the length of the interval")
+            }
+            GaussError::TailNotCaptured => write!(
+                f,
+                "the integrand has not decayed at the largest node, its tail is not captured"
+            ),
+            GaussError::NotConverged => {
+                write!(f, "the estimates did not converge before the maximum order")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GaussError {}
+
+/// Warning printed to the standard error at most once per process.
+///
+/// The rules check their nodes and weights on every call, so a warning printed from a
+/// loop would otherwise flood the standard error. Each family keeps its own `static`
+/// `WarnOnce`.
+pub(crate) struct WarnOnce {
+    warned: AtomicBool,
+}
+
+impl WarnOnce {
+    pub(crate) const fn new() -> Self {
+        WarnOnce {
+            warned: AtomicBool::new(false),
+        }
+    }
+
+    /// Prints `message` unless a message was already printed, returns whether it was printed.
+    pub(crate) fn warn(&self, message: &str) -> bool {
+        if self.warned.swap(true, Ordering::Relaxed) {
+            return false;
+        }
+
+        eprintln!("{}", message);
+        true
+    }
+}
+
 /// Checks integral arguments for Gauss-Laguerre rule
 ///
 /// * `n` - number of steps.
 pub fn check_gauss_rule_args(n: usize) {
+    if let Err(err) = validate_gauss_order(n) {
+        panic!("{}", err);
+    }
+}
+
+/// Checks the order of a Gauss rule, without panicking.
+///
+/// * `n` - number of points of the rule.
+///
+/// # Examples
+/// ```
+/// use integrate::gauss_quadrature::{validate_gauss_order, GaussError};
+///
+/// assert_eq!(validate_gauss_order(0), Err(GaussError::ZeroOrder));
+/// assert_eq!(validate_gauss_order(10), Ok(()));
+/// ```
+pub fn validate_gauss_order(n: usize) -> Result<(), GaussError> {
     if n.is_zero() {
-        panic!("number of steps can't be zero");
+        return Err(GaussError::ZeroOrder);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_gauss_order() {
+        assert_eq!(validate_gauss_order(0), Err(GaussError::ZeroOrder));
+        assert_eq!(validate_gauss_order(10), Ok(()));
+    }
+
+    #[test]
+    fn test_warn_once() {
+        let warning = WarnOnce::new();
+
+        assert!(warning.warn("Warning: first"));
+        for _ in 0..10 {
+            assert!(!warning.warn("Warning: repeated"));
+        }
+
+        // other families keep their own warning
+        assert!(WarnOnce::new().warn("Warning: other family"));
+    }
+
+    #[test]
+    #[should_panic(expected = "number of steps can't be zero")]
+    fn test_check_gauss_rule_args() {
+        check_gauss_rule_args(0);
     }
 }
@@ -0,0 +1,388 @@
+//! Verified Integration
+//!
+//! The rules of this crate return a point estimate of $\int_{a}^{b} f(x)dx$. For verified
+//! computing, the functions of this module instead return an [`Interval`] $\[lo, hi\]$
+//! guaranteed to contain the true integral, accounting for both the rounding errors of the
+//! weighted sum and the truncation error of the rule.
+//!
+//! The integrand, and a derivative of it, are given as interval extensions: functions
+//! mapping an interval $X$ to an interval containing $\\{ f(x) : x \in X \\}$. Every
+//! arithmetic operation on [`Interval`] rounds its lower bound down and its upper bound up,
+//! so that an interval extension is obtained by simply writing $f$ with [`Interval`] operands.
+//!
+//! On each subinterval $\[x_i, x_{i+1}\]$ of length $h$, the truncation error of the
+//! trapezoidal rule is $-\frac{h^3}{12} f^{(2)}(\xi_i)$ and the one of Simpson's rule is
+//! $-\frac{h^5}{2880} f^{(4)}(\xi_i)$ for some $\xi_i$ in $\[x_i, x_{i+1}\]$. The derivative is
+//! enclosed over the subinterval, and the magnitude of the enclosure bounds the error
+//! symmetrically about the computed sum, so the width of the result decreases like $h^2$
+//! for the trapezoidal rule and like $h^4$ for Simpson's rule, until rounding dominates.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Largest `f64` less than `x`, `x` itself if it is infinite or `NaN`.
+fn next_down(x: f64) -> f64 {
+    if x.is_nan() || x == f64::NEG_INFINITY {
+        return x;
+    }
+
+    if x == 0.0 {
+        return -f64::from_bits(1);
+    }
+
+    let bits = x.to_bits();
+
+    if x > 0.0 {
+        f64::from_bits(bits - 1)
+    } else {
+        f64::from_bits(bits + 1)
+    }
+}
+
+/// Smallest `f64` greater than `x`, `x` itself if it is infinite or `NaN`.
+fn next_up(x: f64) -> f64 {
+    -next_down(-x)
+}
+
+/// Closed interval $\[lo, hi\]$ of real numbers with outward rounded arithmetic.
+///
+/// # Examples
+/// ```
+/// use integrate::interval::Interval;
+///
+/// let x = Interval::new(1.0, 2.0);
+///
+/// // 0.1 is not representable, the result encloses the exact 0.1 * [1, 2]
+/// let y = x * Interval::point(0.1);
+///
+/// assert!(y.lo() < 0.1 && y.hi() > 0.2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+    lo: f64,
+    hi: f64,
+}
+
+impl Interval {
+    /// Interval $\[lo, hi\]$.
+    ///
+    /// * `lo` - lower bound.
+    /// * `hi` - upper bound.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo` is greater than `hi` or if either bound is `NaN`.
+    #[must_use]
+    pub fn new(lo: f64, hi: f64) -> Self {
+        if lo.is_nan() || hi.is_nan() || lo > hi {
+            panic!("lo must be less than or equal to hi, got [{}, {}].", lo, hi);
+        }
+
+        Interval { lo, hi }
+    }
+
+    /// Degenerate interval $\[x, x\]$.
+    #[must_use]
+    pub fn point(x: f64) -> Self {
+        Interval::new(x, x)
+    }
+
+    /// Lower bound of the interval.
+    #[must_use]
+    pub fn lo(&self) -> f64 {
+        self.lo
+    }
+
+    /// Upper bound of the interval.
+    #[must_use]
+    pub fn hi(&self) -> f64 {
+        self.hi
+    }
+
+    /// Width $hi - lo$ of the interval, rounded up.
+    #[must_use]
+    pub fn width(&self) -> f64 {
+        next_up(self.hi - self.lo)
+    }
+
+    /// Largest absolute value of the elements of the interval.
+    #[must_use]
+    pub fn mag(&self) -> f64 {
+        self.lo.abs().max(self.hi.abs())
+    }
+
+    /// Whether `x` belongs to the interval.
+    #[must_use]
+    pub fn contains(&self, x: f64) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    /// Smallest interval containing both `self` and `other`.
+    #[must_use]
+    pub fn hull(&self, other: Interval) -> Interval {
+        Interval::new(self.lo.min(other.lo), self.hi.max(other.hi))
+    }
+}
+
+impl Add for Interval {
+    type Output = Interval;
+
+    fn add(self, rhs: Interval) -> Interval {
+        Interval::new(next_down(self.lo + rhs.lo), next_up(self.hi + rhs.hi))
+    }
+}
+
+impl Sub for Interval {
+    type Output = Interval;
+
+    fn sub(self, rhs: Interval) -> Interval {
+        Interval::new(next_down(self.lo - rhs.hi), next_up(self.hi - rhs.lo))
+    }
+}
+
+impl Neg for Interval {
+    type Output = Interval;
+
+    fn neg(self) -> Interval {
+        Interval::new(-self.hi, -self.lo)
+    }
+}
+
+impl Mul for Interval {
+    type Output = Interval;
+
+    fn mul(self, rhs: Interval) -> Interval {
+        let products = [
+            self.lo * rhs.lo,
+            self.lo * rhs.hi,
+            self.hi * rhs.lo,
+            self.hi * rhs.hi,
+        ];
+
+        let lo = products.iter().cloned().fold(f64::INFINITY, f64::min);
+        let hi = products.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+        Interval::new(next_down(lo), next_up(hi))
+    }
+}
+
+impl Div for Interval {
+    type Output = Interval;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` contains zero.
+    fn div(self, rhs: Interval) -> Interval {
+        if rhs.contains(0.0) {
+            panic!("division by an interval containing zero.");
+        }
+
+        let quotients = [
+            self.lo / rhs.lo,
+            self.lo / rhs.hi,
+            self.hi / rhs.lo,
+            self.hi / rhs.hi,
+        ];
+
+        let lo = quotients.iter().cloned().fold(f64::INFINITY, f64::min);
+        let hi = quotients.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+        Interval::new(next_down(lo), next_up(hi))
+    }
+}
+
+fn check_enclosure_args(a: f64, b: f64, n: usize) {
+    if n == 0 {
+        panic!("number of steps can't be zero");
+    }
+
+    if !(a.is_finite() && b.is_finite()) {
+        panic!("Integral limits a and b must be finite");
+    }
+
+    if a >= b {
+        panic!("a must be strictly less than b");
+    }
+}
+
+/// Enclosures of the nodes $x_i = a + i h$, $i = 0, ..., n$, and of $h = \frac{b-a}{n}$.
+fn enclose_nodes(a: f64, b: f64, n: usize) -> (Vec<Interval>, Interval) {
+    let a = Interval::point(a);
+    let b = Interval::point(b);
+    let h = (b - a) / Interval::point(n as f64);
+
+    let mut nodes: Vec<Interval> = (0..n).map(|i| a + Interval::point(i as f64) * h).collect();
+    nodes.push(b);
+
+    (nodes, h)
+}
+
+/// This function encloses the integral of $f(x)$ from $a$ to $b$ using the trapezoidal rule.
+///
+/// * `func` - interval extension of the integrand.
+/// * `second_derivative` - interval extension of $f^{(2)}$.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_intervals` - number of subintervals.
+///
+/// # Examples
+/// ```
+/// use integrate::interval::{trapezoidal_enclosure, Interval};
+///
+/// let f = |x: Interval| x * x;
+/// let f2 = |_: Interval| Interval::point(2.0);
+///
+/// let integral = trapezoidal_enclosure(f, f2, 0.0, 1.0, 100);
+///
+/// assert!(integral.contains(1.0 / 3.0));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `n_intervals` is zero, if a limit is not finite, or if `lower_limit` is not
+/// less than `upper_limit`.
+#[must_use]
+pub fn trapezoidal_enclosure<Func, D2>(
+    func: Func,
+    second_derivative: D2,
+    lower_limit: f64,
+    upper_limit: f64,
+    n_intervals: usize,
+) -> Interval
+where
+    Func: Fn(Interval) -> Interval,
+    D2: Fn(Interval) -> Interval,
+{
+    check_enclosure_args(lower_limit, upper_limit, n_intervals);
+
+    let (nodes, h) = enclose_nodes(lower_limit, upper_limit, n_intervals);
+    let half = Interval::point(0.5);
+
+    let mut sum = half * (func(nodes[0]) + func(nodes[n_intervals]));
+    for node in &nodes[1..n_intervals] {
+        sum = sum + func(*node);
+    }
+
+    // sum of the bounds of |f''| over each subinterval
+    let mut derivative = Interval::point(0.0);
+    for x in nodes.windows(2) {
+        derivative = derivative + Interval::point(second_derivative(x[0].hull(x[1])).mag());
+    }
+
+    let error = (h * h * h * derivative / Interval::point(12.0)).hi();
+
+    h * sum + Interval::new(-error, error)
+}
+
+/// This function encloses the integral of $f(x)$ from $a$ to $b$ using Simpson's rule.
+///
+/// * `func` - interval extension of the integrand.
+/// * `fourth_derivative` - interval extension of $f^{(4)}$.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_intervals` - number of subintervals, each one being split at its midpoint.
+///
+/// # Examples
+/// ```
+/// use integrate::interval::{simpson_enclosure, Interval};
+///
+/// let f = |x: Interval| x * x * x * x;
+/// let f4 = |_: Interval| Interval::point(24.0);
+///
+/// let integral = simpson_enclosure(f, f4, 0.0, 1.0, 100);
+///
+/// assert!(integral.contains(0.2));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `n_intervals` is zero, if a limit is not finite, or if `lower_limit` is not
+/// less than `upper_limit`.
+#[must_use]
+pub fn simpson_enclosure<Func, D4>(
+    func: Func,
+    fourth_derivative: D4,
+    lower_limit: f64,
+    upper_limit: f64,
+    n_intervals: usize,
+) -> Interval
+where
+    Func: Fn(Interval) -> Interval,
+    D4: Fn(Interval) -> Interval,
+{
+    check_enclosure_args(lower_limit, upper_limit, n_intervals);
+
+    let (nodes, h) = enclose_nodes(lower_limit, upper_limit, n_intervals);
+    let half = Interval::point(0.5);
+    let two = Interval::point(2.0);
+    let four = Interval::point(4.0);
+
+    let mut sum = func(nodes[0]) + func(nodes[n_intervals]);
+    for node in &nodes[1..n_intervals] {
+        sum = sum + two * func(*node);
+    }
+    for x in nodes.windows(2) {
+        sum = sum + four * func(half * (x[0] + x[1]));
+    }
+
+    // sum of the bounds of |f''''| over each subinterval
+    let mut derivative = Interval::point(0.0);
+    for x in nodes.windows(2) {
+        derivative = derivative + Interval::point(fourth_derivative(x[0].hull(x[1])).mag());
+    }
+
+    let h2 = h * h;
+    let error = (h2 * h2 * h * derivative / Interval::point(2880.0)).hi();
+
+    h * sum / Interval::point(6.0) + Interval::new(-error, error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outward_rounding() {
+        let tenth = Interval::point(1.0) / Interval::point(10.0);
+
+        assert!(tenth.lo() < 0.1 && 0.1 < tenth.hi());
+        assert_eq!(next_up(next_down(1.0)), 1.0);
+        assert!(next_down(0.0) < 0.0 && next_up(0.0) > 0.0);
+    }
+
+    #[test]
+    fn test_square_enclosures() {
+        let f = |x: Interval| x * x;
+        let f2 = |_: Interval| Interval::point(2.0);
+        let f4 = |_: Interval| Interval::point(0.0);
+        let exact = 1.0 / 3.0;
+
+        let mut previous = f64::INFINITY;
+
+        for n in [10, 100, 1_000] {
+            let trapezoidal = trapezoidal_enclosure(f, f2, 0.0, 1.0, n);
+            assert!(trapezoidal.contains(exact));
+
+            // the error term h²/6 dominates the width
+            assert!(trapezoidal.width() < previous);
+            previous = trapezoidal.width();
+
+            // Simpson's rule is exact for quadratics, only rounding is left
+            let simpson = simpson_enclosure(f, f4, 0.0, 1.0, n);
+            assert!(simpson.contains(exact));
+            assert!(simpson.width() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_exp_enclosure() {
+        // e^x is increasing, so its interval extension is [e^lo, e^hi] widened by rounding
+        let exp = |x: Interval| Interval::new(next_down(x.lo().exp()), next_up(x.hi().exp()));
+        let exact = 1f64.exp() - 1.0;
+
+        let trapezoidal = trapezoidal_enclosure(exp, exp, 0.0, 1.0, 1_000);
+        let simpson = simpson_enclosure(exp, exp, 0.0, 1.0, 100);
+
+        assert!(trapezoidal.contains(exact) && trapezoidal.width() < 1e-6);
+        assert!(simpson.contains(exact) && simpson.width() < 1e-10);
+    }
+}
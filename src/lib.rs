@@ -9,6 +9,52 @@
 //! are Newton-Cotes formulas, Romberg's method, Gaussian quadrature, and to
 //! lesser extents Hermite's formulas and certain adaptive techniques.
 //!
+//! # Examples
+//!
+//! ```
+//! use integrate::{
+//!     adaptive_quadrature::simpson::adaptive_simpson_method,
+//!     gauss_quadrature::legendre::legendre_rule,
+//!     newton_cotes::trapezoidal::trapezoidal_rule,
+//!     romberg::romberg_method,
+//! };
+//!
+//! let f = |x: f64| x.exp();
+//! let exact = 1.0_f64.exp() - 1.0;
+//!
+//! let trapezoidal = trapezoidal_rule(f, 0.0, 1.0, 1_000_usize);
+//! let romberg = romberg_method(f, 0.0, 1.0, 10_usize);
+//! let legendre = legendre_rule(f, 0.0, 1.0, 10_usize);
+//! let adaptive = adaptive_simpson_method(f, 0.0, 1.0, 1e-3, 1e-9).unwrap();
+//!
+//! for integral in [trapezoidal, romberg, legendre, adaptive] {
+//!     assert!((integral - exact).abs() < 1e-6);
+//! }
+//! ```
+//!
+//! All the rules are marked `#[must_use]`, so the compiler warns when an integral is
+//! computed and then discarded by mistake:
+//!
+//! ```compile_fail
+//! #![deny(unused_must_use)]
+//!
+//! use integrate::newton_cotes::simpson::simpson_rule;
+//!
+//! // error: unused return value of `simpson_rule` that must be used
+//! simpson_rule(|x: f64| x * x, 0.0, 1.0, 100_usize);
+//! ```
+//!
+//! # Reversed limits
+//!
+//! As in SciPy's `quad`, the Newton-Cotes rules, Romberg's method, the adaptive Simpson
+//! method and [`integrate_by_halfwavelength`](method::integrate_by_halfwavelength) accept a
+//! lower limit greater than the upper one, and then integrate over the swapped interval and
+//! return the opposite of the result,
+//!
+//! ```math
+//! \int_{b}^{a} f(x) dx = -\int_{a}^{b} f(x) dx
+//! ```
+//!
 //! # Caveats
 //!
 //! All of the numerical integration techniques listed above assume that
@@ -43,7 +89,21 @@
 //!   to each subinterval.
 
 pub mod adaptive_quadrature;
+pub mod applications;
+pub mod convergence;
+pub mod error;
 pub mod gauss_quadrature;
+#[cfg(feature = "interval")]
+pub mod interval;
+pub mod linalg;
+pub mod method;
+pub mod monte_carlo;
 pub mod newton_cotes;
+pub mod prelude;
 pub mod romberg;
-mod utils;
+pub mod tabulated;
+#[cfg(feature = "test-util")]
+pub mod testing;
+pub mod tolerance;
+pub mod transform;
+pub mod utils;
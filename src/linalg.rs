@@ -0,0 +1,332 @@
+//! Symmetric tridiagonal eigenvalues
+//!
+//! The nodes of the Gauss-Laguerre and Gauss-Hermite rules are the eigenvalues of a
+//! symmetric tridiagonal (Jacobi) matrix
+//!
+//! ```math
+//! T = \begin{pmatrix}
+//! a_0 & b_1 &        &         \\
+//! b_1 & a_1 & \ddots  &         \\
+//!     & \ddots & \ddots & b_{n-1} \\
+//!     &        & b_{n-1} & a_{n-1}
+//! \end{pmatrix}
+//! ```
+//!
+//! which are computed here by bisection.
+//!
+//! All the eigenvalues of $T$ lie in the union of the Gershgorin discs, hence in the interval
+//! ```math
+//! \left[ \min_i \left( a_i - |b_i| - |b_{i+1}| \right), \max_i \left( a_i + |b_i| + |b_{i+1}| \right) \right]
+//! ```
+//! which is used as the starting bracket.
+//!
+//! The number of eigenvalues of $T$ less than $x$ is the number of negative terms of the
+//! Sturm sequence
+//! ```math
+//! q_0 = a_0 - x, \quad q_i = a_i - x - \frac{b_i^2}{q_{i-1}}, \quad i = 1, ..., n-1
+//! ```
+//! so the $k^{th}$ eigenvalue can be isolated by repeatedly halving a bracket and keeping
+//! the half in which the count changes. Each eigenvalue is found independently, in parallel,
+//! and only the eigenvalues in a given interval can be computed at a lower cost with
+//! [`TridiagonalSymmetricFloatMatrix::eigenvalues_in_range`].
+
+// extern crate test;
+
+use std::cmp::Ordering;
+
+use num::Float;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::utils::math::{max, min};
+
+/// Maximum number of halvings of a bracket, enough to shrink the widest `f64` bracket down
+/// to the smallest positive `f64`. Past this, the midpoint of the bracket is returned.
+const MAX_BISECTIONS: usize = 4_096;
+
+/// Real symmetric tridiagonal matrix, stored as its diagonal and off-diagonal.
+///
+/// # Examples
+///
+/// ```
+/// use integrate::linalg::TridiagonalSymmetricFloatMatrix;
+///
+/// // eigenvalues of [[2, 1], [1, 2]] are 3 and 1
+/// let matrix = TridiagonalSymmetricFloatMatrix::new(vec![2.0_f64, 2.0], vec![0.0, 1.0]);
+///
+/// let eigenvalues = matrix.eigenvalues();
+/// assert!((eigenvalues[0] - 3.0).abs() < 1e-6);
+/// assert!((eigenvalues[1] - 1.0).abs() < 1e-6);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TridiagonalSymmetricFloatMatrix<F: Float> {
+    diagonal: Vec<F>,
+    offdiagonal: Vec<F>,
+}
+
+impl<F: Float + Send + Sync> TridiagonalSymmetricFloatMatrix<F> {
+    /// Creates the symmetric tridiagonal matrix with the given diagonal and off-diagonal.
+    ///
+    /// * `diagonal` - the $n$ diagonal entries $a_0, ..., a_{n-1}$.
+    /// * `offdiagonal` - the $n$ entries $b_0, ..., b_{n-1}$ where $b_i$ is the entry at
+    ///   $(i, i-1)$ and $(i-1, i)$, conventionally $b_0 = 0$.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `diagonal` is empty or if `offdiagonal` and `diagonal` have different lengths.
+    pub fn new(diagonal: Vec<F>, offdiagonal: Vec<F>) -> TridiagonalSymmetricFloatMatrix<F> {
+        if diagonal.is_empty() {
+            panic!("diagonal of a tridiagonal matrix can't be empty");
+        }
+
+        if offdiagonal.len() != diagonal.len() {
+            panic!(
+                "offdiagonal must have the same length as diagonal (with offdiagonal[0] = 0), got {} and {}",
+                offdiagonal.len(),
+                diagonal.len()
+            );
+        }
+
+        TridiagonalSymmetricFloatMatrix {
+            diagonal,
+            offdiagonal,
+        }
+    }
+
+    /// Computes all the eigenvalues of the matrix, in decreasing order.
+    #[must_use]
+    pub fn eigenvalues(&self) -> Vec<F> {
+        let n = self.diagonal.len();
+        let eigenvalues: Vec<F> = (0..n)
+            .into_par_iter()
+            .map(|k| self.kth_eigenvalue(k))
+            .collect();
+        eigenvalues
+    }
+
+    /// Computes the eigenvalues $\lambda$ of the matrix such that `lower <= `$\lambda$` < upper`,
+    /// in decreasing order.
+    ///
+    /// Only the eigenvalues in the interval are bisected, starting from the interval itself,
+    /// which is cheaper than [`TridiagonalSymmetricFloatMatrix::eigenvalues`] when few of
+    /// them are needed.
+    ///
+    /// * `lower` - lower bound of the interval.
+    /// * `upper` - upper bound of the interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use integrate::linalg::TridiagonalSymmetricFloatMatrix;
+    ///
+    /// // eigenvalues are 1, 2 and 3
+    /// let matrix = TridiagonalSymmetricFloatMatrix::new(vec![1.0, 2.0, 3.0], vec![0.0; 3]);
+    ///
+    /// let eigenvalues = matrix.eigenvalues_in_range(1.5, 10.0);
+    /// assert_eq!(eigenvalues.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn eigenvalues_in_range(&self, lower: F, upper: F) -> Vec<F> {
+        if lower.partial_cmp(&upper) != Some(Ordering::Less) {
+            return vec![];
+        }
+
+        let n = self.diagonal.len();
+
+        // eigenvalues are numbered in decreasing order, and
+        // the k-th one is less than x iff n - k eigenvalues are.
+        let nb_lt_lower = self.nb_eigenvalues_lt_x(lower);
+        let nb_lt_upper = self.nb_eigenvalues_lt_x(upper);
+
+        (n - nb_lt_upper..n - nb_lt_lower)
+            .into_par_iter()
+            .map(|k| self.bisect(k, lower, upper))
+            .collect()
+    }
+
+    fn kth_eigenvalue(&self, k: usize) -> F {
+        let (xlower, xupper) = self.gershgorin_bounds();
+        self.bisect(k, xlower, xupper)
+    }
+
+    fn bisect(&self, k: usize, mut xlower: F, mut xupper: F) -> F {
+        let n = self.diagonal.len();
+        let epsilon = F::epsilon();
+        let two = F::one() + F::one();
+
+        // relative tolerance, floored so that it never vanishes around a zero eigenvalue
+        let tolerance = |scale: F, xlower: F, xupper: F| {
+            let tolerance = scale * epsilon * (xupper.abs() + xlower.abs());
+            max(tolerance, F::min_positive_value())
+        };
+
+        let mut tol = tolerance(two, xlower, xupper);
+
+        for _ in 0..MAX_BISECTIONS {
+            if (xupper - xlower).abs() <= tol {
+                break;
+            }
+
+            let xmid = (xupper + xlower) / two;
+
+            let nb_eig_lt_xmid = self.nb_eigenvalues_lt_x(xmid);
+
+            if nb_eig_lt_xmid >= n - k {
+                xupper = xmid;
+            } else {
+                xlower = xmid;
+            }
+
+            tol = tolerance(F::one(), xlower, xupper);
+        }
+
+        (xlower + xupper) / two
+    }
+
+    fn gershgorin_bounds(&self) -> (F, F) {
+        let n = self.diagonal.len();
+
+        let (lower_bound, upper_bound) = (0..n - 1)
+            .into_par_iter()
+            .map(|i| {
+                let x = self.offdiagonal[i].abs() + self.offdiagonal[i + 1].abs();
+                (self.diagonal[i] - x, self.diagonal[i] + x)
+            })
+            .reduce(
+                || {
+                    (
+                        self.diagonal[n - 1] - self.offdiagonal[n - 1].abs(),
+                        self.diagonal[n - 1] + self.offdiagonal[n - 1].abs(),
+                    )
+                },
+                |(l1, u1), (l2, u2)| (min(l1, l2), max(u1, u2)),
+            );
+
+        (lower_bound, upper_bound)
+    }
+
+    fn nb_eigenvalues_lt_x(&self, x: F) -> usize {
+        let mut q = F::one();
+        let epsilon = F::from(f64::EPSILON).unwrap();
+        let mut k: usize = 0;
+        let n = self.diagonal.len();
+
+        for i in 0..n {
+            q = if q.is_zero() {
+                self.diagonal[i] - x - self.offdiagonal[i].abs() / epsilon
+            } else {
+                self.diagonal[i] - x - self.offdiagonal[i].powi(2) / q
+            };
+
+            if q.is_sign_negative() {
+                k += 1;
+            }
+        }
+
+        k
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::*;
+    // use test::Bencher;
+
+    #[test]
+    fn test_tdsf_matrix() {
+        let n: usize = 1_000;
+        let diagonal: Vec<f64> = (1..=n).map(|e| e.pow(2) as f64).collect();
+        let offdiagonal: Vec<f64> = (0..n).map(|e| e.pow(4) as f64).collect();
+        let matrix = TridiagonalSymmetricFloatMatrix::new(diagonal, offdiagonal);
+
+        let _ = matrix.eigenvalues();
+    }
+
+    #[test]
+    #[should_panic(expected = "offdiagonal must have the same length as diagonal")]
+    fn test_mismatched_offdiagonal() {
+        let diagonal: Vec<f64> = vec![2.0; 5];
+        let offdiagonal: Vec<f64> = vec![-1.0; 4];
+
+        TridiagonalSymmetricFloatMatrix::new(diagonal, offdiagonal);
+    }
+
+    #[test]
+    fn test_laplacian_eigenvalues() {
+        let n: usize = 50;
+        let diagonal: Vec<f64> = vec![2.0; n];
+        let mut offdiagonal: Vec<f64> = vec![-1.0; n];
+        offdiagonal[0] = 0.0;
+        let matrix = TridiagonalSymmetricFloatMatrix::new(diagonal, offdiagonal);
+
+        // 2 - 2cos(kπ/(n+1)), k = n, ..., 1 in decreasing order
+        let exact: Vec<f64> = (1..=n)
+            .rev()
+            .map(|k| 2.0 - 2.0 * (k as f64 * PI / (n + 1) as f64).cos())
+            .collect();
+
+        let eigenvalues = matrix.eigenvalues();
+        assert_eq!(eigenvalues.len(), n);
+        for (computed, exact) in eigenvalues.iter().zip(exact.iter()) {
+            assert!((computed - exact).abs() < 1e-6);
+        }
+
+        let (lower, upper) = (1.0, 3.0);
+        let in_range = matrix.eigenvalues_in_range(lower, upper);
+        let exact_in_range: Vec<f64> = exact
+            .into_iter()
+            .filter(|&e| lower <= e && e < upper)
+            .collect();
+
+        assert_eq!(in_range.len(), exact_in_range.len());
+        for (computed, exact) in in_range.iter().zip(exact_in_range.iter()) {
+            assert!((computed - exact).abs() < 1e-6);
+        }
+
+        assert!(matrix.eigenvalues_in_range(4.0, 5.0).is_empty());
+        assert_eq!(matrix.eigenvalues_in_range(-1.0, 5.0).len(), n);
+    }
+
+    #[test]
+    fn test_gershgorin_bounds_with_nan() {
+        let diagonal: Vec<f64> = vec![2.0, f64::NAN, 2.0, 2.0];
+        let offdiagonal: Vec<f64> = vec![0.0, 0.0, -1.0, -1.0];
+        let matrix = TridiagonalSymmetricFloatMatrix::new(diagonal, offdiagonal);
+
+        // the NaN row is ignored, bounds come from the other rows
+        let (lower, upper) = matrix.gershgorin_bounds();
+        assert_eq!((lower, upper), (0.0, 4.0));
+    }
+
+    #[test]
+    fn test_zero_eigenvalue_terminates() {
+        // eigenvalues of [[1, 1], [1, 1]] are 2 and 0
+        let matrix = TridiagonalSymmetricFloatMatrix::new(vec![1.0_f64, 1.0], vec![0.0, 1.0]);
+
+        let eigenvalues = matrix.eigenvalues();
+        assert!((eigenvalues[0] - 2.0).abs() < 1e-6);
+        assert!(eigenvalues[1].abs() < 1e-6);
+
+        // zero matrix, and a bracket shrinking onto 0 from one side
+        let matrix = TridiagonalSymmetricFloatMatrix::new(vec![0.0_f32; 3], vec![0.0; 3]);
+        assert_eq!(matrix.eigenvalues(), vec![0.0; 3]);
+
+        let matrix = TridiagonalSymmetricFloatMatrix::new(vec![0.0_f64, 1.0], vec![0.0; 2]);
+        let eigenvalues = matrix.eigenvalues_in_range(0.0, 0.5);
+        assert_eq!(eigenvalues.len(), 1);
+        assert!(eigenvalues[0].abs() < 1e-300);
+    }
+
+    // #[bench]
+    // fn bench_eigenvalues(bencher: &mut Bencher) {
+    //     let n: usize = 1_000;
+    //     let diagonal: Vec<f64> = (1..=n).map(|e| e.pow(2) as f64).collect();
+    //     let offdiagonal: Vec<f64> = (0..n).map(|e| e.pow(4) as f64).collect();
+    //     let matrix = TridiagonalSymmetricFloatMatrix::new(diagonal, offdiagonal);
+
+    //     bencher.iter(|| {
+    //         matrix.eigenvalues();
+    //     })
+    // }
+}
@@ -0,0 +1,1068 @@
+//! Integration methods
+//!
+//! [`Method`] names the rules of this crate that approximate the integral of a function
+//! $f(x)$ over a closed and bounded interval $\[a,b\]$ given a number of steps $n$, so that
+//! they can be selected at runtime and passed to the helpers that work with any rule.
+//!
+//! For the Newton-Cotes rules, $n$ is the number of subintervals, while for the
+//! Gauss-Legendre rule it is the number of points.
+//!
+//! [`integrate_by_halfwavelength`] applies a rule to each half wavelength of an oscillatory
+//! integrand separately, as advised in the caveats of the crate documentation.
+//!
+//! [`compare_methods`] runs every rule on the same integrand, and [`ComparisonTable`]
+//! displays the results side by side.
+//!
+//! [`integrate_piecewise`] applies a rule to each segment between breakpoints, evaluating
+//! the integrand once at each breakpoint.
+//!
+//! [`integrate_with_antiderivative_check`] compares the result of a rule with the value
+//! given by an antiderivative, to validate an integrand and antiderivative pair.
+//!
+//! [`top_contributors`] lists the nodes contributing the most to the approximation of a
+//! rule, showing where the integrand matters.
+//!
+//! [`integrate_nd`] nests a rule in each dimension to integrate over a box, and
+//! [`integrate_nd_mixed`] a different rule in each dimension.
+//!
+//! [`precision_comparison`] runs a rule on the `f32` and `f64` versions of an integrand, to
+//! measure the precision lost by working in single precision.
+
+use std::fmt;
+
+use num::Float;
+
+use crate::gauss_quadrature::legendre::{legendre_rule, GaussLegendre};
+use crate::newton_cotes::{
+    newton::newton_rule, rectangle::rectangle_rule, simpson::simpson_rule,
+    trapezoidal::trapezoidal_rule, utils::oriented,
+};
+use crate::utils::affine::AffineMap;
+
+/// Maximum number of chunks, hence of progress reports, of [`Method::integrate_with_progress`].
+pub const PROGRESS_CHUNKS: usize = 100;
+
+/// A rule integrating $f(x)$ over $\[a,b\]$ with $n$ steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Method {
+    /// Rectangle rule, see [`rectangle_rule`].
+    Rectangle,
+    /// Trapezoidal rule, see [`trapezoidal_rule`].
+    Trapezoidal,
+    /// Simpson's rule, see [`simpson_rule`].
+    Simpson,
+    /// Newton's 3/8 rule, see [`newton_rule`].
+    Newton,
+    /// Gauss-Legendre rule, see [`legendre_rule`].
+    GaussLegendre,
+}
+
+impl Method {
+    /// All the rules, in increasing order of sophistication.
+    pub const ALL: [Method; 5] = [
+        Method::Rectangle,
+        Method::Trapezoidal,
+        Method::Simpson,
+        Method::Newton,
+        Method::GaussLegendre,
+    ];
+
+    /// Integrates $f(x)$ from $a$ to $b$ using this rule with `n` steps.
+    ///
+    /// * `func` - Integrand function of a single variable.
+    /// * `lower_limit` - lower limit of the integration interval.
+    /// * `upper_limit` - upper limit of the integration interval.
+    /// * `n` - number of subintervals, or number of points for Gauss-Legendre.
+    ///
+    /// # Examples
+    /// ```
+    /// use integrate::method::Method;
+    ///
+    ///
+    /// let square = |x: f64| x * x;
+    ///
+    /// let integral = Method::Simpson.integrate(square, 0.0, 1.0, 100);
+    /// ```
+    #[must_use]
+    pub fn integrate<Func, F1: Float + Sync, F2: Float + Send + Sync>(
+        &self,
+        func: Func,
+        lower_limit: F1,
+        upper_limit: F1,
+        n: usize,
+    ) -> f64
+    where
+        Func: Fn(F1) -> F2 + Sync,
+    {
+        match self {
+            Method::Rectangle => rectangle_rule(func, lower_limit, upper_limit, n),
+            Method::Trapezoidal => trapezoidal_rule(func, lower_limit, upper_limit, n),
+            Method::Simpson => simpson_rule(func, lower_limit, upper_limit, n),
+            Method::Newton => newton_rule(func, lower_limit, upper_limit, n),
+            Method::GaussLegendre => legendre_rule(func, lower_limit, upper_limit, n),
+        }
+    }
+
+    /// Integrates $f(x)$ from $a$ to $b$ using this rule with `n` steps, reporting progress.
+    ///
+    /// For the Newton-Cotes rules, the `n` subintervals are processed in up to
+    /// [`PROGRESS_CHUNKS`] consecutive chunks, each chunk being integrated by the rule, and
+    /// `progress` is called after each chunk with the fraction of the subintervals processed.
+    /// The Gauss-Legendre rule can't be split, `progress` is called once with $1$.
+    ///
+    /// `progress` is only called from the calling thread.
+    ///
+    /// * `func` - Integrand function of a single variable.
+    /// * `lower_limit` - lower limit of the integration interval.
+    /// * `upper_limit` - upper limit of the integration interval.
+    /// * `n` - number of subintervals, or number of points for Gauss-Legendre.
+    /// * `progress` - optional progress callback.
+    ///
+    /// # Examples
+    /// ```
+    /// use integrate::method::Method;
+    ///
+    ///
+    /// let square = |x: f64| x * x;
+    /// let report = |fraction: f64| println!("{:.0}%", 100.0 * fraction);
+    ///
+    /// let integral = Method::Simpson.integrate_with_progress(square, 0.0, 1.0, 1_000_000, Some(&report));
+    /// ```
+    #[must_use]
+    pub fn integrate_with_progress<Func, F1: Float + Sync, F2: Float + Send + Sync>(
+        &self,
+        func: Func,
+        lower_limit: F1,
+        upper_limit: F1,
+        n: usize,
+        progress: Option<&dyn Fn(f64)>,
+    ) -> f64
+    where
+        Func: Fn(F1) -> F2 + Sync,
+    {
+        let progress = match progress {
+            Some(progress) => progress,
+            None => return self.integrate(func, lower_limit, upper_limit, n),
+        };
+
+        if *self == Method::GaussLegendre || n == 0 {
+            let integral = self.integrate(func, lower_limit, upper_limit, n);
+            progress(1.0);
+            return integral;
+        }
+
+        let nb_chunks = PROGRESS_CHUNKS.min(n);
+
+        // left end of the chunk starting at subinterval `step`
+        let boundary = |step: usize| {
+            if step == n {
+                upper_limit
+            } else {
+                lower_limit
+                    + (upper_limit - lower_limit) * F1::from(step).unwrap() / F1::from(n).unwrap()
+            }
+        };
+
+        let mut integral = 0.0;
+
+        for chunk in 0..nb_chunks {
+            let start = chunk * n / nb_chunks;
+            let end = (chunk + 1) * n / nb_chunks;
+
+            integral += self.integrate(&func, boundary(start), boundary(end), end - start);
+
+            progress(end as f64 / n as f64);
+        }
+
+        integral
+    }
+
+    /// Order $p$ of the rule: the error behaves like $K h^p$ where $h = \frac{b-a}{n}$.
+    ///
+    /// Returns `None` for the Gauss-Legendre rule, whose error does not decrease
+    /// algebraically with $n$ but with the $2n^{th}$ derivative of $f$.
+    #[must_use]
+    pub fn order(&self) -> Option<i32> {
+        match self {
+            Method::Rectangle | Method::Trapezoidal => Some(2),
+            Method::Simpson | Method::Newton => Some(4),
+            Method::GaussLegendre => None,
+        }
+    }
+
+    /// Nodes $x_i$ and weights $w_i$ of the rule with $n$ steps on $\[a,b\]$, so that the
+    /// rule approximates the integral by $\sum_i w_i f(x_i)$.
+    ///
+    /// The nodes are listed in ascending order, and a node shared by two subintervals of a
+    /// closed rule appears once, with the sum of its weights.
+    ///
+    /// * `a` - lower limit of the integration interval.
+    /// * `b` - upper limit of the integration interval.
+    /// * `n` - number of subintervals, or number of points for Gauss-Legendre.
+    ///
+    /// # Examples
+    /// ```
+    /// use integrate::method::Method;
+    ///
+    ///
+    /// let pairs = Method::Trapezoidal.nodes_weights(0.0, 1.0, 2);
+    ///
+    /// assert_eq!(pairs, vec![(0.0, 0.25), (0.5, 0.5), (1.0, 0.25)]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    #[must_use]
+    pub fn nodes_weights(&self, a: f64, b: f64, n: usize) -> Vec<(f64, f64)> {
+        if n == 0 {
+            panic!("n must be at least 1.");
+        }
+
+        let h = (b - a) / n as f64;
+
+        // closed rule splitting each subinterval in `m` parts, with the weights `pattern`
+        // of the nodes of a subinterval, its last node excluded, in units of `h`
+        let closed = |m: usize, pattern: &[f64]| {
+            (0..=m * n)
+                .map(|k| {
+                    let x = a + k as f64 * h / m as f64;
+                    let weight = if k == 0 || k == m * n {
+                        pattern[0]
+                    } else if k % m == 0 {
+                        2.0 * pattern[0]
+                    } else {
+                        pattern[k % m]
+                    };
+
+                    (x, weight * h)
+                })
+                .collect()
+        };
+
+        match self {
+            Method::Rectangle => (0..n).map(|i| (a + (i as f64 + 0.5) * h, h)).collect(),
+            Method::Trapezoidal => closed(1, &[0.5]),
+            Method::Simpson => closed(2, &[1.0 / 6.0, 4.0 / 6.0]),
+            Method::Newton => closed(3, &[1.0 / 8.0, 3.0 / 8.0, 3.0 / 8.0]),
+            Method::GaussLegendre => {
+                let rule = GaussLegendre::new(n);
+                let map = AffineMap::new(a, b);
+
+                rule.nodes()
+                    .iter()
+                    .zip(rule.weights())
+                    .map(|(&t, &w)| (map.map(t), map.jacobian() * w))
+                    .collect()
+            }
+        }
+    }
+}
+
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Method::Rectangle => "Rectangle",
+            Method::Trapezoidal => "Trapezoidal",
+            Method::Simpson => "Simpson",
+            Method::Newton => "Newton 3/8",
+            Method::GaussLegendre => "Gauss-Legendre",
+        };
+        f.pad(name)
+    }
+}
+
+/// Integrates $f(x)$ from $a$ to $b$ with every rule of [`Method::ALL`] using `n` steps.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n` - number of subintervals, or number of points for Gauss-Legendre.
+///
+/// Wrap the results in a [`ComparisonTable`] to print them side by side.
+///
+/// # Examples
+/// ```
+/// use integrate::method::{compare_methods, ComparisonTable};
+///
+///
+/// let f = |x: f64| x.exp();
+///
+/// let results = compare_methods(f, 0.0, 1.0, 100);
+///
+/// println!("{}", ComparisonTable(&results));
+/// ```
+#[must_use]
+pub fn compare_methods<Func, F1: Float + Sync, F2: Float + Send + Sync>(
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    n: usize,
+) -> Vec<(Method, f64)>
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    Method::ALL
+        .iter()
+        .map(|method| {
+            let integral = method.integrate(&func, lower_limit, upper_limit, n);
+            (*method, integral)
+        })
+        .collect()
+}
+
+/// Integrates the `f32` and `f64` versions of the same integrand from $a$ to $b$ with `rule`
+/// using `n` steps, and returns both results along with their absolute difference.
+///
+/// * `f32_func` - Integrand function computed in single precision.
+/// * `f64_func` - Integrand function computed in double precision.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n` - number of subintervals, or number of points for Gauss-Legendre.
+/// * `rule` - integration method.
+///
+/// In the single precision run, the limits, the nodes and the values of the integrand are
+/// `f32`, the difference is therefore the precision lost by choosing `f32` over `f64`.
+///
+/// # Examples
+/// ```
+/// use integrate::method::{precision_comparison, Method};
+///
+///
+/// let (single, double, difference) = precision_comparison(
+///     |x: f32| x.exp(),
+///     |x: f64| x.exp(),
+///     0.0,
+///     1.0,
+///     100,
+///     Method::Simpson,
+/// );
+///
+/// assert_eq!(difference, (single as f64 - double).abs());
+/// ```
+#[must_use]
+pub fn precision_comparison<Func32, Func64>(
+    f32_func: Func32,
+    f64_func: Func64,
+    lower_limit: f64,
+    upper_limit: f64,
+    n: usize,
+    rule: Method,
+) -> (f32, f64, f64)
+where
+    Func32: Fn(f32) -> f32 + Sync,
+    Func64: Fn(f64) -> f64 + Sync,
+{
+    let single = rule.integrate(f32_func, lower_limit as f32, upper_limit as f32, n) as f32;
+    let double = rule.integrate(f64_func, lower_limit, upper_limit, n);
+
+    (single, double, (f64::from(single) - double).abs())
+}
+
+/// Displays the results of [`compare_methods`] as a table, one rule per row.
+///
+/// ```text
+/// method         | integral
+/// ---------------+-----------------------
+/// Rectangle      | 1.7182803867362676e0
+/// ...
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ComparisonTable<'a>(pub &'a [(Method, f64)]);
+
+impl fmt::Display for ComparisonTable<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{:<14} | integral", "method")?;
+        writeln!(f, "{:-<15}+{:-<23}", "", "")?;
+
+        for (method, integral) in self.0 {
+            writeln!(f, "{:<14} | {:e}", method, integral)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Integrates an oscillatory $f(x)$ from $a$ to $b$ by applying `rule` to each half wavelength.
+///
+/// For an integrand of dominant angular frequency $\omega$, the half wavelength is
+/// $\frac{\pi}{\omega}$. $\[a, b\]$ is split into consecutive pieces of that length starting
+/// at $a$, the last one being shorter if $b - a$ is not a multiple of $\frac{\pi}{\omega}$,
+/// and `rule` is applied to each piece with `points_per_halfwave` steps.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `omega` - dominant angular frequency $\omega$ of the integrand.
+/// * `rule` - integration method used on each half wavelength.
+/// * `points_per_halfwave` - number of subintervals, or number of points for
+///   Gauss-Legendre, per half wavelength.
+///
+/// # Examples
+/// ```
+/// use integrate::method::{integrate_by_halfwavelength, Method};
+///
+///
+/// let f = |x: f64| (10.0 * x).sin();
+///
+/// let integral = integrate_by_halfwavelength(f, 0.0, 1.0, 10.0, Method::GaussLegendre, 10);
+///
+/// assert!((integral - (1.0 - 10f64.cos()) / 10.0).abs() < 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `omega` is not positive and finite, or if `points_per_halfwave` is zero.
+#[must_use]
+pub fn integrate_by_halfwavelength<Func, F1: Float + Sync, F2: Float + Send + Sync>(
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    omega: F1,
+    rule: Method,
+    points_per_halfwave: usize,
+) -> f64
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    if !(omega > F1::zero() && omega.is_finite()) {
+        panic!("omega must be positive and finite.");
+    }
+
+    if points_per_halfwave == 0 {
+        panic!("points_per_halfwave must be at least 1.");
+    }
+
+    let (lower_limit, upper_limit, sign) = oriented(lower_limit, upper_limit);
+
+    let halfwave = F1::from(std::f64::consts::PI).unwrap() / omega;
+    let nb_pieces = ((upper_limit - lower_limit) / halfwave)
+        .ceil()
+        .to_usize()
+        .expect("failed to convert the number of half wavelengths");
+
+    let integral: f64 = (0..nb_pieces)
+        .map(|k| {
+            let a = lower_limit + halfwave * F1::from(k).unwrap();
+            let b = if k + 1 == nb_pieces {
+                upper_limit
+            } else {
+                lower_limit + halfwave * F1::from(k + 1).unwrap()
+            };
+
+            rule.integrate(&func, a, b, points_per_halfwave)
+        })
+        .sum();
+
+    sign * integral
+}
+
+/// Integrates $f(x)$ over consecutive segments $\[x_0, x_1\], \dots, \[x_{k-1}, x_k\]$, applying
+/// `rule` to each segment, and returns the sum.
+///
+/// Splitting the interval at the points where $f$ or one of its derivatives is
+/// discontinuous keeps the rule from straddling them. The closed rules, trapezoidal,
+/// Simpson and Newton 3/8, evaluate $f$ at both ends of each segment: $f$ is evaluated
+/// once at every breakpoint and these values are shared by the adjacent segments, saving
+/// $k - 1$ evaluations.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `breakpoints` - strictly increasing breakpoints $x_0, \dots, x_k$.
+/// * `n` - number of subintervals, or number of points for Gauss-Legendre, per segment.
+/// * `rule` - integration method used on each segment.
+///
+/// # Examples
+/// ```
+/// use integrate::method::{integrate_piecewise, Method};
+///
+///
+/// let f = |x: f64| x.abs();
+///
+/// let integral = integrate_piecewise(f, &[-1.0, 0.0, 2.0], 10, Method::Trapezoidal);
+///
+/// assert!((integral - 2.5).abs() < 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics if fewer than two breakpoints are given, if they are not strictly increasing, or
+/// if `n` is zero.
+#[must_use]
+pub fn integrate_piecewise<Func>(func: Func, breakpoints: &[f64], n: usize, rule: Method) -> f64
+where
+    Func: Fn(f64) -> f64 + Sync,
+{
+    if breakpoints.len() < 2 {
+        panic!("at least two breakpoints are required.");
+    }
+
+    if breakpoints.windows(2).any(|w| w[0] >= w[1]) {
+        panic!("breakpoints must be strictly increasing.");
+    }
+
+    if n == 0 {
+        panic!("n must be at least 1.");
+    }
+
+    if let Method::Rectangle | Method::GaussLegendre = rule {
+        // open rules, no node is shared between segments
+        return breakpoints
+            .windows(2)
+            .map(|limits| rule.integrate(&func, limits[0], limits[1], n))
+            .sum();
+    }
+
+    let values: Vec<f64> = breakpoints.iter().map(|&x| func(x)).collect();
+
+    breakpoints
+        .windows(2)
+        .zip(values.windows(2))
+        .map(|(limits, values)| {
+            let (a, b) = (limits[0], limits[1]);
+
+            // nodes are at least (b - a) / 3n apart, the end nodes are told apart from
+            // the others even when a + n h rounds to a value other than b
+            let margin = (b - a) / (6 * n) as f64;
+
+            let segment = |x: f64| {
+                if (x - a).abs() < margin {
+                    values[0]
+                } else if (b - x).abs() < margin {
+                    values[1]
+                } else {
+                    func(x)
+                }
+            };
+
+            rule.integrate(segment, a, b, n)
+        })
+        .sum()
+}
+
+/// Integrates $f(x)$ from $a$ to $b$ with `rule`, and returns the result along with its
+/// distance to $F(b) - F(a)$, $F$ being an antiderivative of $f$.
+///
+/// A residual much larger than the error expected from the rule points to a mistake in
+/// either the integrand or the antiderivative.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `antiderivative` - antiderivative $F$ of `func`.
+/// * `a` - lower limit of the integration interval.
+/// * `b` - upper limit of the integration interval.
+/// * `n` - number of subintervals, or number of points for Gauss-Legendre.
+/// * `rule` - integration method.
+///
+/// # Examples
+/// ```
+/// use integrate::method::{integrate_with_antiderivative_check, Method};
+///
+///
+/// let (integral, residual) =
+///     integrate_with_antiderivative_check(|x: f64| x * x, |x: f64| x.powi(3) / 3.0, 0.0, 1.0, 10, Method::Simpson);
+///
+/// assert!((integral - 1.0 / 3.0).abs() < 1e-15);
+/// assert!(residual < 1e-15);
+/// ```
+#[must_use]
+pub fn integrate_with_antiderivative_check<Func, Anti>(
+    func: Func,
+    antiderivative: Anti,
+    a: f64,
+    b: f64,
+    n: usize,
+    rule: Method,
+) -> (f64, f64)
+where
+    Func: Fn(f64) -> f64 + Sync,
+    Anti: Fn(f64) -> f64,
+{
+    let numerical = rule.integrate(func, a, b, n);
+
+    (
+        numerical,
+        (numerical - (antiderivative(b) - antiderivative(a))).abs(),
+    )
+}
+
+/// Returns the `k` pairs $(x_i, w_i f(x_i))$ of the rule with $n$ steps on $\[a,b\]$ whose
+/// contributions $w_i f(x_i)$ to the approximation are the largest in absolute value,
+/// sorted by decreasing absolute value.
+///
+/// The nodes and weights are those of [`Method::nodes_weights`]. Fewer than `k` pairs are
+/// returned if the rule has fewer nodes.
+///
+/// * `rule` - integration method.
+/// * `func` - Integrand function of a single variable.
+/// * `a` - lower limit of the integration interval.
+/// * `b` - upper limit of the integration interval.
+/// * `n` - number of subintervals, or number of points for Gauss-Legendre.
+/// * `k` - number of pairs returned.
+///
+/// # Examples
+/// ```
+/// use integrate::method::{top_contributors, Method};
+///
+///
+/// let f = |x: f64| x * x;
+///
+/// let top = top_contributors(Method::Simpson, f, 0.0, 1.0, 10, 1);
+///
+/// // x = 0.95 has weight 4h/6 and is the largest contribution
+/// assert!((top[0].0 - 0.95).abs() < 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `n` is zero.
+#[must_use]
+pub fn top_contributors<Func>(
+    rule: Method,
+    func: Func,
+    a: f64,
+    b: f64,
+    n: usize,
+    k: usize,
+) -> Vec<(f64, f64)>
+where
+    Func: Fn(f64) -> f64,
+{
+    let mut contributions: Vec<(f64, f64)> = rule
+        .nodes_weights(a, b, n)
+        .into_iter()
+        .map(|(x, w)| (x, w * func(x)))
+        .collect();
+
+    contributions.sort_by(|left, right| right.1.abs().total_cmp(&left.1.abs()));
+    contributions.truncate(k);
+
+    contributions
+}
+
+/// Integrates $f(x_1, \dots, x_d)$ over the box $\[a_1, b_1\] \times \dots \times \[a_d, b_d\]$
+/// by nesting `rule` with `n_per_dim` steps in each dimension.
+///
+/// * `func` - Integrand function of the point $(x_1, \dots, x_d)$.
+/// * `lowers` - lower limits $a_1, \dots, a_d$.
+/// * `uppers` - upper limits $b_1, \dots, b_d$.
+/// * `n_per_dim` - number of subintervals, or number of points for Gauss-Legendre, in each
+///   dimension.
+/// * `rule` - integration method applied in each dimension.
+///
+/// The integral over $x_1$ is computed by `rule`, whose integrand is the integral over
+/// $x_2$, and so on down to $x_d$. A rule evaluating its integrand at $m$ nodes in one
+/// dimension thus evaluates $f$ at $m^d$ points, e.g. $(2n + 1)^d$ for Simpson's rule: the
+/// cost grows exponentially with the dimension, and nested rules are only practical for a
+/// few dimensions, typically up to 4.
+///
+/// # Examples
+/// ```
+/// use integrate::method::{integrate_nd, Method};
+///
+///
+/// let f = |x: &[f64]| x[0] * x[1];
+///
+/// let integral = integrate_nd(f, &[0.0, 0.0], &[1.0, 2.0], 10, Method::Simpson);
+///
+/// assert!((integral - 1.0).abs() < 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `lowers` and `uppers` have different lengths or are empty.
+#[must_use]
+pub fn integrate_nd<Func>(
+    func: Func,
+    lowers: &[f64],
+    uppers: &[f64],
+    n_per_dim: usize,
+    rule: Method,
+) -> f64
+where
+    Func: Fn(&[f64]) -> f64 + Sync,
+{
+    if lowers.len() != uppers.len() {
+        panic!(
+            "lowers and uppers must have the same length, got {} and {}.",
+            lowers.len(),
+            uppers.len()
+        );
+    }
+
+    if lowers.is_empty() {
+        panic!("at least one dimension is required.");
+    }
+
+    let dims = lowers.len();
+    integrate_nd_mixed(
+        func,
+        lowers,
+        uppers,
+        &vec![n_per_dim; dims],
+        &vec![rule; dims],
+    )
+}
+
+/// Integrates $f(x_1, \dots, x_d)$ over the box $\[a_1, b_1\] \times \dots \times \[a_d, b_d\]$
+/// by nesting the rule `rules[i]` with `n_per_dim[i]` steps in dimension $i$.
+///
+/// Anisotropic integrands, e.g. smooth in $x_1$ and oscillatory in $x_2$, can thus be
+/// integrated with a few Gauss-Legendre points in $x_1$ and many Simpson subintervals in
+/// $x_2$. See [`integrate_nd`] for the nesting and its cost.
+///
+/// * `func` - Integrand function of the point $(x_1, \dots, x_d)$.
+/// * `lowers` - lower limits $a_1, \dots, a_d$.
+/// * `uppers` - upper limits $b_1, \dots, b_d$.
+/// * `n_per_dim` - number of subintervals, or number of points for Gauss-Legendre, in each
+///   dimension.
+/// * `rules` - integration method applied in each dimension.
+///
+/// # Examples
+/// ```
+/// use integrate::method::{integrate_nd_mixed, Method};
+///
+///
+/// let f = |x: &[f64]| x[0] * x[1];
+///
+/// let rules = [Method::GaussLegendre, Method::Trapezoidal];
+/// let integral = integrate_nd_mixed(f, &[0.0, 0.0], &[1.0, 2.0], &[2, 1], &rules);
+///
+/// assert!((integral - 1.0).abs() < 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `lowers`, `uppers`, `n_per_dim` and `rules` don't all have the same length,
+/// or are empty.
+#[must_use]
+pub fn integrate_nd_mixed<Func>(
+    func: Func,
+    lowers: &[f64],
+    uppers: &[f64],
+    n_per_dim: &[usize],
+    rules: &[Method],
+) -> f64
+where
+    Func: Fn(&[f64]) -> f64 + Sync,
+{
+    let dims = lowers.len();
+
+    if uppers.len() != dims || n_per_dim.len() != dims || rules.len() != dims {
+        panic!(
+            "lowers, uppers, n_per_dim and rules must have the same length, got {}, {}, {} and {}.",
+            dims,
+            uppers.len(),
+            n_per_dim.len(),
+            rules.len()
+        );
+    }
+
+    if dims == 0 {
+        panic!("at least one dimension is required.");
+    }
+
+    nested_integral(&func, &[], lowers, uppers, n_per_dim, rules)
+}
+
+/// Integral over the dimensions following the fixed coordinates `point`.
+fn nested_integral(
+    func: &(dyn Fn(&[f64]) -> f64 + Sync),
+    point: &[f64],
+    lowers: &[f64],
+    uppers: &[f64],
+    n_per_dim: &[usize],
+    rules: &[Method],
+) -> f64 {
+    let dim = point.len();
+    if dim == lowers.len() {
+        return func(point);
+    }
+
+    let inner = |x: f64| {
+        let mut point = point.to_vec();
+        point.push(x);
+        nested_integral(func, &point, lowers, uppers, n_per_dim, rules)
+    };
+
+    rules[dim].integrate(inner, lowers[dim], uppers[dim], n_per_dim[dim])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integrate() {
+        let cube = |x: f64| x.powi(3);
+
+        for method in [
+            Method::Rectangle,
+            Method::Trapezoidal,
+            Method::Simpson,
+            Method::Newton,
+            Method::GaussLegendre,
+        ] {
+            let integral = method.integrate(cube, 0.0, 1.0, 1_000);
+
+            assert!((integral - 0.25).abs() < 10e-6);
+        }
+    }
+
+    #[test]
+    fn test_integrate_with_progress() {
+        use std::cell::RefCell;
+
+        let f = |x: f64| x.exp();
+        let exact = 1f64.exp() - 1.0;
+
+        for method in [Method::Trapezoidal, Method::Simpson, Method::Newton] {
+            let fractions = RefCell::new(Vec::new());
+            let progress = |fraction: f64| fractions.borrow_mut().push(fraction);
+
+            let integral = method.integrate_with_progress(f, 0.0, 1.0, 10_000, Some(&progress));
+            let reference = method.integrate(f, 0.0, 1.0, 10_000);
+
+            assert!((integral - exact).abs() < 1e-8);
+            assert!((integral - reference).abs() < 1e-12);
+
+            let fractions = fractions.into_inner();
+            assert_eq!(fractions.len(), PROGRESS_CHUNKS);
+            assert!(fractions.windows(2).all(|w| w[0] < w[1]));
+            assert_eq!(*fractions.last().unwrap(), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_compare_methods() {
+        let square = |x: f64| x * x;
+        let n = 100;
+
+        let results = compare_methods(square, 0.0, 1.0, n);
+        assert_eq!(results.len(), Method::ALL.len());
+
+        for (method, integral) in &results {
+            // error is K h^p, exact for the higher order rules
+            let tolerance = match method.order() {
+                Some(2) => (1.0 / n as f64).powi(2),
+                _ => 1e-14,
+            };
+
+            assert!(
+                (integral - 1.0 / 3.0).abs() < tolerance,
+                "{} gave {}",
+                method,
+                integral
+            );
+        }
+
+        let table = ComparisonTable(&results).to_string();
+        assert_eq!(table.lines().count(), 2 + Method::ALL.len());
+        assert!(table.contains("Gauss-Legendre"));
+    }
+
+    #[test]
+    fn test_integrate_nd() {
+        for rule in [Method::Trapezoidal, Method::Simpson, Method::GaussLegendre] {
+            let integral = integrate_nd(|x| x[0] * x[1], &[0.0; 2], &[1.0; 2], 4, rule);
+            assert!((integral - 0.25).abs() < 1e-12);
+
+            let integral = integrate_nd(|x| x[0] + x[1] + x[2], &[0.0; 3], &[1.0; 3], 4, rule);
+            assert!((integral - 1.5).abs() < 1e-12);
+        }
+
+        // the rule is applied on each coordinate in turn
+        let f = |x: &[f64]| (x[0] + 2.0 * x[1]).exp() * x[2] * x[3];
+        let integral = integrate_nd(f, &[0.0; 4], &[1.0; 4], 8, Method::GaussLegendre);
+        let exact = (1f64.exp() - 1.0) * (2f64.exp() - 1.0) / 2.0 / 4.0;
+        assert!((integral - exact).abs() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_integrate_nd_dimension_mismatch() {
+        let _ = integrate_nd(|x| x[0], &[0.0, 0.0], &[1.0], 4, Method::Simpson);
+    }
+
+    #[test]
+    fn test_precision_comparison() {
+        let (single, double, difference) = precision_comparison(
+            |x: f32| x.cos(),
+            |x: f64| x.cos(),
+            0.0,
+            10.0,
+            200,
+            Method::GaussLegendre,
+        );
+
+        let exact = 10.0_f64.sin();
+
+        assert!((double - exact).abs() < 1e-13);
+        assert!((f64::from(single) - exact).abs() < 1e-5);
+
+        // single precision nodes and values cost several orders of magnitude
+        assert!(difference > 1e-10);
+        assert!(difference < 1e-5);
+    }
+
+    #[test]
+    fn test_integrate_by_halfwavelength() {
+        use std::f64::consts::PI;
+
+        let f = |x: f64| (10.0 * x).sin();
+        let exact = |b: f64| (1.0 - (10.0 * b).cos()) / 10.0;
+
+        let integral = integrate_by_halfwavelength(f, 0.0, 2.0 * PI, 10.0, Method::Simpson, 8);
+        assert!(integral.abs() < 1e-10);
+
+        // on [0, 2π] the uniform grid of a single Simpson's rule is symmetric and the
+        // nodes cancel out exactly, stopping short of it shows how it fails
+        let b = 2.0 * PI - 0.5;
+
+        let single = Method::Simpson.integrate(f, 0.0, b, 8);
+        assert!((single - exact(b)).abs() > 1e-1);
+
+        let integral = integrate_by_halfwavelength(f, 0.0, b, 10.0, Method::Simpson, 8);
+        assert!((integral - exact(b)).abs() < 1e-7);
+
+        let reversed = integrate_by_halfwavelength(f, b, 0.0, 10.0, Method::Simpson, 8);
+        assert_eq!(reversed, -integral);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_integrate_by_halfwavelength_zero_omega() {
+        let _ = integrate_by_halfwavelength(|x: f64| x, 0.0, 1.0, 0.0, Method::Simpson, 8);
+    }
+
+    #[test]
+    fn test_integrate_piecewise_reuses_breakpoints() {
+        use std::sync::Mutex;
+
+        let breakpoints = [0.0, 0.3, 1.1, 2.0];
+        let n = 10;
+
+        for rule in Method::ALL {
+            let nodes = Mutex::new(Vec::new());
+            let f = |x: f64| {
+                nodes.lock().unwrap().push(x);
+                x.exp()
+            };
+
+            let integral = integrate_piecewise(f, &breakpoints, n, rule);
+
+            let separate: f64 = breakpoints
+                .windows(2)
+                .map(|limits| rule.integrate(|x: f64| x.exp(), limits[0], limits[1], n))
+                .sum();
+            assert!((integral - separate).abs() < 1e-13, "{}", rule);
+
+            let nodes = nodes.into_inner().unwrap();
+            for breakpoint in &breakpoints[1..3] {
+                let count = nodes.iter().filter(|&x| x == breakpoint).count();
+                let expected = match rule {
+                    Method::Rectangle | Method::GaussLegendre => 0,
+                    _ => 1,
+                };
+                assert_eq!(count, expected, "{} at {}", rule, breakpoint);
+            }
+
+            // 2n + 1 nodes per segment for Simpson's rule, the 2 interior breakpoints once
+            if rule == Method::Simpson {
+                assert_eq!(nodes.len(), 3 * (2 * n + 1) - 2);
+            }
+        }
+    }
+
+    #[test]
+    fn test_nodes_weights_match_integrate() {
+        let f = |x: f64| (3.0 * x).sin() + x * x;
+
+        for rule in Method::ALL {
+            for n in [1_usize, 4, 7] {
+                let sum: f64 = rule
+                    .nodes_weights(-1.0, 2.0, n)
+                    .into_iter()
+                    .map(|(x, w)| w * f(x))
+                    .sum();
+
+                let integral = rule.integrate(f, -1.0, 2.0, n);
+                assert!((sum - integral).abs() < 1e-12, "{} with n = {}", rule, n);
+            }
+        }
+    }
+
+    #[test]
+    fn test_top_contributors_peak() {
+        // narrow bump centered at 0.3
+        let peak = 0.3;
+        let f = |x: f64| (-((x - peak) / 0.01).powi(2)).exp();
+
+        for rule in Method::ALL {
+            let top = top_contributors(rule, f, 0.0, 1.0, 200, 5);
+            assert_eq!(top.len(), 5);
+
+            for (x, _) in &top {
+                assert!((x - peak).abs() < 0.02, "{}: node {}", rule, x);
+            }
+
+            for pair in top.windows(2) {
+                assert!(pair[0].1.abs() >= pair[1].1.abs());
+            }
+        }
+
+        // never more pairs than nodes
+        assert_eq!(
+            top_contributors(Method::Rectangle, f, 0.0, 1.0, 3, 10).len(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_integrate_nd_mixed() {
+        // ∫₀¹ ∫₀^{7π/2} x² sin(y) dy dx, x² is integrated exactly by 2 Gauss-Legendre
+        // points while sin(y) oscillates over [0, 7π/2]
+        let f = |x: &[f64]| x[0] * x[0] * x[1].sin();
+        let (b, d) = (1.0, 3.5 * std::f64::consts::PI);
+        let exact = (1.0 / 3.0) * (1.0 - d.cos());
+
+        let rules = [Method::GaussLegendre, Method::Simpson];
+        let integral = integrate_nd_mixed(f, &[0.0, 0.0], &[b, d], &[2, 200], &rules);
+        assert!((integral - exact).abs() < 1e-8);
+
+        // the same rule in every dimension is integrate_nd
+        let uniform = integrate_nd_mixed(f, &[0.0, 0.0], &[b, d], &[20, 20], &[Method::Simpson; 2]);
+        let nested = integrate_nd(f, &[0.0, 0.0], &[b, d], 20, Method::Simpson);
+        assert_eq!(uniform, nested);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_integrate_nd_mixed_rules_mismatch() {
+        let _ = integrate_nd_mixed(|x| x[0], &[0.0], &[1.0], &[4], &[]);
+    }
+
+    #[test]
+    fn test_integrate_with_antiderivative_check() {
+        let ln = |x: f64| x.ln();
+        let e = 1f64.exp();
+
+        for rule in Method::ALL {
+            let (integral, residual) =
+                integrate_with_antiderivative_check(ln, |x: f64| x * x.ln() - x, 1.0, e, 100, rule);
+
+            // ∫₁^e ln(x) dx = 1
+            assert!((integral - 1.0).abs() < 1e-4, "{}", rule);
+            assert!(residual < 1e-4, "{}", rule);
+        }
+
+        // a wrong antiderivative, missing the - x term, is caught
+        let (_, residual) = integrate_with_antiderivative_check(
+            ln,
+            |x: f64| x * x.ln(),
+            1.0,
+            e,
+            100,
+            Method::Simpson,
+        );
+        assert!(residual > 0.5);
+    }
+}
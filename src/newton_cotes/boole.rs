@@ -0,0 +1,128 @@
+//! Boole's Rule
+//!
+//! Boole's rule approximates the integral of a function $f(x)$ on the closed and bounded
+//! interval $\[a, a+h\]$ of length $h > 0$ by the integral on $\[a, a+h\]$ of the quartic
+//! passing through the five equally spaced points $\left(a + k\frac{h}{4}, f\left(a + k\frac{h}{4}\right)\right)$, $k = 0, \dots, 4$
+//!
+//! ```math
+//! \int_{a}^{a+h} f(x) dx \approx \frac{h}{90} \left[ 7 f(a) + 32 f\left(a + \frac{h}{4}\right) + 12 f\left(a + \frac{h}{2}\right) + 32 f\left(a + \frac{3h}{4}\right) + 7 f(a + h) \right]
+//! ```
+//!
+//! The composite Boole's rule decomposes $\[a, b\]$ into $n$ subintervals of equal length
+//! $h = \frac{b-a}{n}$ and adds the results of applying Boole's rule to each of them, the
+//! end points shared by two subintervals getting the weight $14$. Its truncation error is
+//!
+//! ```math
+//! B_h(f) - \int_{a}^{b} f(x)dx = \frac{2 (b-a)}{945} \left(\frac{h}{4}\right)^6 f^{(6)}(c)
+//! ```
+//!
+//! for some point $c$ where $a ≤ c ≤ b$, so that Boole's rule is exact for polynomials of
+//! degree up to $5$.
+
+use num::{Float, ToPrimitive, Unsigned};
+
+use super::utils::{check_newton_method_args, oriented, sum_terms};
+
+/// This function integrates $f(x)$ from $a$ to $a+nh$ using Boole's rule by summing from
+/// the left end of the interval to the right end.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_intervals` - number of subintervals, each one split in four.
+///
+/// # Examples
+/// ```
+/// use integrate::newton_cotes::boole::boole_rule;
+///
+///
+/// let square = |x: f64| x * x;
+///
+/// let a = 0.0;
+/// let b = 1.0;
+///
+/// let num_steps: usize = 1_000;
+///
+/// let integral = boole_rule(square, a, b, num_steps);
+/// ```
+///
+/// # Resources
+/// [Methods of numerical Integration (2nd edition), by Philip J. Davis and Philip Rabinowitz.](https://www.cambridge.org/core/journals/mathematical-gazette/article/abs/methods-of-numerical-integration-2nd-edition-by-philip-j-davis-and-philip-rabinowitz-pp-612-3650-1984-isbn-0122063600-academic-press/C331158D0392E1D5CD9B0C6ED4EE5F43)
+#[must_use]
+pub fn boole_rule<Func, F1: Float + Sync, F2: Float, U: Unsigned + ToPrimitive + Copy>(
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    n_intervals: U,
+) -> f64
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    let (lower_limit, upper_limit, sign) = oriented(lower_limit, upper_limit);
+
+    // checking arguments
+    check_newton_method_args(lower_limit, upper_limit, n_intervals);
+
+    // length of each subinterval
+    let h: F1 = (upper_limit - lower_limit)
+        / F1::from(n_intervals).expect("failed to convert length of subinterval h");
+
+    // a quarter of the length of each subinterval h/4
+    let h_over_4 = h / F1::from(4).unwrap();
+
+    let f = |k: usize| {
+        let k = F1::from(k).expect("failed to convert node index k");
+        func(lower_limit + k * h_over_4).to_f64().unwrap()
+    };
+
+    // first term of the sum
+    let i_0 = 7.0 * func(lower_limit).to_f64().unwrap() + 32.0 * f(1) + 12.0 * f(2) + 32.0 * f(3);
+
+    let integral: f64 = sum_terms(4..(4 * n_intervals.to_usize().unwrap()), 4, |k| {
+        14.0 * f(k) + 32.0 * f(k + 1) + 12.0 * f(k + 2) + 32.0 * f(k + 3)
+    });
+
+    let i_n = 7.0 * func(upper_limit).to_f64().unwrap();
+
+    sign * (i_0 + integral + i_n) * h.to_f64().unwrap() / 90.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quartic() {
+        let quartic = |x: f64| x.powi(4);
+
+        for n in [1_usize, 2, 5, 10] {
+            let integral = boole_rule(quartic, 0.0, 1.0, n);
+            assert!((integral - 0.2).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_quintic_is_exact() {
+        let quintic = |x: f64| x.powi(5) - 2.0 * x.powi(3) + x;
+
+        // ∫₋₁² = (64 - 1) / 6 - (16 - 1) / 2 + (4 - 1) / 2
+        let integral = boole_rule(quintic, -1.0, 2.0, 1_usize);
+        assert!((integral - (63.0 / 6.0 - 7.5 + 1.5)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_sixth_degree_error() {
+        // the error of a single subinterval is 2/945 (1/4)^6 6!
+        let integral = boole_rule(|x: f64| x.powi(6), 0.0, 1.0, 1_usize);
+        let error = 2.0 / 945.0 * 0.25_f64.powi(6) * 720.0;
+
+        assert!((integral - 1.0 / 7.0 - error).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_f32_to_f32() {
+        let integral = boole_rule(|x: f32| x.exp(), 0.0_f32, 1.0, 100_usize);
+
+        assert!((integral - (1f64.exp() - 1.0)).abs() < 1e-5);
+    }
+}
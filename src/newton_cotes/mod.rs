@@ -10,9 +10,13 @@
 //! - Trapezoidal Rule.
 //! - Simpson's Rule.
 //! - Newton's 3/8 Rule.
+//! - Boole's Rule.
 
+pub mod boole;
 pub mod newton;
 pub mod rectangle;
 pub mod simpson;
 pub mod trapezoidal;
-mod utils;
+pub(crate) mod utils;
+
+pub use utils::PARALLEL_THRESHOLD;
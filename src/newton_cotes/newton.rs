@@ -109,9 +109,11 @@ use std::ops::Div;
 
 use num::{Float, ToPrimitive, Unsigned};
 
-use super::utils::check_newton_method_args;
-
-use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use super::utils::{
+    check_newton_method_args, check_step, oriented, richardson_error, sum_terms,
+    validate_newton_method_args,
+};
+use crate::error::QuadError;
 
 /// This function integrates $f(x)$ from $a$ to $a+nh$ using the Newton's 3/8
 /// rule by summing from the left end of the interval to the right end.
@@ -138,6 +140,7 @@ use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterato
 ///
 /// # Resources
 /// [Methods of numerical Integration (2nd edition), by Philip J. Davis and Philip Rabinowitz.](https://www.cambridge.org/core/journals/mathematical-gazette/article/abs/methods-of-numerical-integration-2nd-edition-by-philip-j-davis-and-philip-rabinowitz-pp-612-3650-1984-isbn-0122063600-academic-press/C331158D0392E1D5CD9B0C6ED4EE5F43)
+#[must_use]
 pub fn newton_rule<Func, F1: Float + Sync, F2: Float, U: Unsigned + ToPrimitive + Copy>(
     func: Func,
     lower_limit: F1,
@@ -147,6 +150,8 @@ pub fn newton_rule<Func, F1: Float + Sync, F2: Float, U: Unsigned + ToPrimitive
 where
     Func: Fn(F1) -> F2 + Sync,
 {
+    let (lower_limit, upper_limit, sign) = oriented(lower_limit, upper_limit);
+
     // checking arguments
     check_newton_method_args(lower_limit, upper_limit, n_intervals);
 
@@ -166,26 +171,182 @@ where
             * (func(lower_limit + h_over_3).to_f64().unwrap()
                 + func(lower_limit + h2_over_3).to_f64().unwrap());
 
-    let integral: f64 = (3..(3 * n_intervals.to_usize().unwrap()))
-        .into_par_iter()
-        .step_by(3)
-        .map(|i| {
-            // subinterval index (as real)
-            let i_plus_1 = F1::from(i + 1).expect("failed to convert subinterval index (i+1)");
-            let i_plus_2 = F1::from(i + 2).expect("failed to convert subinterval index (i+2)");
-            let i = F1::from(i).expect("failed to convert subinterval index i");
-
-            2.0 * func(lower_limit + i * h_over_3).to_f64().unwrap()
-                + 3.0
-                    * (func(lower_limit + i_plus_1 * h_over_3).to_f64().unwrap()
-                        + func(lower_limit + i_plus_2 * h_over_3).to_f64().unwrap())
-        })
-        .sum();
+    let integral: f64 = sum_terms(3..(3 * n_intervals.to_usize().unwrap()), 3, |i| {
+        // subinterval index (as real)
+        let i_plus_1 = F1::from(i + 1).expect("failed to convert subinterval index (i+1)");
+        let i_plus_2 = F1::from(i + 2).expect("failed to convert subinterval index (i+2)");
+        let i = F1::from(i).expect("failed to convert subinterval index i");
+
+        2.0 * func(lower_limit + i * h_over_3).to_f64().unwrap()
+            + 3.0
+                * (func(lower_limit + i_plus_1 * h_over_3).to_f64().unwrap()
+                    + func(lower_limit + i_plus_2 * h_over_3).to_f64().unwrap())
+    });
 
     let n = F1::from(n_intervals).expect("failed to convert n");
-    let i_n = func(lower_limit + n * h_over_3).to_f64().unwrap();
+    let i_n = func(lower_limit + n * h).to_f64().unwrap();
+
+    sign * (i_0 + integral + i_n) * h.to_f64().unwrap() * 1.0.div(8.0)
+}
+
+/// Same as [`newton_rule`], but returns an error instead of panicking on invalid arguments, see
+/// [`try_rectangle_rule`](super::rectangle::try_rectangle_rule).
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_intervals` - number of subintervals.
+///
+/// # Examples
+/// ```
+/// use integrate::error::QuadError;
+/// use integrate::newton_cotes::newton::try_newton_rule;
+///
+///
+/// let square = |x: f64| x * x;
+///
+/// assert!(try_newton_rule(square, 0.0, 1.0, 12_usize).is_ok());
+/// assert_eq!(try_newton_rule(square, 0.0, 1.0, 0_usize), Err(QuadError::ZeroSteps));
+/// ```
+pub fn try_newton_rule<Func, F1: Float + Sync, F2: Float, U: Unsigned + ToPrimitive + Copy>(
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    n_intervals: U,
+) -> Result<f64, QuadError>
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    // checking arguments
+    validate_newton_method_args(lower_limit, upper_limit, n_intervals)?;
+    check_step(lower_limit, upper_limit, n_intervals)?;
 
-    (i_0 + integral + i_n) * h.to_f64().unwrap() * 1.0.div(8.0)
+    Ok(newton_rule(func, lower_limit, upper_limit, n_intervals))
+}
+
+/// This function integrates $f(x)$ from $a$ to $b$ using Newton's 3/8 rule with $n$ and $2n$
+/// subintervals, and returns the estimate with $2n$ subintervals along with the Richardson
+/// estimate of its error.
+///
+/// The error of Newton's 3/8 rule behaves like $K h^4$, so that halving $h$ divides it by
+/// $2^4$ and the error of $I_{2n}$ is about
+///
+/// ```math
+/// \frac{\left| I_{2n} - I_n \right|}{2^4 - 1}
+/// ```
+///
+/// as long as $f$ is smooth enough for the leading error term to dominate.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_intervals` - number of subintervals $n$ of the coarser estimate.
+///
+/// # Examples
+/// ```
+/// use integrate::newton_cotes::newton::newton_rule_with_error;
+///
+///
+/// let f = |x: f64| x.exp();
+///
+/// let (integral, error) = newton_rule_with_error(f, 0.0, 1.0, 100_usize);
+///
+/// assert!((integral - (1f64.exp() - 1.0)).abs() < 2.0 * error);
+/// ```
+#[must_use]
+pub fn newton_rule_with_error<Func, F1: Float + Sync, F2: Float, U: Unsigned + ToPrimitive + Copy>(
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    n_intervals: U,
+) -> (f64, f64)
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    let coarse = newton_rule(&func, lower_limit, upper_limit, n_intervals);
+    let fine = newton_rule(&func, lower_limit, upper_limit, n_intervals + n_intervals);
+
+    (fine, richardson_error(coarse, fine, 4))
+}
+
+/// This function integrates $f(x)$ from $a$ to $b$ using the classic composite
+/// Newton's 3/8 rule, in which the $n$ subintervals of length $h = \frac{b-a}{n}$ are
+/// grouped in panels of three.
+///
+/// ```math
+/// \int_{a}^{b} f(x) dx \approx \frac{3h}{8} \left[ f(x_0) + 3f(x_1) + 3f(x_2) + 2f(x_3) + ··· + 2f(x_{n-3}) + 3f(x_{n-2}) + 3f(x_{n-1}) + f(x_n) \right]
+/// ```
+///
+/// Unlike [`newton_rule`], which splits each of its $n$ subintervals in three, the classic
+/// rule counts the subintervals between consecutive nodes, so that `n` must be a multiple
+/// of 3.
+///
+/// Returns [`QuadError::StepsNotMultiple`] if `n` is not a multiple of 3, and otherwise the
+/// errors of [`try_newton_rule`]: [`QuadError::ZeroSteps`] if `n` is zero,
+/// [`QuadError::InfiniteLimit`] if either limit is infinite, [`QuadError::IntervalOverflow`]
+/// if $b - a$ overflows and [`QuadError::StepUnderflow`] if $h$ underflows to zero.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_intervals` - number of subintervals, a multiple of 3.
+///
+/// # Examples
+/// ```
+/// use integrate::error::QuadError;
+/// use integrate::newton_cotes::newton::newton_three_eighths_classic;
+///
+///
+/// let cube = |x: f64| x * x * x;
+///
+/// let integral = newton_three_eighths_classic(cube, 0.0, 2.0, 6_usize).unwrap();
+/// assert!((integral - 4.0).abs() < 1e-12);
+///
+/// let result = newton_three_eighths_classic(cube, 0.0, 2.0, 4_usize);
+/// assert_eq!(result, Err(QuadError::StepsNotMultiple(3)));
+/// ```
+pub fn newton_three_eighths_classic<
+    Func,
+    F1: Float + Sync,
+    F2: Float,
+    U: Unsigned + ToPrimitive + Copy,
+>(
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    n_intervals: U,
+) -> Result<f64, QuadError>
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    let (lower_limit, upper_limit, sign) = oriented(lower_limit, upper_limit);
+
+    // checking arguments
+    validate_newton_method_args(lower_limit, upper_limit, n_intervals)?;
+
+    let n = n_intervals.to_usize().unwrap();
+
+    if n % 3 != 0 {
+        return Err(QuadError::StepsNotMultiple(3));
+    }
+
+    check_step(lower_limit, upper_limit, n_intervals)?;
+
+    // length of each subinterval
+    let h: F1 = (upper_limit - lower_limit)
+        / F1::from(n_intervals).expect("failed to convert length of subinterval h");
+
+    let end_points = func(lower_limit).to_f64().unwrap() + func(upper_limit).to_f64().unwrap();
+
+    // interior nodes, weight 2 on panel boundaries and 3 inside the panels
+    let interior: f64 = sum_terms(1..n, 1, |i| {
+        let weight = if i % 3 == 0 { 2.0 } else { 3.0 };
+        let x = lower_limit + F1::from(i).expect("failed to convert node index i") * h;
+
+        weight * func(x).to_f64().unwrap()
+    });
+
+    Ok(sign * (end_points + interior) * 3.0 * h.to_f64().unwrap() / 8.0)
 }
 
 #[cfg(test)]
@@ -264,6 +425,71 @@ mod tests {
         assert!((integral - analytic_result).abs() < EPSILON);
     }
 
+    #[test]
+    fn test_last_node_is_upper_limit() {
+        // the last node used to be a + nh/3 instead of b
+        let nodes = std::sync::Mutex::new(Vec::new());
+
+        let _ = newton_rule(
+            |x: f64| {
+                nodes.lock().unwrap().push(x);
+                x
+            },
+            0.0,
+            3.0,
+            3_usize,
+        );
+
+        let nodes = nodes.into_inner().unwrap();
+        assert!(nodes.contains(&3.0), "b was not sampled: {:?}", nodes);
+    }
+
+    #[test]
+    fn test_cubic_is_exact() {
+        let cube = |x: f64| x.powi(3);
+
+        let integral = newton_rule(cube, 0.0, 2.0, 3_usize);
+
+        assert!((integral - 4.0).abs() < 10e-12);
+    }
+
+    #[test]
+    fn test_three_eighths_classic() {
+        let cube = |x: f64| x.powi(3);
+
+        for n in [3_usize, 6, 99] {
+            let integral = newton_three_eighths_classic(cube, 0.0, 2.0, n).unwrap();
+            assert!((integral - 4.0).abs() < 10e-12);
+        }
+
+        // same nodes as newton_rule with a third of the subintervals
+        let f = |x: f64| x.exp();
+        let classic = newton_three_eighths_classic(f, 0.0, 1.0, 30_usize).unwrap();
+        assert!((classic - newton_rule(f, 0.0, 1.0, 10_usize)).abs() < 10e-14);
+
+        assert_eq!(
+            newton_three_eighths_classic(cube, 0.0, 2.0, 4_usize),
+            Err(QuadError::StepsNotMultiple(3))
+        );
+        assert_eq!(
+            newton_three_eighths_classic(cube, -f64::MAX, f64::MAX, 6_usize),
+            Err(QuadError::IntervalOverflow)
+        );
+
+        // invalid arguments are returned rather than panicking
+        assert_eq!(
+            newton_three_eighths_classic(cube, 0.0, 2.0, 0_usize),
+            Err(QuadError::ZeroSteps)
+        );
+        assert_eq!(
+            newton_three_eighths_classic(cube, 0.0, f64::INFINITY, 6_usize),
+            Err(QuadError::InfiniteLimit)
+        );
+
+        let reversed = newton_three_eighths_classic(cube, 2.0, 0.0, 6_usize).unwrap();
+        assert!((reversed + 4.0).abs() < 10e-12);
+    }
+
     // #[bench]
     // fn bench_integral_value(bencher: &mut Bencher) {
     //     fn f1(x: f64) -> f64 {
@@ -277,4 +503,32 @@ mod tests {
     //         newton_rule(f1, a, b, NUM_STEPS);
     //     })
     // }
+
+    #[test]
+    fn test_try_newton_rule_errors() {
+        let f = |x: f64| x;
+
+        assert_eq!(
+            try_newton_rule(f, 0.0, 1.0, 0_usize),
+            Err(QuadError::ZeroSteps)
+        );
+        assert_eq!(
+            try_newton_rule(f, 0.0, f64::INFINITY, 10_usize),
+            Err(QuadError::InfiniteLimit)
+        );
+        assert_eq!(
+            try_newton_rule(f, f64::NEG_INFINITY, 0.0, 10_usize),
+            Err(QuadError::InfiniteLimit)
+        );
+        assert_eq!(
+            try_newton_rule(f, 1.0, 0.0, 12_usize),
+            try_newton_rule(f, 0.0, 1.0, 12_usize).map(|integral| -integral)
+        );
+
+        let integral = try_newton_rule(f, 0.0, 1.0, 12_usize).unwrap();
+        assert!((integral - 0.5).abs() < 1e-12);
+
+        // a zero-width interval is valid, its integral is zero
+        assert_eq!(try_newton_rule(f, 1.0, 1.0, 10_usize), Ok(0.0));
+    }
 }
@@ -96,7 +96,11 @@
 use num::{Float, ToPrimitive, Unsigned};
 use rayon::prelude::*;
 
-use super::utils::check_newton_method_args;
+use super::utils::{
+    check_newton_method_args, check_step, oriented, richardson_error, sum_terms,
+    validate_newton_method_args,
+};
+use crate::error::QuadError;
 
 /// This function integrates $f(x)$ from $a$ to $a+nh$ using the rectangle
 /// rule by summing from the left end of the interval to the right end.
@@ -123,6 +127,7 @@ use super::utils::check_newton_method_args;
 ///
 /// # Resources
 /// [Methods of numerical Integration (2nd edition), by Philip J. Davis and Philip Rabinowitz.](https://www.cambridge.org/core/journals/mathematical-gazette/article/abs/methods-of-numerical-integration-2nd-edition-by-philip-j-davis-and-philip-rabinowitz-pp-612-3650-1984-isbn-0122063600-academic-press/C331158D0392E1D5CD9B0C6ED4EE5F43)
+#[must_use]
 pub fn rectangle_rule<Func, F1: Float + Sync, F2: Float + Sync, U: Unsigned + ToPrimitive + Copy>(
     func: Func,
     lower_limit: F1,
@@ -132,6 +137,8 @@ pub fn rectangle_rule<Func, F1: Float + Sync, F2: Float + Sync, U: Unsigned + To
 where
     Func: Fn(F1) -> F2 + Sync,
 {
+    let (lower_limit, upper_limit, sign) = oriented(lower_limit, upper_limit);
+
     // checking arguments
     check_newton_method_args(lower_limit, upper_limit, n_intervals);
 
@@ -139,23 +146,190 @@ where
     let h: F1 = (upper_limit - lower_limit)
         / F1::from(n_intervals).expect("failed to convert length of subinterval h");
 
-    let integral: f64 = (0..(n_intervals.to_usize().unwrap()))
+    let integral: f64 = sum_terms(0..(n_intervals.to_usize().unwrap()), 1, |i| {
+        // subinterval index (as real)
+        let i = F1::from(i).expect("failed to convert subinterval index i");
+
+        // subinterval midpoint
+        let x = lower_limit
+            + i * h
+            + (h / F1::from(2).expect("failed to compute subinterval midpoint"));
+
+        // converting f(x) to primitive type f64
+        func(x).to_f64().expect("failed to convert f(x) to f64")
+    });
+    sign * integral * h.to_f64().unwrap()
+}
+
+/// Same as [`rectangle_rule`], but returns an error instead of panicking on invalid arguments:
+/// [`QuadError::ZeroSteps`] if `n_intervals` is zero, [`QuadError::InfiniteLimit`] if
+/// either limit is infinite, [`QuadError::NanLimit`] if either limit is `NaN`. As [`try_simpson_rule`](super::simpson::try_simpson_rule), it also
+/// returns [`QuadError::IntervalOverflow`] or [`QuadError::StepUnderflow`] when the length of
+/// the interval or of the subintervals can't be represented.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_intervals` - number of subintervals.
+///
+/// # Examples
+/// ```
+/// use integrate::error::QuadError;
+/// use integrate::newton_cotes::rectangle::try_rectangle_rule;
+///
+///
+/// let square = |x: f64| x * x;
+///
+/// assert!(try_rectangle_rule(square, 0.0, 1.0, 12_usize).is_ok());
+/// assert_eq!(try_rectangle_rule(square, 0.0, 1.0, 0_usize), Err(QuadError::ZeroSteps));
+/// ```
+pub fn try_rectangle_rule<
+    Func,
+    F1: Float + Sync,
+    F2: Float + Sync,
+    U: Unsigned + ToPrimitive + Copy,
+>(
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    n_intervals: U,
+) -> Result<f64, QuadError>
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    // checking arguments
+    validate_newton_method_args(lower_limit, upper_limit, n_intervals)?;
+    check_step(lower_limit, upper_limit, n_intervals)?;
+
+    Ok(rectangle_rule(func, lower_limit, upper_limit, n_intervals))
+}
+
+/// This function integrates $f(x)$ from $a$ to $b$ using the rectangle rule with $n$ and $2n$
+/// subintervals, and returns the estimate with $2n$ subintervals along with the Richardson
+/// estimate of its error.
+///
+/// The error of the rectangle rule behaves like $K h^2$, so that halving $h$ divides it by
+/// $2^2$ and the error of $I_{2n}$ is about
+///
+/// ```math
+/// \frac{\left| I_{2n} - I_n \right|}{2^2 - 1}
+/// ```
+///
+/// as long as $f$ is smooth enough for the leading error term to dominate.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_intervals` - number of subintervals $n$ of the coarser estimate.
+///
+/// # Examples
+/// ```
+/// use integrate::newton_cotes::rectangle::rectangle_rule_with_error;
+///
+///
+/// let f = |x: f64| x.exp();
+///
+/// let (integral, error) = rectangle_rule_with_error(f, 0.0, 1.0, 100_usize);
+///
+/// assert!((integral - (1f64.exp() - 1.0)).abs() < 2.0 * error);
+/// ```
+#[must_use]
+pub fn rectangle_rule_with_error<
+    Func,
+    F1: Float + Sync,
+    F2: Float + Sync,
+    U: Unsigned + ToPrimitive + Copy,
+>(
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    n_intervals: U,
+) -> (f64, f64)
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    let coarse = rectangle_rule(&func, lower_limit, upper_limit, n_intervals);
+    let fine = rectangle_rule(&func, lower_limit, upper_limit, n_intervals + n_intervals);
+
+    (fine, richardson_error(coarse, fine, 2))
+}
+
+/// This function brackets the integral of a monotone function $f(x)$ from $a$ to $b$
+/// between its left and right Riemann sums
+/// ```math
+/// L_h(f) = h \sum_{i=0}^{n-1} f(a + ih), \quad R_h(f) = h \sum_{i=1}^{n} f(a + ih)
+/// ```
+///
+/// If $f$ is increasing then $L_h(f) \le \int_{a}^{b} f(x) dx \le R_h(f)$, and the inequalities
+/// are reversed if $f$ is decreasing, whatever the smoothness of $f$. The width of the bracket is
+/// $h \left| f(b) - f(a) \right|$.
+///
+/// Returns `(lower_bound, upper_bound)`. The bound holds in exact arithmetic, the rounding
+/// errors of the sums are not accounted for.
+///
+/// * `func` - Integrand function of a single variable, monotone on $\[a,b\]$.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_intervals` - number of subintervals.
+/// * `increasing` - whether `func` is increasing (`true`) or decreasing (`false`).
+///
+/// # Examples
+/// ```
+/// use integrate::newton_cotes::rectangle::integrate_monotone_bracketed;
+///
+///
+/// let f = |x: f64| x.sqrt();
+///
+/// let (lower, upper) = integrate_monotone_bracketed(f, 0.0, 1.0, 1_000_usize, true);
+///
+/// assert!(lower <= 2.0 / 3.0 && 2.0 / 3.0 <= upper);
+/// ```
+#[must_use]
+pub fn integrate_monotone_bracketed<
+    Func,
+    F1: Float + Sync,
+    F2: Float + Sync,
+    U: Unsigned + ToPrimitive + Copy,
+>(
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    n_intervals: U,
+    increasing: bool,
+) -> (f64, f64)
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    // checking arguments
+    check_newton_method_args(lower_limit, upper_limit, n_intervals);
+
+    // length of each subinterval
+    let h: F1 = (upper_limit - lower_limit)
+        / F1::from(n_intervals).expect("failed to convert length of subinterval h");
+
+    // nodes shared by both sums
+    let interior: f64 = (1..(n_intervals.to_usize().unwrap()))
         .into_par_iter()
         .map(|i| {
             // subinterval index (as real)
             let i = F1::from(i).expect("failed to convert subinterval index i");
 
-            // subinterval midpoint
-            let x = lower_limit
-                + i * h
-                + (h / F1::from(2).expect("failed to compute subinterval midpoint"));
-
-            // converting f(x) to primitive type f64
-            func(x).to_f64().expect("failed to convert f(x) to f64")
-            // 0.0
+            func(lower_limit + i * h)
+                .to_f64()
+                .expect("failed to convert f(x) to f64")
         })
         .sum();
-    integral * h.to_f64().unwrap()
+
+    let h = h.to_f64().unwrap();
+
+    let left = (func(lower_limit).to_f64().unwrap() + interior) * h;
+    let right = (interior + func(upper_limit).to_f64().unwrap()) * h;
+
+    if increasing {
+        (left, right)
+    } else {
+        (right, left)
+    }
 }
 
 #[cfg(test)]
@@ -247,4 +421,48 @@ mod tests {
     //         rectangle_rule(f1, a, b, NUM_STEPS);
     //     })
     // }
+
+    #[test]
+    fn test_monotone_bracket() {
+        let exact = 1f64.exp() - 1.0;
+
+        let (lower, upper) =
+            integrate_monotone_bracketed(|x: f64| x.exp(), 0.0, 1.0, 100_usize, true);
+        assert!(lower < exact && exact < upper);
+        assert!((upper - lower - (exact / 100.0)).abs() < 1e-12);
+
+        // e^{-x} is decreasing
+        let exact = 1.0 - (-1f64).exp();
+        let (lower, upper) =
+            integrate_monotone_bracketed(|x: f64| (-x).exp(), 0.0, 1.0, 100_usize, false);
+        assert!(lower < exact && exact < upper);
+    }
+
+    #[test]
+    fn test_try_rectangle_rule_errors() {
+        let f = |x: f64| x;
+
+        assert_eq!(
+            try_rectangle_rule(f, 0.0, 1.0, 0_usize),
+            Err(QuadError::ZeroSteps)
+        );
+        assert_eq!(
+            try_rectangle_rule(f, 0.0, f64::INFINITY, 10_usize),
+            Err(QuadError::InfiniteLimit)
+        );
+        assert_eq!(
+            try_rectangle_rule(f, f64::NEG_INFINITY, 0.0, 10_usize),
+            Err(QuadError::InfiniteLimit)
+        );
+        assert_eq!(
+            try_rectangle_rule(f, 1.0, 0.0, 12_usize),
+            try_rectangle_rule(f, 0.0, 1.0, 12_usize).map(|integral| -integral)
+        );
+
+        let integral = try_rectangle_rule(f, 0.0, 1.0, 12_usize).unwrap();
+        assert!((integral - 0.5).abs() < 1e-12);
+
+        // a zero-width interval is valid, its integral is zero
+        assert_eq!(try_rectangle_rule(f, 1.0, 1.0, 10_usize), Ok(0.0));
+    }
 }
@@ -89,11 +89,19 @@
 
 use std::ops::Div;
 
-use num::{Float, ToPrimitive, Unsigned};
+use num::{Complex, Float, ToPrimitive, Unsigned};
 
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
 
-use super::utils::check_newton_method_args;
+use super::rectangle::rectangle_rule;
+use super::utils::{
+    check_newton_method_args, check_step, check_width, oriented, richardson_error, sum_terms,
+    validate_newton_method_args,
+};
+use crate::error::QuadError;
+use crate::gauss_quadrature::utils::WarnOnce;
+
+static OPEN_ENDPOINTS_WARNING: WarnOnce = WarnOnce::new();
 
 /// This function integrates $f(x)$ from $a$ to $a+nh$ using the Simpson's
 /// rule by summing from the left end of the interval to the right end.
@@ -103,65 +111,1319 @@ use super::utils::check_newton_method_args;
 /// * `upper_limit` - upper limit of the integration interval.
 /// * `n_intervals` - number of subintervals.
 ///
+/// The nodes are computed in the type `F1` of the limits, which is also the type the
+/// integrand takes, while the values of the integrand may be of any float type `F2`. To
+/// compute the nodes in a precision different from the one of the limits, see
+/// [`simpson_rule_mixed`].
+///
+/// # Examples
+/// ```
+/// use integrate::newton_cotes::simpson::simpson_rule;
+///
+///
+/// let square = |x: f64| x * x;
+///
+/// let a = 0.0;
+/// let b = 1.0;
+///
+/// let num_steps: usize = 1_000_000;
+///
+/// let integral = simpson_rule(square, a, b, num_steps);
+/// ```
+///
+/// # Reproducibility
+///
+/// From [`PARALLEL_THRESHOLD`](super::PARALLEL_THRESHOLD) subintervals on, the terms are
+/// summed in parallel, and the rounding errors depend on how the sum is split between
+/// threads. Results obtained with different numbers of threads, e.g. with different values
+/// of `RAYON_NUM_THREADS`, differ by at most $10^{-12} \int_{a}^{b} |f(x)| dx$ for up to
+/// $10^7$ subintervals. Use [`simpson_rule_deterministic`] for bitwise reproducible results.
+///
+/// # Resources
+/// [Methods of numerical Integration (2nd edition), by Philip J. Davis and Philip Rabinowitz.](https://www.cambridge.org/core/journals/mathematical-gazette/article/abs/methods-of-numerical-integration-2nd-edition-by-philip-j-davis-and-philip-rabinowitz-pp-612-3650-1984-isbn-0122063600-academic-press/C331158D0392E1D5CD9B0C6ED4EE5F43)
+#[must_use]
+pub fn simpson_rule<Func, F1: Float + Sync, F2: Float, U: Unsigned + ToPrimitive + Copy>(
+    f: Func,
+    a: F1,
+    b: F1,
+    n: U,
+) -> f64
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    let (a, b, sign) = oriented(a, b);
+
+    // checking arguments
+    check_newton_method_args(a, b, n);
+
+    // length of each subinterval
+    let h: F1 = (b - a) / F1::from(n).expect("failed to convert length of subinterval h");
+
+    // half the length of each subinterval h/2
+    let h_over_2 = h / F1::from(2).unwrap();
+
+    // first term of the sum
+    let i_0 = f(a).to_f64().unwrap() + 4.0 * f(a + h_over_2).to_f64().unwrap();
+
+    let integral: f64 = sum_terms(2..(2 * n.to_usize().unwrap()), 2, |i| {
+        interior_term(&f, a, h_over_2, i)
+    });
+
+    let n = F1::from(n).expect("failed to convert n");
+    let i_n = f(a + n * h).to_f64().unwrap();
+
+    sign * (i_0 + integral + i_n) * h.to_f64().unwrap() * 1.0.div(6.0)
+}
+
+/// This function integrates $f(x)$ from $a$ to $b$ using Simpson's rule with $n$ and $2n$
+/// subintervals, and returns the estimate with $2n$ subintervals along with the Richardson
+/// estimate of its error.
+///
+/// The error of Simpson's rule behaves like $K h^4$, so that halving $h$ divides it by
+/// $2^4$ and the error of $I_{2n}$ is about
+///
+/// ```math
+/// \frac{\left| I_{2n} - I_n \right|}{2^4 - 1}
+/// ```
+///
+/// as long as $f$ is smooth enough for the leading error term to dominate.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_intervals` - number of subintervals $n$ of the coarser estimate.
+///
+/// # Examples
+/// ```
+/// use integrate::newton_cotes::simpson::simpson_rule_with_error;
+///
+///
+/// let f = |x: f64| x.exp();
+///
+/// let (integral, error) = simpson_rule_with_error(f, 0.0, 1.0, 100_usize);
+///
+/// assert!((integral - (1f64.exp() - 1.0)).abs() < 2.0 * error);
+/// ```
+#[must_use]
+pub fn simpson_rule_with_error<
+    Func,
+    F1: Float + Sync,
+    F2: Float,
+    U: Unsigned + ToPrimitive + Copy,
+>(
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    n_intervals: U,
+) -> (f64, f64)
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    let coarse = simpson_rule(&func, lower_limit, upper_limit, n_intervals);
+    let fine = simpson_rule(&func, lower_limit, upper_limit, n_intervals + n_intervals);
+
+    (fine, richardson_error(coarse, fine, 4))
+}
+
+/// This function integrates $f(x)$ from $a$ to $a+nh$ using the Simpson's
+/// rule, producing bitwise reproducible results.
+///
+/// Floating point addition is not associative, and the order in which [`simpson_rule`]
+/// adds up the contributions of the nodes depends on how the work is split between threads.
+/// Here the integrand is still evaluated in parallel, but the contributions are collected
+/// in order and summed from the left end of the interval to the right end, so that the
+/// result does not depend on the number of threads nor on their scheduling.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_intervals` - number of subintervals.
+///
+/// # Examples
+/// ```
+/// use integrate::newton_cotes::simpson::simpson_rule_deterministic;
+///
+///
+/// let square = |x: f64| x * x;
+///
+/// let a = 0.0;
+/// let b = 1.0;
+///
+/// let num_steps: usize = 1_000_000;
+///
+/// let integral = simpson_rule_deterministic(square, a, b, num_steps);
+///
+/// assert_eq!(integral, simpson_rule_deterministic(square, a, b, num_steps));
+/// ```
+#[must_use]
+pub fn simpson_rule_deterministic<
+    Func,
+    F1: Float + Sync,
+    F2: Float,
+    U: Unsigned + ToPrimitive + Copy,
+>(
+    f: Func,
+    a: F1,
+    b: F1,
+    n: U,
+) -> f64
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    // checking arguments
+    check_newton_method_args(a, b, n);
+
+    // length of each subinterval
+    let h: F1 = (b - a) / F1::from(n).expect("failed to convert length of subinterval h");
+
+    // half the length of each subinterval h/2
+    let h_over_2 = h / F1::from(2).unwrap();
+
+    // first term of the sum
+    let i_0 = f(a).to_f64().unwrap() + 4.0 * f(a + h_over_2).to_f64().unwrap();
+
+    // contributions are collected in order
+    let terms: Vec<f64> = (2..(2 * n.to_usize().unwrap()))
+        .into_par_iter()
+        .step_by(2)
+        .map(|i| interior_term(&f, a, h_over_2, i))
+        .collect();
+
+    // then summed sequentially
+    let integral: f64 = terms.iter().sum();
+
+    let n = F1::from(n).expect("failed to convert n");
+    let i_n = f(a + n * h).to_f64().unwrap();
+
+    (i_0 + integral + i_n) * h.to_f64().unwrap() * 1.0.div(6.0)
+}
+
+/// This function integrates $f(x)$ from $a$ to $a+nh$ using the Simpson's rule, with limits
+/// given in a float type `L` other than the type `F1` of the nodes.
+///
+/// The limits are converted to `F1` once, and the nodes are then computed in `F1` as in
+/// [`simpson_rule`]. Converting `f32` limits to `f64` is exact, so an `f64` integrand is
+/// integrated over exactly the interval described by the limits. Converting `f64` limits to
+/// `f32` rounds them to the nearest `f32`, and the interval actually integrated over may
+/// differ from the requested one by about $10^{-7}$ relative to the limits.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_intervals` - number of subintervals.
+///
+/// # Examples
+/// ```
+/// use integrate::newton_cotes::simpson::simpson_rule_mixed;
+///
+///
+/// // f32 limits, nodes and integrand in f64
+/// let square = |x: f64| x * x;
+///
+/// let integral = simpson_rule_mixed(square, 0.0_f32, 1.0_f32, 100_usize);
+///
+/// assert!((integral - 1.0 / 3.0).abs() < 1e-15);
+/// ```
+#[must_use]
+pub fn simpson_rule_mixed<
+    Func,
+    L: Float,
+    F1: Float + Sync,
+    F2: Float,
+    U: Unsigned + ToPrimitive + Copy,
+>(
+    f: Func,
+    a: L,
+    b: L,
+    n: U,
+) -> f64
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    let a = F1::from(a).expect("failed to convert lower limit");
+    let b = F1::from(b).expect("failed to convert upper limit");
+
+    simpson_rule(f, a, b, n)
+}
+
+/// This function integrates $f(x)$ from $a$ to $b$ using the Simpson's rule, switching to
+/// an open rule if $f$ is not finite at an endpoint.
+///
+/// Simpson's rule evaluates $f(a)$ and $f(b)$, so an integrand with an integrable
+/// singularity at an endpoint, such as $\frac{1}{\sqrt{x}}$ at $0$, poisons the sum with an
+/// infinite or `NaN` value. If $f(a)$ or $f(b)$ is not finite, the integral is computed with the
+/// rectangle rule, which only evaluates $f$ at the midpoints of $2n$ subintervals. Note that
+/// the convergence is then only as fast as the singularity allows, e.g. like $\sqrt{h}$ for
+/// $\frac{1}{\sqrt{x}}$. The first time an endpoint is skipped, a warning naming it is printed
+/// to the standard error.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_intervals` - number of subintervals.
+///
+/// # Examples
+/// ```
+/// use integrate::newton_cotes::simpson::simpson_rule_open_endpoints;
+///
+///
+/// let f = |x: f64| 1.0 / x.sqrt();
+///
+/// let integral = simpson_rule_open_endpoints(f, 0.0, 1.0, 100_000_usize);
+///
+/// assert!((integral - 2.0).abs() < 1e-2);
+/// ```
+#[must_use]
+pub fn simpson_rule_open_endpoints<
+    Func,
+    F1: Float + Sync,
+    F2: Float + Send + Sync,
+    U: Unsigned + ToPrimitive + Copy,
+>(
+    f: Func,
+    a: F1,
+    b: F1,
+    n: U,
+) -> f64
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    // checking arguments
+    check_newton_method_args(a, b, n);
+
+    let (integral, skipped) = open_endpoints_rule(f, a, b, n);
+
+    if let Some(skipped) = skipped {
+        OPEN_ENDPOINTS_WARNING.warn(&format!(
+            "Warning: the integrand is not finite at {}, which is skipped using the rectangle rule.",
+            skipped
+        ));
+    }
+
+    integral
+}
+
+/// [`simpson_rule_open_endpoints`] without the warning, returning the endpoints skipped, if any,
+/// along with the integral.
+fn open_endpoints_rule<
+    Func,
+    F1: Float + Sync,
+    F2: Float + Send + Sync,
+    U: Unsigned + ToPrimitive + Copy,
+>(
+    f: Func,
+    a: F1,
+    b: F1,
+    n: U,
+) -> (f64, Option<&'static str>)
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    let lower_finite = f(a).is_finite();
+    let upper_finite = f(b).is_finite();
+
+    if lower_finite && upper_finite {
+        return (simpson_rule(f, a, b, n), None);
+    }
+
+    let skipped = match (lower_finite, upper_finite) {
+        (false, false) => "both endpoints",
+        (false, true) => "the lower limit",
+        _ => "the upper limit",
+    };
+
+    (
+        rectangle_rule(f, a, b, 2 * n.to_usize().unwrap()),
+        Some(skipped),
+    )
+}
+
+/// This function integrates $f(x)$ from $a$ to $b$ using Simpson's rule separately on
+/// $\[a, s\]$ and $\[s, b\]$, for an integrand with a known singularity at $s$.
+///
+/// The nodes of Simpson's rule on $\[a, b\]$ include its midpoint $\frac{a + b}{2}$ for any
+/// $n$, and more generally any point $a + k \frac{h}{2}$: an integrand singular there makes
+/// the whole sum infinite or `NaN`. Splitting at the singularity makes it an endpoint of
+/// both halves, each integrated by [`simpson_rule_open_endpoints`] with $n$ subintervals,
+/// so that $f$ is only evaluated at $s$ to find out that it is not finite there. As the
+/// singularity is expected, skipping $s$ doesn't print any warning.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_intervals` - number of subintervals on each side of the split.
+/// * `split` - point $s$ where the integrand is singular, strictly between the limits.
+///
+/// # Examples
+/// ```
+/// use integrate::newton_cotes::simpson::{simpson_rule, simpson_rule_split_at};
+///
+///
+/// // singular at the midpoint of [0, 2]
+/// let f = |x: f64| (x - 1.0).abs().ln();
+///
+/// assert!(simpson_rule(f, 0.0, 2.0, 100_usize).is_infinite());
+///
+/// let integral = simpson_rule_split_at(f, 0.0, 2.0, 10_000_usize, 1.0);
+/// assert!((integral + 2.0).abs() < 1e-3);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `split` is not strictly between `lower_limit` and `upper_limit`, and in the
+/// cases where [`simpson_rule`] panics.
+#[must_use]
+pub fn simpson_rule_split_at<
+    Func,
+    F1: Float + Sync,
+    F2: Float + Send + Sync,
+    U: Unsigned + ToPrimitive + Copy,
+>(
+    f: Func,
+    a: F1,
+    b: F1,
+    n: U,
+    split: F1,
+) -> f64
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    // checking arguments
+    check_newton_method_args(a, b, n);
+
+    if !(a < split && split < b) {
+        panic!("split must be strictly between a and b.");
+    }
+
+    let (lower, _) = open_endpoints_rule(&f, a, split, n);
+    let (upper, _) = open_endpoints_rule(&f, split, b, n);
+
+    lower + upper
+}
+
+/// This function integrates the real part of a complex valued $f(x)$ from $a$ to $b$ using
+/// Simpson's rule, accumulating only $\operatorname{Re} f(x_i)$.
+///
+/// The nodes $x_i$ and the weights of the rule are real, so that the real part of the sum
+/// is the sum of the real parts
+///
+/// ```math
+/// \operatorname{Re} \sum_i w_i f(x_i) = \sum_i w_i \operatorname{Re} f(x_i)
+/// ```
+///
+/// and the result is exactly the real part of the complex Simpson estimate, while the
+/// imaginary parts are never summed. This relies on the interval being real: it does not
+/// apply to contour integrals, where the nodes or the weights are complex.
+///
+/// * `func` - Complex valued integrand function of a single real variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_intervals` - number of subintervals.
+///
+/// # Examples
+/// ```
+/// use integrate::newton_cotes::simpson::simpson_rule_real_part;
+/// use num::Complex;
+///
+///
+/// // Re e^{ix} = cos(x)
+/// let f = |x: f64| Complex::new(0.0, x).exp();
+///
+/// let integral = simpson_rule_real_part(f, 0.0, std::f64::consts::FRAC_PI_2, 100_usize);
+///
+/// assert!((integral - 1.0).abs() < 1e-8);
+/// ```
+#[must_use]
+pub fn simpson_rule_real_part<Func, F1: Float + Sync, U: Unsigned + ToPrimitive + Copy>(
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    n_intervals: U,
+) -> f64
+where
+    Func: Fn(F1) -> Complex<F1> + Sync,
+{
+    simpson_rule(|x: F1| func(x).re, lower_limit, upper_limit, n_intervals)
+}
+
+/// This function refines a previous Simpson's rule estimate of the integral of $f(x)$ from
+/// $a$ to $b$ with $n$ subintervals into the estimate with $2n$ subintervals.
+///
+/// With $T_n$ and $M_n$ the trapezoidal and midpoint rules on $n$ subintervals, Simpson's
+/// rule is $S_n = \frac{T_n + 2 M_n}{3}$, and $T_{2n} = \frac{T_n + M_n}{2}$. Recomputing
+/// $M_n$ ($n$ evaluations) recovers $T_n = 3 S_n - 2 M_n$ from `prev_value`, so that only the
+/// $2n$ midpoints of the refined subintervals are new, for $3n$ evaluations in total instead
+/// of the $4n + 1$ of [`simpson_rule`] with $2n$ subintervals.
+///
+/// Returns the refined estimate $S_{2n}$ and the Richardson estimate of its error
+/// $\frac{S_{2n} - S_n}{15}$.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `prev_n` - number of subintervals $n$ of the previous estimate.
+/// * `prev_value` - previous estimate $S_n$, as returned by [`simpson_rule`].
+///
+/// # Examples
+/// ```
+/// use integrate::newton_cotes::simpson::{simpson_refine, simpson_rule};
+///
+///
+/// let f = |x: f64| x.exp();
+///
+/// let coarse = simpson_rule(f, 0.0, 1.0, 10_usize);
+/// let (fine, error) = simpson_refine(f, 0.0, 1.0, 10_usize, coarse);
+///
+/// assert!((fine - (1f64.exp() - 1.0)).abs() < 2.0 * error.abs());
+/// ```
+#[must_use]
+pub fn simpson_refine<Func, F1: Float + Sync, F2: Float, U: Unsigned + ToPrimitive + Copy>(
+    f: Func,
+    a: F1,
+    b: F1,
+    prev_n: U,
+    prev_value: f64,
+) -> (f64, f64)
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    // checking arguments
+    check_newton_method_args(a, b, prev_n);
+
+    let n = prev_n.to_usize().unwrap();
+
+    // midpoints of the n subintervals, then of the 2n refined subintervals
+    let midpoint_rule = |n: usize| {
+        let h: F1 = (b - a) / F1::from(n).expect("failed to convert length of subinterval h");
+
+        let sum = sum_terms(0..n, 1, |i| {
+            let x = a + (F1::from(i).unwrap() + F1::from(0.5).unwrap()) * h;
+            f(x).to_f64().unwrap()
+        });
+
+        sum * h.to_f64().unwrap()
+    };
+
+    let midpoints = midpoint_rule(n);
+    let trapezoidal = 3.0 * prev_value - 2.0 * midpoints;
+
+    let refined_trapezoidal = (trapezoidal + midpoints) / 2.0;
+    let refined_midpoints = midpoint_rule(2 * n);
+
+    let refined = (refined_trapezoidal + 2.0 * refined_midpoints) / 3.0;
+
+    (refined, (refined - prev_value) / 15.0)
+}
+
+/// This function integrates $f(x)$ from $a$ to $b$ using the Simpson's rule once for each
+/// number of subintervals in `ns`, e.g. for a mesh or convergence study.
+///
+/// The integrals are computed in parallel across `ns`, and returned as `(n, integral)`
+/// pairs in the order of `ns`. See [`crate::convergence`] to turn them into error estimates.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `ns` - numbers of subintervals.
+///
+/// # Examples
+/// ```
+/// use integrate::newton_cotes::simpson::simpson_mesh_study;
+///
+///
+/// let f = |x: f64| x.exp();
+///
+/// for (n, integral) in simpson_mesh_study(f, 0.0, 1.0, [10, 20, 40, 80]) {
+///     println!("{} subintervals: {}", n, integral);
+/// }
+/// ```
+#[must_use]
+pub fn simpson_mesh_study<Func, F1: Float + Sync + Send, F2: Float>(
+    f: Func,
+    a: F1,
+    b: F1,
+    ns: impl IntoIterator<Item = usize>,
+) -> Vec<(usize, f64)>
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    let ns: Vec<usize> = ns.into_iter().collect();
+
+    ns.into_par_iter()
+        .map(|n| (n, simpson_rule(&f, a, b, n)))
+        .collect()
+}
+
+/// This function integrates $f(x)$ from $a$ to $b$ using the Simpson's rule on a graded
+/// mesh of `total_panels` subintervals, denser where $f$ is more curved.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `total_panels` - number of subintervals.
+///
+/// A pilot pass evaluates $f$ on `total_panels` uniform cells and estimates $|f''|$ by
+/// central differences. The mesh then equidistributes the density $|f''|^{\frac{1}{5}}$,
+/// raised by a tenth of its mean so that flat regions keep some subintervals: each
+/// subinterval holds the same share of $\int_{a}^{b} |f''(x)|^{\frac{1}{5}} dx$. Simpson's
+/// rule is finally applied on each subinterval, with its midpoint as the middle node.
+///
+/// The graded mesh pays off when few subintervals are affordable, or when the curvature of
+/// $f$ is concentrated in a small region. For smooth integrands and many subintervals, the
+/// errors of the uniform mesh of [`simpson_rule`] cancel between neighbouring subintervals,
+/// as the Euler-Maclaurin summation formula shows, and the uniform mesh is more accurate.
+///
+/// # Examples
+/// ```
+/// use integrate::newton_cotes::simpson::simpson_rule_curvature_adaptive;
+///
+///
+/// let runge = |x: f64| 1.0 / (1.0 + 25.0 * x * x);
+///
+/// let integral = simpson_rule_curvature_adaptive(runge, -1.0, 1.0, 16_usize);
+///
+/// assert!((integral - 0.4 * 5.0_f64.atan()).abs() < 1e-5);
+/// ```
+#[must_use]
+pub fn simpson_rule_curvature_adaptive<
+    Func,
+    F1: Float + Sync,
+    F2: Float,
+    U: Unsigned + ToPrimitive + Copy,
+>(
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    total_panels: U,
+) -> f64
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    // checking arguments
+    check_newton_method_args(lower_limit, upper_limit, total_panels);
+
+    let n = total_panels.to_usize().unwrap();
+
+    // pilot pass, at least 4 cells for the differences to be meaningful
+    let cells = n.max(4);
+    let h = (upper_limit - lower_limit) / F1::from(cells).unwrap();
+    let node = |i: usize| lower_limit + F1::from(i).unwrap() * h;
+
+    let values: Vec<f64> = (0..=cells)
+        .map(|i| func(node(i)).to_f64().unwrap())
+        .collect();
+
+    let h2 = h.to_f64().unwrap().powi(2);
+    let mut density: Vec<f64> = values
+        .windows(3)
+        .map(|v| ((v[0] - 2.0 * v[1] + v[2]) / h2).abs().powf(0.2))
+        .collect();
+
+    // the differences are centred on the interior nodes, extend them to the end nodes
+    density.insert(0, density[0]);
+    density.push(density[density.len() - 1]);
+
+    // a tenth of the mean density in every cell, and a uniform mesh if f'' vanishes
+    // everywhere, so that every cell has a positive mass
+    let mean = density.iter().sum::<f64>() / density.len() as f64;
+    let floor = if mean > 0.0 { 0.1 * mean } else { 1.0 };
+
+    // cumulative density at the pilot nodes, trapezoidal rule on each cell
+    let mut cumulative = vec![0.0; cells + 1];
+    for i in 0..cells {
+        cumulative[i + 1] = cumulative[i] + (density[i] + density[i + 1]) / 2.0 + floor;
+    }
+
+    // mesh nodes, equidistributing the cumulative density
+    let total = cumulative[cells];
+    let mut cell = 0;
+    let mut mesh: Vec<F1> = Vec::with_capacity(n + 1);
+    mesh.push(lower_limit);
+    for k in 1..n {
+        let target = total * k as f64 / n as f64;
+        while cumulative[cell + 1] < target {
+            cell += 1;
+        }
+
+        let fraction = (target - cumulative[cell]) / (cumulative[cell + 1] - cumulative[cell]);
+        mesh.push(node(cell) + F1::from(fraction).unwrap() * h);
+    }
+    mesh.push(upper_limit);
+
+    let two = F1::one() + F1::one();
+
+    mesh.windows(2)
+        .map(|x| {
+            let left = func(x[0]).to_f64().unwrap();
+            let middle = func((x[0] + x[1]) / two).to_f64().unwrap();
+            let right = func(x[1]).to_f64().unwrap();
+
+            (x[1] - x[0]).to_f64().unwrap() * (left + 4.0 * middle + right) / 6.0
+        })
+        .sum()
+}
+
+/// This function integrates $f(x, y)$ over the rectangle $\[a, b\] \times \[c, d\]$ using
+/// the tensor product Simpson's rule.
+///
+/// Iterating [`simpson_rule`], i.e. applying it in $x$ to the inner integral in $y$, computes
+/// the nodes and weights in $y$ again for every node in $x$. Here the nodes and weights of
+/// both directions are computed once, then shared by all the inner sums.
+///
+/// * `func` - Integrand function of two variables.
+/// * `x_limits` - limits $(a, b)$ of integration in $x$.
+/// * `y_limits` - limits $(c, d)$ of integration in $y$.
+/// * `nx` - number of subintervals in $x$.
+/// * `ny` - number of subintervals in $y$.
+///
+/// # Examples
+/// ```
+/// use integrate::newton_cotes::simpson::simpson_rule_2d_cached;
+///
+///
+/// let f = |x: f64, y: f64| x * x + y * y;
+///
+/// let integral = simpson_rule_2d_cached(f, (0.0, 2.0), (-1.0, 1.0), 10_usize, 10_usize);
+///
+/// // Simpson's rule is exact for quadratics
+/// assert!((integral - 20.0 / 3.0).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn simpson_rule_2d_cached<
+    Func,
+    F1: Float + Send + Sync,
+    F2: Float,
+    U: Unsigned + ToPrimitive + Copy,
+>(
+    func: Func,
+    x_limits: (F1, F1),
+    y_limits: (F1, F1),
+    nx: U,
+    ny: U,
+) -> f64
+where
+    Func: Fn(F1, F1) -> F2 + Sync,
+{
+    // checking arguments
+    check_newton_method_args(x_limits.0, x_limits.1, nx);
+    check_newton_method_args(y_limits.0, y_limits.1, ny);
+
+    let (xs, x_weights): (Vec<F1>, Vec<f64>) =
+        simpson_nodes(x_limits.0, x_limits.1, nx.to_usize().unwrap()).unzip();
+    let (ys, y_weights): (Vec<F1>, Vec<f64>) =
+        simpson_nodes(y_limits.0, y_limits.1, ny.to_usize().unwrap()).unzip();
+
+    xs.into_par_iter()
+        .zip(x_weights)
+        .map(|(x, x_weight)| {
+            let inner: f64 = ys
+                .iter()
+                .zip(y_weights.iter())
+                .map(|(&y, y_weight)| y_weight * func(x, y).to_f64().unwrap())
+                .sum();
+
+            x_weight * inner
+        })
+        .sum()
+}
+
+/// Same as [`simpson_rule`], but returns an error instead of panicking on invalid arguments,
+/// see [`try_rectangle_rule`](super::rectangle::try_rectangle_rule), or of a meaningless
+/// result when the length of the subintervals $h = \frac{b-a}{n}$ underflows to zero,
+/// [`QuadError::StepUnderflow`], or when the length $b - a$ of the interval overflows to
+/// infinity, [`QuadError::IntervalOverflow`].
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_intervals` - number of subintervals.
+///
+/// # Examples
+/// ```
+/// use integrate::error::QuadError;
+/// use integrate::newton_cotes::simpson::try_simpson_rule;
+///
+///
+/// let square = |x: f64| x * x;
+///
+/// assert!(try_simpson_rule(square, 0.0, 1.0, 10_usize).is_ok());
+/// assert_eq!(try_simpson_rule(square, 0.0, 1e-320, 1_000_000_usize), Err(QuadError::StepUnderflow));
+/// assert_eq!(try_simpson_rule(square, -f64::MAX, f64::MAX, 10_usize), Err(QuadError::IntervalOverflow));
+/// ```
+pub fn try_simpson_rule<Func, F1: Float + Sync, F2: Float, U: Unsigned + ToPrimitive + Copy>(
+    f: Func,
+    a: F1,
+    b: F1,
+    n: U,
+) -> Result<f64, QuadError>
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    // checking arguments
+    validate_newton_method_args(a, b, n)?;
+    check_step(a, b, n)?;
+
+    Ok(simpson_rule(f, a, b, n))
+}
+
+/// Same as [`simpson_rule`], but with the number of sample points rather than the number
+/// of subintervals, as in NumPy or SciPy.
+///
+/// The $2n + 1$ points of Simpson's rule with $n$ subintervals are the ends of the
+/// subintervals and their midpoints, `n_points` must therefore be odd, and at least 3.
+/// [`QuadError::IntervalOverflow`] is returned if the length $b - a$ of the interval
+/// overflows to infinity, [`QuadError::InfiniteLimit`] or [`QuadError::NanLimit`] if either
+/// limit is infinite or `NaN`.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_points` - number of sample points, odd and at least 3.
+///
+/// # Examples
+/// ```
+/// use integrate::error::QuadError;
+/// use integrate::newton_cotes::simpson::simpson_rule_from_points;
+///
+///
+/// let cube = |x: f64| x * x * x;
+///
+/// // a single subinterval, exact for cubics
+/// let integral = simpson_rule_from_points(cube, 0.0, 2.0, 3).unwrap();
+/// assert!((integral - 4.0).abs() < 1e-14);
+///
+/// assert_eq!(simpson_rule_from_points(cube, 0.0, 2.0, 4), Err(QuadError::InvalidPointCount));
+/// ```
+pub fn simpson_rule_from_points<Func, F1: Float + Sync, F2: Float>(
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    n_points: usize,
+) -> Result<f64, QuadError>
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    if n_points < 3 || n_points % 2 == 0 {
+        return Err(QuadError::InvalidPointCount);
+    }
+
+    check_width(lower_limit, upper_limit)?;
+
+    Ok(simpson_rule(
+        func,
+        lower_limit,
+        upper_limit,
+        (n_points - 1) / 2,
+    ))
+}
+
+/// This function integrates $f(x)$ from $a$ to $b$ using the Simpson's rule, for an
+/// integrand which may be undefined at some nodes.
+///
+/// The nodes where `func` returns `None` contribute zero to the sum, as a guard returning
+/// $0$ around the undefined points would, and are counted.
+///
+/// * `func` - Integrand function of a single variable, `None` where it is undefined.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_intervals` - number of subintervals.
+///
+/// Returns the integral along with the number of nodes skipped.
+///
+/// # Examples
+/// ```
+/// use integrate::newton_cotes::simpson::simpson_rule_option;
+///
+///
+/// // ln(x) is undefined at 0
+/// let ln = |x: f64| if x > 0.0 { Some(x.ln()) } else { None };
+///
+/// let (integral, skipped) = simpson_rule_option(ln, 0.0, 1.0, 1_000_usize);
+///
+/// assert_eq!(skipped, 1);
+/// assert!((integral + 1.0).abs() < 1e-2);
+/// ```
+#[must_use]
+pub fn simpson_rule_option<
+    Func,
+    F1: Float + Send + Sync,
+    F2: Float,
+    U: Unsigned + ToPrimitive + Copy,
+>(
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    n_intervals: U,
+) -> (f64, usize)
+where
+    Func: Fn(F1) -> Option<F2> + Sync,
+{
+    check_newton_method_args(lower_limit, upper_limit, n_intervals);
+
+    simpson_nodes(lower_limit, upper_limit, n_intervals.to_usize().unwrap())
+        .map(|(x, weight)| match func(x) {
+            Some(y) => (weight * y.to_f64().unwrap(), 0),
+            None => (0.0, 1),
+        })
+        .reduce(|| (0.0, 0), |(s1, k1), (s2, k2)| (s1 + s2, k1 + k2))
+}
+
+/// This function bounds the error of [`simpson_rule`] from a bound on the fourth derivative
+/// of $f$.
+///
+/// On $\[a, b\]$ split into $n$ subintervals of length $h = \frac{b-a}{n}$, the error of
+/// Simpson's rule is
+/// ```math
+/// \left| S_h(f) - \int_{a}^{b} f(x)dx \right| \leq \frac{h^4}{2880} (b-a) M
+/// ```
+///
+/// for any $M \geq \max_{a \leq x \leq b} |f^{(4)}(x)|$. The bound is only as rigorous as
+/// `max_abs_f4`: sampling $|f^{(4)}|$ at a few nodes may miss its maximum between them.
+///
+/// * `max_abs_f4` - bound $M$ on $|f^{(4)}(x)|$ over $\[a, b\]$.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_intervals` - number of subintervals.
+///
+/// # Examples
+/// ```
+/// use integrate::newton_cotes::simpson::simpson_rule_rigorous_error;
+///
+///
+/// // |exp⁽⁴⁾| = exp ≤ e over [0, 1], so the bound is (0.1⁴ / 2880) * e
+/// let bound = simpson_rule_rigorous_error(1f64.exp(), 0.0, 1.0, 10_usize);
+///
+/// assert!((bound - 1e-4 / 2880.0 * 1f64.exp()).abs() < 1e-15);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `max_abs_f4` is negative or `NaN`, and in the cases where [`simpson_rule`]
+/// panics on its arguments.
+#[must_use]
+pub fn simpson_rule_rigorous_error<F: Float, U: Unsigned + ToPrimitive + Copy>(
+    max_abs_f4: F,
+    a: F,
+    b: F,
+    n: U,
+) -> f64 {
+    // checking arguments
+    check_newton_method_args(a, b, n);
+
+    if max_abs_f4.is_nan() || max_abs_f4 < F::zero() {
+        panic!("the bound on the fourth derivative must be non-negative.");
+    }
+
+    let h = (b - a) / F::from(n).expect("failed to convert n");
+    let h = h.to_f64().unwrap();
+
+    h.powi(4) / 2880.0 * (b - a).to_f64().unwrap() * max_abs_f4.to_f64().unwrap()
+}
+
+/// Relative positions in $\[a, b\]$ of the nodes sampled by [`simpson_rule_sanity`], irregularly
+/// spaced so that periodic integrands are unlikely to take the same value at all of them.
+const SANITY_NODES: [f64; 5] = [0.0, 0.137, 0.421, 0.768, 1.0];
+
+/// This function integrates $f(x)$ from $a$ to $a+nh$ using the Simpson's rule, after
+/// checking that $f$ is not constant.
+///
+/// $f$ is first sampled at a few nodes of $\[a, b\]$: if it returns the same value at all
+/// of them, it most likely ignores its argument (e.g. `|_| 1.0` instead of `|x| x`), and
+/// [`QuadError::ConstantIntegrand`] is returned. Legitimately constant integrands should
+/// be integrated with [`simpson_rule`] instead.
+///
+/// Invalid arguments are reported as by [`try_simpson_rule`]: [`QuadError::ZeroSteps`] if `n`
+/// is zero, [`QuadError::InfiniteLimit`] if either limit is infinite,
+/// [`QuadError::StepUnderflow`] if the length of the subintervals underflows to zero, and
+/// [`QuadError::IntervalOverflow`] if the length of the interval overflows.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_intervals` - number of subintervals.
+///
+/// # Examples
+/// ```
+/// use integrate::error::QuadError;
+/// use integrate::newton_cotes::simpson::simpson_rule_sanity;
+///
+///
+/// let constant = |_: f64| 1.0;
+/// assert_eq!(simpson_rule_sanity(constant, 0.0, 1.0, 10_usize), Err(QuadError::ConstantIntegrand));
+///
+/// let square = |x: f64| x * x;
+/// assert!(simpson_rule_sanity(square, 0.0, 1.0, 10_usize).is_ok());
+/// ```
+pub fn simpson_rule_sanity<Func, F1: Float + Sync, F2: Float, U: Unsigned + ToPrimitive + Copy>(
+    f: Func,
+    a: F1,
+    b: F1,
+    n: U,
+) -> Result<f64, QuadError>
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    // checking arguments
+    validate_newton_method_args(a, b, n)?;
+    check_step(a, b, n)?;
+
+    let first = f(a);
+
+    let is_constant = SANITY_NODES.iter().skip(1).all(|&t| {
+        let x = a + (b - a) * F1::from(t).unwrap();
+        f(x) == first
+    });
+
+    if is_constant {
+        return Err(QuadError::ConstantIntegrand);
+    }
+
+    Ok(simpson_rule(f, a, b, n))
+}
+
+/// This function integrates both $f(x)$ and $|f(x)|$ from $a$ to $a+nh$ using the Simpson's
+/// rule, in a single pass over the nodes.
+///
+/// Returns the signed integral $\int_{a}^{b} f(x) dx$ and the integral of the absolute
+/// value $\int_{a}^{b} |f(x)| dx$, the latter being computed with the same weights applied
+/// to $|f(x_i)|$.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_intervals` - number of subintervals.
+///
+/// Note that $|f|$ is usually not smooth where $f$ changes sign, so that the integral of
+/// the absolute value converges more slowly than the signed integral.
+///
+/// # Examples
+/// ```
+/// use std::f64::consts::PI;
+///
+/// use integrate::newton_cotes::simpson::simpson_rule_with_abs;
+///
+///
+/// let f = |x: f64| x.sin();
+///
+/// let (integral, abs_integral) = simpson_rule_with_abs(f, 0.0, 2.0 * PI, 1_000_usize);
+/// ```
+#[must_use]
+pub fn simpson_rule_with_abs<
+    Func,
+    F1: Float + Send + Sync,
+    F2: Float,
+    U: Unsigned + ToPrimitive + Copy,
+>(
+    f: Func,
+    a: F1,
+    b: F1,
+    n: U,
+) -> (f64, f64)
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    // checking arguments
+    check_newton_method_args(a, b, n);
+
+    simpson_nodes(a, b, n.to_usize().unwrap())
+        .map(|(x, w)| {
+            let y = f(x).to_f64().unwrap();
+            (w * y, w * y.abs())
+        })
+        .reduce(|| (0.0, 0.0), |(s1, a1), (s2, a2)| (s1 + s2, a1 + a2))
+}
+
+/// This function splits the Simpson's rule sum for $f(x)$ from $a$ to $a+nh$ into its
+/// positive and negative contributions.
+///
+/// Returns `(positive_sum, negative_sum)`, the sums of the weighted node contributions
+/// $w_j f(x_j)$ that are respectively positive and negative, the latter as a non-negative
+/// magnitude, so that the integral is `positive_sum - negative_sum`.
+///
+/// When both sums are large compared to their difference, the integral results from a
+/// cancellation and is ill-conditioned: the rounding errors made on the sums, relative to
+/// `positive_sum + negative_sum`, are amplified in the integral.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_intervals` - number of subintervals.
+///
+/// # Examples
+/// ```
+/// use integrate::newton_cotes::simpson::simpson_rule_signed_parts;
+///
+///
+/// let f = |x: f64| x.sin();
+///
+/// let (positive, negative) = simpson_rule_signed_parts(f, -1.0, 2.0, 1_000_usize);
+///
+/// let exact = 1f64.cos() - 2f64.cos();
+/// assert!((positive - negative - exact).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn simpson_rule_signed_parts<
+    Func,
+    F1: Float + Send + Sync,
+    F2: Float,
+    U: Unsigned + ToPrimitive + Copy,
+>(
+    f: Func,
+    a: F1,
+    b: F1,
+    n: U,
+) -> (f64, f64)
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    // checking arguments
+    check_newton_method_args(a, b, n);
+
+    simpson_nodes(a, b, n.to_usize().unwrap())
+        .map(|(x, w)| {
+            let contribution = w * f(x).to_f64().unwrap();
+
+            if contribution > 0.0 {
+                (contribution, 0.0)
+            } else {
+                (0.0, -contribution)
+            }
+        })
+        .reduce(|| (0.0, 0.0), |(p1, n1), (p2, n2)| (p1 + p2, n1 + n2))
+}
+
+/// This function computes, using the Simpson's rule, the integral of $f(x)$ from $a$ to $b$
+/// and the abscissa of the centroid of the region under $f$
+/// ```math
+/// \bar{x} = \frac{\int_{a}^{b} x f(x) dx}{\int_{a}^{b} f(x) dx}
+/// ```
+///
+/// Both integrals are computed in a single pass over the nodes, with the same weights.
+/// Returns `(integral, centroid)`, the centroid being NaN when the integral is zero.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_intervals` - number of subintervals.
+///
 /// # Examples
 /// ```
-/// use integrate::newton_cotes::simpson::simpson_rule;
+/// use integrate::newton_cotes::simpson::centroid;
 ///
 ///
-/// let square = |x: f64| x * x;
-///
-/// let a = 0.0;
-/// let b = 1.0;
+/// // triangle of height 1 over [0, 1], its centroid is at 2/3
+/// let f = |x: f64| x;
 ///
-/// let num_steps: usize = 1_000_000;
+/// let (area, x_bar) = centroid(f, 0.0, 1.0, 10_usize);
 ///
-/// let integral = simpson_rule(square, a, b, num_steps);
+/// assert!((area - 0.5).abs() < 1e-15);
+/// assert!((x_bar - 2.0 / 3.0).abs() < 1e-15);
 /// ```
-///
-/// # Resources
-/// [Methods of numerical Integration (2nd edition), by Philip J. Davis and Philip Rabinowitz.](https://www.cambridge.org/core/journals/mathematical-gazette/article/abs/methods-of-numerical-integration-2nd-edition-by-philip-j-davis-and-philip-rabinowitz-pp-612-3650-1984-isbn-0122063600-academic-press/C331158D0392E1D5CD9B0C6ED4EE5F43)
-pub fn simpson_rule<Func, F1: Float + Sync, F2: Float, U: Unsigned + ToPrimitive + Copy>(
+#[must_use]
+pub fn centroid<Func, F1: Float + Send + Sync, F2: Float, U: Unsigned + ToPrimitive + Copy>(
     f: Func,
     a: F1,
     b: F1,
     n: U,
-) -> f64
+) -> (f64, f64)
 where
     Func: Fn(F1) -> F2 + Sync,
 {
     // checking arguments
     check_newton_method_args(a, b, n);
 
+    let (integral, moment) = simpson_nodes(a, b, n.to_usize().unwrap())
+        .map(|(x, w)| {
+            let y = w * f(x).to_f64().unwrap();
+            (y, x.to_f64().unwrap() * y)
+        })
+        .reduce(|| (0.0, 0.0), |(s1, m1), (s2, m2)| (s1 + s2, m1 + m2));
+
+    let centroid = if integral == 0.0 {
+        f64::NAN
+    } else {
+        moment / integral
+    };
+
+    (integral, centroid)
+}
+
+/// Nodes $x_j = a + j\frac{h}{2}$, $j = 0, ..., 2n$, of the composite Simpson's rule with
+/// $n$ subintervals of length $h = \frac{b-a}{n}$, paired with their weights
+/// $\frac{h}{6}$, $\frac{4h}{6}$ or $\frac{2h}{6}$.
+pub(crate) fn simpson_nodes<F1: Float + Send + Sync>(
+    a: F1,
+    b: F1,
+    n: usize,
+) -> impl IndexedParallelIterator<Item = (F1, f64)> {
     // length of each subinterval
     let h: F1 = (b - a) / F1::from(n).expect("failed to convert length of subinterval h");
 
     // half the length of each subinterval h/2
     let h_over_2 = h / F1::from(2).unwrap();
 
-    // first term of the sum
-    let i_0 = f(a).to_f64().unwrap() + 4.0 * f(a + h_over_2).to_f64().unwrap();
+    let h_over_6 = h.to_f64().unwrap() * 1.0.div(6.0);
 
-    let integral: f64 = (2..(2 * n.to_usize().unwrap()))
-        .into_par_iter()
-        .step_by(2)
-        .map(|i| {
-            // subinterval index (as real)
-            let i_plus_1 = F1::from(i + 1).expect("failed to convert subinterval index (i+1)");
-            let i = F1::from(i).expect("failed to convert subinterval index i");
+    (0..2 * n + 1).into_par_iter().map(move |j| {
+        let coefficient = if j == 0 || j == 2 * n {
+            1.0
+        } else if j % 2 == 1 {
+            4.0
+        } else {
+            2.0
+        };
 
-            2.0 * f(a + i * h_over_2).to_f64().unwrap()
-                + 4.0 * f(a + i_plus_1 * h_over_2).to_f64().unwrap()
-        })
-        .sum();
+        // node index (as real)
+        let x = if j == 2 * n {
+            b
+        } else {
+            a + F1::from(j).expect("failed to convert node index j") * h_over_2
+        };
 
-    let n = F1::from(n).expect("failed to convert n");
-    let i_n = f(a + n * h_over_2).to_f64().unwrap();
+        (x, coefficient * h_over_6)
+    })
+}
 
-    (i_0 + integral + i_n) * h.to_f64().unwrap() * 1.0.div(6.0)
+/// Weighted sum $2 f(a + i\frac{h}{2}) + 4 f(a + (i+1)\frac{h}{2})$ contributed by the
+/// $i^{th}$ half-step node and the midpoint that follows it.
+fn interior_term<Func, F1: Float, F2: Float>(f: &Func, a: F1, h_over_2: F1, i: usize) -> f64
+where
+    Func: Fn(F1) -> F2,
+{
+    // subinterval index (as real)
+    let i_plus_1 = F1::from(i + 1).expect("failed to convert subinterval index (i+1)");
+    let i = F1::from(i).expect("failed to convert subinterval index i");
+
+    2.0 * f(a + i * h_over_2).to_f64().unwrap() + 4.0 * f(a + i_plus_1 * h_over_2).to_f64().unwrap()
+}
+
+/// Simpson's rule computed in chunks of subintervals, so that a long integration can be
+/// checkpointed and resumed.
+///
+/// Each call to [`ResumableSimpson::advance`] integrates the next subintervals, and
+/// [`ResumableSimpson::accumulated`] returns the integral over the subintervals covered so
+/// far. Once all of them are covered, it equals [`simpson_rule`] up to rounding. With the
+/// `serde` feature enabled the state can be serialized between two chunks.
+///
+/// # Examples
+/// ```
+/// use integrate::newton_cotes::simpson::ResumableSimpson;
+///
+///
+/// let f = |x: f64| x.exp();
+///
+/// let mut integration = ResumableSimpson::new(0.0, 1.0, 1_000);
+///
+/// while !integration.is_complete() {
+///     integration.advance(f, 100);
+/// }
+///
+/// assert!((integration.accumulated() - (1f64.exp() - 1.0)).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResumableSimpson {
+    lower_limit: f64,
+    upper_limit: f64,
+    n_intervals: usize,
+    // number of subintervals covered so far
+    covered: usize,
+    // f(x_0) + 4 f(m_0) + 2 f(x_1) + ... + 4 f(m_{k-1}) + 2 f(x_k), k = covered
+    sum: f64,
+    // f(x_k)
+    last_value: f64,
+}
+
+impl ResumableSimpson {
+    /// Prepares the integration from $a$ to $b$ with `n_intervals` subintervals, none of
+    /// them covered yet.
+    ///
+    /// * `lower_limit` - lower limit of the integration interval.
+    /// * `upper_limit` - upper limit of the integration interval.
+    /// * `n_intervals` - number of subintervals.
+    pub fn new(lower_limit: f64, upper_limit: f64, n_intervals: usize) -> Self {
+        // checking arguments
+        check_newton_method_args(lower_limit, upper_limit, n_intervals);
+
+        ResumableSimpson {
+            lower_limit,
+            upper_limit,
+            n_intervals,
+            covered: 0,
+            sum: 0.0,
+            last_value: 0.0,
+        }
+    }
+
+    /// Integrates the next `chunk` subintervals, or the remaining ones if there are fewer.
+    ///
+    /// * `func` - Integrand function of a single variable, the same on every call.
+    /// * `chunk` - number of subintervals to cover.
+    pub fn advance<Func>(&mut self, func: Func, chunk: usize)
+    where
+        Func: Fn(f64) -> f64 + Sync,
+    {
+        let end = (self.covered + chunk).min(self.n_intervals);
+        if end == self.covered {
+            return;
+        }
+
+        let a = self.lower_limit;
+        let h = self.h();
+        let h_over_2 = h / 2.0;
+
+        if self.covered == 0 {
+            self.sum = func(a);
+        }
+
+        // midpoints of the subintervals of this chunk, then their right end points but the last
+        let midpoints = sum_terms(self.covered..end, 1, |i| {
+            func(a + (2 * i + 1) as f64 * h_over_2)
+        });
+        let right_ends = sum_terms(self.covered + 1..end, 1, |i| func(a + i as f64 * h));
+
+        self.last_value = if end == self.n_intervals {
+            func(self.upper_limit)
+        } else {
+            func(a + end as f64 * h)
+        };
+
+        self.sum += 4.0 * midpoints + 2.0 * right_ends + 2.0 * self.last_value;
+        self.covered = end;
+    }
+
+    /// Integral over the subintervals covered so far, i.e. from $a$ to $a + kh$ once $k$
+    /// subintervals are covered.
+    #[must_use]
+    pub fn accumulated(&self) -> f64 {
+        if self.covered == 0 {
+            return 0.0;
+        }
+
+        // the last end point has weight 1, not 2
+        (self.sum - self.last_value) * self.h() / 6.0
+    }
+
+    /// Number of subintervals covered so far.
+    #[must_use]
+    pub fn covered(&self) -> usize {
+        self.covered
+    }
+
+    /// Whether all the subintervals are covered.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.covered == self.n_intervals
+    }
+
+    fn h(&self) -> f64 {
+        (self.upper_limit - self.lower_limit) / self.n_intervals as f64
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::f64::consts::PI;
 
     use super::*;
     // use test::Bencher;
@@ -169,6 +1431,16 @@ mod tests {
     const EPSILON: f64 = 10e-7;
     const NUM_STEPS: usize = 1_000_000;
 
+    #[test]
+    fn test_curvature_adaptive_without_curvature() {
+        // f'' vanishes everywhere, the mesh is uniform
+        let integral = simpson_rule_curvature_adaptive(|_: f64| 2.0, 0.0, 1.0, 10_usize);
+        assert!((integral - 2.0).abs() < 1e-14);
+
+        let integral = simpson_rule_curvature_adaptive(|x: f64| 3.0 * x + 1.0, 0.0, 4.0, 8_usize);
+        assert!((integral - 28.0).abs() < 1e-12);
+    }
+
     #[test]
     fn test_integral_value() {
         fn square(x: f64) -> f64 {
@@ -236,6 +1508,70 @@ mod tests {
         assert!((integral - analytic_result).abs() < EPSILON);
     }
 
+    #[test]
+    fn test_deterministic_sum() {
+        let f = |x: f64| x.exp() * (10.0 * x).sin();
+
+        let a = 0.0;
+        let b = 1.0;
+        let n: usize = 10_000;
+
+        let reference = simpson_rule_deterministic(f, a, b, n);
+
+        for _ in 0..100 {
+            let integral = simpson_rule_deterministic(f, a, b, n);
+
+            assert_eq!(integral.to_bits(), reference.to_bits());
+        }
+
+        assert!((reference - simpson_rule(f, a, b, n)).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_last_node_is_upper_limit() {
+        // the last node used to be a + nh/2 instead of b
+        let nodes = std::sync::Mutex::new(Vec::new());
+
+        let _ = simpson_rule(
+            |x: f64| {
+                nodes.lock().unwrap().push(x);
+                x
+            },
+            0.0,
+            3.0,
+            4_usize,
+        );
+
+        let nodes = nodes.into_inner().unwrap();
+        assert!(nodes.contains(&3.0), "b was not sampled: {:?}", nodes);
+    }
+
+    #[test]
+    fn test_cubic_is_exact() {
+        let cube = |x: f64| x.powi(3);
+
+        let integral = simpson_rule(cube, 0.0, 2.0, 3_usize);
+
+        assert!((integral - 4.0).abs() < 10e-12);
+    }
+
+    #[test]
+    fn test_with_abs() {
+        let f = |x: f64| x.sin();
+
+        let (integral, abs_integral) = simpson_rule_with_abs(f, 0.0, 2.0 * PI, 1_000_usize);
+
+        assert!(integral.abs() < 10e-12);
+        assert!((abs_integral - 4.0).abs() < 10e-6);
+
+        // same weights as simpson_rule
+        let g = |x: f64| x.exp();
+        let (integral, abs_integral) = simpson_rule_with_abs(g, 0.0, 1.0, 100_usize);
+
+        assert!((integral - simpson_rule(g, 0.0, 1.0, 100_usize)).abs() < 10e-14);
+        assert!((integral - abs_integral).abs() < 10e-14);
+    }
+
     // #[bench]
     // fn bench_integral_value(bencher: &mut Bencher) {
     //     fn f1(x: f64) -> f64 {
@@ -249,4 +1585,512 @@ mod tests {
     //         simpson_rule(f1, a, b, NUM_STEPS);
     //     })
     // }
+
+    #[test]
+    fn test_centroid() {
+        // symmetric about the midpoint of [0, 2]
+        let gaussian = |x: f64| (-(x - 1.0).powi(2)).exp();
+
+        let (integral, x_bar) = centroid(gaussian, 0.0, 2.0, 100_usize);
+
+        // √π erf(1)
+        assert!((integral - 1.493_648_265_624_854).abs() < 1e-8);
+        assert!((x_bar - 1.0).abs() < 1e-12);
+
+        // zero integral
+        let (integral, x_bar) = centroid(|_: f64| 0.0, -1.0, 1.0, 100_usize);
+        assert_eq!(integral, 0.0);
+        assert!(x_bar.is_nan());
+    }
+
+    #[test]
+    fn test_serial_below_threshold() {
+        use std::sync::Mutex;
+        use std::thread::{self, ThreadId};
+
+        use crate::newton_cotes::PARALLEL_THRESHOLD;
+
+        let caller = thread::current().id();
+        let threads: Mutex<Vec<ThreadId>> = Mutex::new(Vec::new());
+
+        let f = |x: f64| {
+            threads.lock().unwrap().push(thread::current().id());
+            x.exp()
+        };
+
+        let n = PARALLEL_THRESHOLD / 2;
+        let integral = simpson_rule(f, 0.0, 1.0, n);
+
+        // every evaluation happened on the calling thread
+        assert!(threads.lock().unwrap().iter().all(|&id| id == caller));
+
+        // and the terms were summed in order
+        let deterministic = simpson_rule_deterministic(|x: f64| x.exp(), 0.0, 1.0, n);
+        assert_eq!(integral.to_bits(), deterministic.to_bits());
+
+        // above the threshold, the parallel sum agrees up to rounding
+        let n = 2 * PARALLEL_THRESHOLD;
+        let parallel = simpson_rule(|x: f64| x.exp(), 0.0, 1.0, n);
+        let deterministic = simpson_rule_deterministic(|x: f64| x.exp(), 0.0, 1.0, n);
+        assert!((parallel - deterministic).abs() < 1e-13);
+    }
+
+    #[test]
+    fn test_sanity_constant_integrand() {
+        fn f(_: f64) -> f64 {
+            1.0
+        }
+
+        assert_eq!(
+            simpson_rule_sanity(f, 0.0, 1.0, 100_usize),
+            Err(QuadError::ConstantIntegrand)
+        );
+
+        let integral = simpson_rule_sanity(|x: f64| x.sin(), 0.0, 2.0 * PI, 100_usize).unwrap();
+        assert!(integral.abs() < 1e-12);
+
+        // invalid arguments are returned rather than panicking
+        assert_eq!(
+            simpson_rule_sanity(|x: f64| x, 0.0, 1.0, 0_usize),
+            Err(QuadError::ZeroSteps)
+        );
+        assert_eq!(
+            simpson_rule_sanity(|x: f64| x, f64::NEG_INFINITY, 1.0, 10_usize),
+            Err(QuadError::InfiniteLimit)
+        );
+    }
+
+    #[test]
+    fn test_split_at_midpoint_singularity() {
+        // ∫₀² 1 / √|x - 1| dx = 4, singular at the midpoint of [0, 2]
+        let f = |x: f64| 1.0 / (x - 1.0).abs().sqrt();
+
+        assert!(!simpson_rule(f, 0.0, 2.0, 1_000_usize).is_finite());
+
+        // the convergence is only like √h near the singularity
+        let integral = simpson_rule_split_at(f, 0.0, 2.0, 100_000_usize, 1.0);
+        assert!((integral - 4.0).abs() < 1e-2);
+
+        // away from any singularity the split changes nothing but the nodes
+        let smooth = simpson_rule_split_at(|x: f64| x.exp(), 0.0, 2.0, 100_usize, 0.5);
+        assert!((smooth - (2f64.exp() - 1.0)).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_skipped_endpoints() {
+        let f = |x: f64| 1.0 / (x - 1.0).abs().sqrt();
+
+        // the halves of simpson_rule_split_at skip the split point without warning
+        let (_, skipped) = open_endpoints_rule(f, 0.0, 1.0, 10_usize);
+        assert_eq!(skipped, Some("the upper limit"));
+        let (_, skipped) = open_endpoints_rule(f, 1.0, 2.0, 10_usize);
+        assert_eq!(skipped, Some("the lower limit"));
+        let (_, skipped) = open_endpoints_rule(|x: f64| x, 1.0, 2.0, 10_usize);
+        assert_eq!(skipped, None);
+
+        // the public rule warns once, whichever call printed the warning
+        let _ = simpson_rule_open_endpoints(f, 0.0, 1.0, 10_usize);
+        assert!(!OPEN_ENDPOINTS_WARNING.warn("Warning: repeated"));
+    }
+
+    #[test]
+    fn test_real_part() {
+        let f = |x: f64| Complex::new(0.0, x).exp();
+        let n = 1_000_usize;
+
+        // ∫₀^π e^{ix} dx = 2i, its real part ∫₀^π cos(x) dx vanishes
+        let real = simpson_rule_real_part(f, 0.0, PI, n);
+        assert!(real.abs() < 1e-12);
+
+        // ∫₀¹ e^{(1+i)x} dx = (e^{1+i} - 1) / (1 + i), whose real part doesn't vanish
+        let g = |x: f64| Complex::new(x, x).exp();
+        let exact = (Complex::new(1.0, 1.0).exp() - 1.0) / Complex::new(1.0, 1.0);
+
+        let real = simpson_rule_real_part(g, 0.0, 1.0, n);
+        assert!((real - exact.re).abs() < 1e-12);
+
+        // the complex composite Simpson's sum, with its weights 1, 4, 2, 4, ..., 4, 1
+        let h_over_2 = 0.5 / n as f64;
+        let sum: Complex<f64> = (0..=2 * n)
+            .map(|k| {
+                let weight = if k == 0 || k == 2 * n {
+                    1.0
+                } else if k % 2 == 1 {
+                    4.0
+                } else {
+                    2.0
+                };
+                g(k as f64 * h_over_2) * weight
+            })
+            .sum();
+        let full = sum * h_over_2 / 3.0;
+
+        assert!((real - full.re).abs() < 1e-14);
+        assert!((full.im - exact.im).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_interval_overflow() {
+        let f = |x: f64| x.sin();
+
+        // b - a = f64::MAX exactly, the width is still finite
+        let (a, b) = (-f64::MAX / 2.0, f64::MAX / 2.0);
+        assert!((b - a).is_finite());
+        assert!(try_simpson_rule(f, a, b, 10_usize).is_ok());
+
+        // a little more and b - a overflows instead of giving an infinite h
+        let (a, b) = (-f64::MAX / 2.0, f64::MAX);
+        assert_eq!(
+            try_simpson_rule(f, a, b, 10_usize),
+            Err(QuadError::IntervalOverflow)
+        );
+        assert_eq!(
+            simpson_rule_sanity(f, a, b, 10_usize),
+            Err(QuadError::IntervalOverflow)
+        );
+        assert_eq!(
+            simpson_rule_from_points(f, a, b, 21),
+            Err(QuadError::IntervalOverflow)
+        );
+    }
+
+    #[test]
+    fn test_step_underflow() {
+        let f = |x: f64| x.sin();
+        let n = 1_000_000_000_000_000_usize;
+
+        assert_eq!(
+            try_simpson_rule(f, 0.0, 1e-310, n),
+            Err(QuadError::StepUnderflow)
+        );
+        assert_eq!(
+            simpson_rule_sanity(f, 0.0, 1e-310, n),
+            Err(QuadError::StepUnderflow)
+        );
+
+        let integral = try_simpson_rule(f, 0.0, PI, 100_usize).unwrap();
+        assert!((integral - 2.0).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_error_bound() {
+        // |sin⁽⁴⁾| = |sin| ≤ 1, reached in all the intervals below
+        for (b, n) in [(PI, 4_usize), (PI, 10), (2.0, 7), (5.0, 100)] {
+            let exact = 1.0 - b.cos();
+            let integral = simpson_rule(|x: f64| x.sin(), 0.0, b, n);
+            let bound = simpson_rule_rigorous_error(1.0, 0.0, b, n);
+
+            let error = (integral - exact).abs();
+            assert!(error <= bound, "error {} above bound {}", error, bound);
+            // and the bound is tight
+            assert!(error > bound / 10.0);
+        }
+    }
+
+    #[test]
+    fn test_error_bound_between_nodes() {
+        // f⁽⁴⁾ = (1 - cos 4πx) / 2 vanishes at the 3 nodes of n = 1 but reaches 1 in between
+        let k = 4.0 * PI;
+        let f = |x: f64| x.powi(4) / 48.0 - (k * x).cos() / (2.0 * k.powi(4));
+        let exact = 1.0 / 240.0;
+
+        let error = (simpson_rule(f, 0.0, 1.0, 1_usize) - exact).abs();
+        let bound = simpson_rule_rigorous_error(1.0, 0.0, 1.0, 1_usize);
+
+        assert!(error > 1e-4);
+        assert!(error <= bound, "error {} above bound {}", error, bound);
+    }
+
+    #[test]
+    fn test_mesh_study() {
+        let exact = 1f64.exp() - 1.0;
+
+        let ns = (1..=8).map(|k| 1 << k);
+        let study = simpson_mesh_study(|x: f64| x.exp(), 0.0, 1.0, ns);
+
+        assert_eq!(study.len(), 8);
+        assert!(study.windows(2).all(|w| w[0].0 < w[1].0));
+
+        let errors: Vec<f64> = study.iter().map(|(_, i)| (i - exact).abs()).collect();
+        assert!(errors.windows(2).all(|e| e[1] < e[0]));
+        assert!(*errors.last().unwrap() < 1e-10);
+    }
+
+    #[test]
+    fn test_curvature_adaptive() {
+        let runge = |x: f64| 1.0 / (1.0 + 25.0 * x * x);
+        let exact = 0.4 * 5.0_f64.atan();
+
+        for n in [8_usize, 16] {
+            let graded = simpson_rule_curvature_adaptive(runge, -1.0, 1.0, n);
+
+            // same number of subintervals, each with its midpoint as the middle node
+            let uniform = simpson_rule(runge, -1.0, 1.0, n);
+
+            assert!((graded - exact).abs() < (uniform - exact).abs());
+        }
+
+        // a cubic is integrated exactly, whatever the mesh
+        let integral = simpson_rule_curvature_adaptive(|x: f64| x.powi(3), 0.0, 2.0, 10_usize);
+        assert!((integral - 4.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_simpson_rule_2d_cached() {
+        let f = |x: f64, y: f64| (x * y).sin() + x;
+        let (nx, ny) = (20_usize, 30_usize);
+
+        // iterated 1D rule, recomputing the nodes in y for each node in x
+        let naive = simpson_rule(
+            |x: f64| simpson_rule(|y: f64| f(x, y), -1.0, 2.0, ny),
+            0.0,
+            1.0,
+            nx,
+        );
+        let cached = simpson_rule_2d_cached(f, (0.0, 1.0), (-1.0, 2.0), nx, ny);
+        assert!((cached - naive).abs() < 1e-13);
+
+        // separable integrand, the 2D rule is the product of the 1D rules
+        let g = |x: f64| x.exp();
+        let h = |y: f64| y.cos();
+        let separable = simpson_rule_2d_cached(|x, y| g(x) * h(y), (0.0, 1.0), (0.0, 3.0), nx, ny);
+        let product = simpson_rule(g, 0.0, 1.0, nx) * simpson_rule(h, 0.0, 3.0, ny);
+        assert!((separable - product).abs() < 1e-13);
+    }
+
+    #[test]
+    fn test_resumable_simpson() {
+        let f = |x: f64| x.sin() + x * x;
+        let n = 1_000;
+
+        let mut integration = ResumableSimpson::new(0.0, 2.0, n);
+        assert_eq!(integration.accumulated(), 0.0);
+
+        integration.advance(f, 400);
+        assert_eq!(integration.covered(), 400);
+
+        // integral over [0, 0.8], the subintervals covered so far
+        let partial = simpson_rule(f, 0.0, 0.8, 400_usize);
+        assert!((integration.accumulated() - partial).abs() < 1e-13);
+
+        integration.advance(f, 400);
+        integration.advance(f, 400);
+        assert!(integration.is_complete());
+
+        let full = simpson_rule(f, 0.0, 2.0, n);
+        assert!((integration.accumulated() - full).abs() < 1e-13);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_resumable_simpson_serde() {
+        let f = |x: f64| x.exp();
+
+        let mut integration = ResumableSimpson::new(0.0, 1.0, 300);
+        integration.advance(f, 100);
+
+        // checkpoint, then resume from the restored state
+        let json = serde_json::to_string(&integration).unwrap();
+        let mut restored: ResumableSimpson = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, integration);
+
+        restored.advance(f, 200);
+        assert!((restored.accumulated() - simpson_rule(f, 0.0, 1.0, 300_usize)).abs() < 1e-13);
+    }
+
+    #[test]
+    fn test_simpson_rule_from_points() {
+        let cube = |x: f64| x.powi(3);
+
+        let integral = simpson_rule_from_points(cube, 0.0, 2.0, 3).unwrap();
+        assert!((integral - 4.0).abs() < 1e-14);
+
+        let f = |x: f64| x.exp();
+        let integral = simpson_rule_from_points(f, 0.0, 1.0, 201).unwrap();
+        assert_eq!(integral, simpson_rule(f, 0.0, 1.0, 100_usize));
+
+        for n_points in [0, 1, 2, 4, 100] {
+            assert_eq!(
+                simpson_rule_from_points(cube, 0.0, 2.0, n_points),
+                Err(QuadError::InvalidPointCount)
+            );
+        }
+
+        // only finite limits too far apart overflow
+        assert_eq!(
+            simpson_rule_from_points(cube, -f64::MAX, f64::MAX, 3),
+            Err(QuadError::IntervalOverflow)
+        );
+        assert_eq!(
+            simpson_rule_from_points(cube, 0.0, f64::INFINITY, 3),
+            Err(QuadError::InfiniteLimit)
+        );
+        assert_eq!(
+            simpson_rule_from_points(cube, f64::NAN, 2.0, 3),
+            Err(QuadError::NanLimit)
+        );
+    }
+
+    #[test]
+    fn test_thread_count_independence() {
+        use rayon::ThreadPoolBuilder;
+
+        let f = |x: f64| (50.0 * x).sin() + x.exp();
+        let n = 2_000_000_usize;
+
+        let (_, abs_integral) = simpson_rule_with_abs(f, 0.0, 3.0, n);
+
+        let integrals: Vec<f64> = [1, 2, 7, 64]
+            .iter()
+            .map(|&num_threads| {
+                let pool = ThreadPoolBuilder::new()
+                    .num_threads(num_threads)
+                    .build()
+                    .unwrap();
+
+                pool.install(|| simpson_rule(f, 0.0, 3.0, n))
+            })
+            .collect();
+
+        // documented bound on the difference between thread counts
+        for integral in &integrals {
+            assert!((integral - integrals[0]).abs() <= 1e-12 * abs_integral);
+        }
+    }
+
+    #[test]
+    fn test_open_endpoints() {
+        let f = |x: f64| 1.0 / x.sqrt();
+        assert!(simpson_rule(f, 0.0, 1.0, 100_usize).is_infinite());
+
+        let integral = simpson_rule_open_endpoints(f, 0.0, 1.0, 10_000_usize);
+        assert!((integral - 2.0).abs() < 1e-2);
+
+        // singular at the upper limit
+        let integral = simpson_rule_open_endpoints(|x: f64| f(1.0 - x), 0.0, 1.0, 10_000_usize);
+        assert!((integral - 2.0).abs() < 1e-2);
+
+        // regular integrands still use Simpson's rule
+        let integral = simpson_rule_open_endpoints(|x: f64| x.exp(), 0.0, 1.0, 100_usize);
+        assert_eq!(
+            integral,
+            simpson_rule(|x: f64| x.exp(), 0.0, 1.0, 100_usize)
+        );
+    }
+
+    #[test]
+    fn test_mixed_precision_limits() {
+        // f32 limits, f64 nodes, f32 values
+        let f = |x: f64| x.exp() as f32;
+        let integral = simpson_rule_mixed(f, 0.0_f32, 1.0_f32, 100_usize);
+        assert!((integral - (1f64.exp() - 1.0)).abs() < 1e-6);
+
+        // f64 limits rounded to f32, nodes computed in f32
+        let g = |x: f32| x as f64;
+        let integral = simpson_rule_mixed(g, 0.0_f64, 0.1_f64, 10_usize);
+        assert!((integral - 0.005).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_signed_parts() {
+        // 25 full periods, the integral vanishes
+        let f = |x: f64| (50.0 * PI * x).cos();
+
+        let (positive, negative) = simpson_rule_signed_parts(f, 0.0, 1.0, 10_000_usize);
+
+        assert!((positive - negative).abs() < 1e-12);
+
+        // the positive and negative lobes each weigh 1/π
+        assert!((positive - 1.0 / PI).abs() < 1e-6);
+        assert!((negative - 1.0 / PI).abs() < 1e-6);
+
+        // so the integral is ill-conditioned
+        assert!(positive + negative > 1e10 * (positive - negative).abs());
+    }
+
+    #[test]
+    fn test_refine() {
+        let f = |x: f64| x.sin() * x.exp();
+
+        for n in [1_usize, 7, 100, 20_000] {
+            let coarse = simpson_rule(f, -1.0, 2.0, n);
+            let (refined, error) = simpson_refine(f, -1.0, 2.0, n, coarse);
+            let fine = simpson_rule(f, -1.0, 2.0, 2 * n);
+
+            assert!((refined - fine).abs() < 1e-13 * fine.abs());
+            assert_eq!(error, (refined - coarse) / 15.0);
+        }
+    }
+
+    #[test]
+    fn test_simpson_rule_option_problem25() {
+        // ln|x - 0.7|, undefined in a neighbourhood of 0.7
+        let f = |x: f64| {
+            if (x - 0.7).abs() <= 1e-3 {
+                None
+            } else {
+                Some((x - 0.7).abs().ln())
+            }
+        };
+        let guarded = |x: f64| f(x).unwrap_or(0.0);
+
+        // ∫₀¹ ln|x - 0.7| dx, minus ∫ ln|u| du = 2 (ε ln ε - ε) over |u| ≤ ε
+        let antiderivative = |u: f64| u * u.ln() - u;
+        let epsilon: f64 = 1e-3;
+        let exact = antiderivative(0.3) + antiderivative(0.7) - 2.0 * antiderivative(epsilon);
+
+        let n = 10_000_usize;
+        let (integral, skipped) = simpson_rule_option(f, 0.0, 1.0, n);
+
+        let guarded = simpson_rule(guarded, 0.0, 1.0, n);
+        assert!((integral - guarded).abs() < 1e-12);
+        assert!((integral - exact).abs() < 1e-3);
+
+        // nodes every 5e-5 within 1e-3 of 0.7
+        assert!((39..=41).contains(&skipped), "{} nodes skipped", skipped);
+    }
+
+    #[test]
+    fn test_single_panel() {
+        // with n = 1 the interior sum is empty and only the endpoints and midpoint remain
+        let f = |x: f64| x.exp() + x.sin();
+        let expected = (f(0.0) + 4.0 * f(0.5) + f(1.0)) / 6.0;
+        assert_eq!(simpson_rule(f, 0.0, 1.0, 1_usize), expected);
+
+        let f = |x: f32| x.exp() + x.sin();
+        let expected = (f(0.0) as f64 + 4.0 * f(0.5) as f64 + f(1.0) as f64) / 6.0;
+        assert_eq!(simpson_rule(f, 0.0_f32, 1.0, 1_usize), expected);
+    }
+
+    #[test]
+    fn test_try_simpson_rule_errors() {
+        let f = |x: f64| x;
+
+        assert_eq!(
+            try_simpson_rule(f, 0.0, 1.0, 0_usize),
+            Err(QuadError::ZeroSteps)
+        );
+        assert_eq!(
+            try_simpson_rule(f, 0.0, f64::INFINITY, 10_usize),
+            Err(QuadError::InfiniteLimit)
+        );
+        assert_eq!(
+            try_simpson_rule(f, f64::NAN, 1.0, 10_usize),
+            Err(QuadError::NanLimit)
+        );
+        assert_eq!(
+            try_simpson_rule(f, 0.0, f64::NAN, 10_usize),
+            Err(QuadError::NanLimit)
+        );
+        assert_eq!(
+            try_simpson_rule(f, 1.0, 0.0, 10_usize),
+            try_simpson_rule(f, 0.0, 1.0, 10_usize).map(|integral| -integral)
+        );
+    }
+
+    #[test]
+    fn test_try_simpson_rule_zero_width() {
+        // a zero-width interval is valid, its integral is zero
+        assert_eq!(try_simpson_rule(|x: f64| x, 1.0, 1.0, 10_usize), Ok(0.0));
+    }
 }
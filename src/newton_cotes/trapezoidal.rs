@@ -84,7 +84,11 @@
 use num::{Float, ToPrimitive, Unsigned};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
-use super::utils::check_newton_method_args;
+use super::utils::{
+    check_newton_method_args, check_step, oriented, richardson_error, sum_terms,
+    validate_newton_method_args,
+};
+use crate::error::QuadError;
 
 /// This function integrates $f(x)$ from $a$ to $a+nh$ using the Simpson's
 /// rule by summing from the left end of the interval to the right end.
@@ -111,6 +115,7 @@ use super::utils::check_newton_method_args;
 ///
 /// # Resources
 /// [Methods of numerical Integration (2nd edition), by Philip J. Davis and Philip Rabinowitz.](https://www.cambridge.org/core/journals/mathematical-gazette/article/abs/methods-of-numerical-integration-2nd-edition-by-philip-j-davis-and-philip-rabinowitz-pp-612-3650-1984-isbn-0122063600-academic-press/C331158D0392E1D5CD9B0C6ED4EE5F43)
+#[must_use]
 pub fn trapezoidal_rule<
     Func,
     F1: Float + Sync,
@@ -125,6 +130,8 @@ pub fn trapezoidal_rule<
 where
     Func: Fn(F1) -> F2 + Sync,
 {
+    let (lower_limit, upper_limit, sign) = oriented(lower_limit, upper_limit);
+
     // checking arguments
     check_newton_method_args(lower_limit, upper_limit, n_intervals);
 
@@ -135,20 +142,248 @@ where
     // first term of the sum
     let i_0 = func(lower_limit).to_f64().unwrap();
 
-    let integral: f64 = (1..(n_intervals.to_usize().unwrap()))
-        .into_par_iter()
-        .map(|i| {
-            // subinterval index (as real)
-            let i = F1::from(i).expect("failed to convert subinterval index i");
-            func(lower_limit + i * h).to_f64().unwrap()
-        })
-        .sum();
+    let integral: f64 = sum_terms(1..(n_intervals.to_usize().unwrap()), 1, |i| {
+        // subinterval index (as real)
+        let i = F1::from(i).expect("failed to convert subinterval index i");
+        func(lower_limit + i * h).to_f64().unwrap()
+    });
 
     let n: F1 = F1::from(n_intervals).expect("failed to convert number of steps n");
     // last term of the sum
     let i_n = func(lower_limit + h * n).to_f64().unwrap();
 
-    (0.5 * i_0 + integral + 0.5 * i_n) * h.to_f64().expect("failed to convert subintervql length")
+    sign * (0.5 * i_0 + integral + 0.5 * i_n)
+        * h.to_f64().expect("failed to convert subintervql length")
+}
+
+/// Same as [`trapezoidal_rule`], but returns an error instead of panicking on invalid arguments, see
+/// [`try_rectangle_rule`](super::rectangle::try_rectangle_rule).
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_intervals` - number of subintervals.
+///
+/// # Examples
+/// ```
+/// use integrate::error::QuadError;
+/// use integrate::newton_cotes::trapezoidal::try_trapezoidal_rule;
+///
+///
+/// let square = |x: f64| x * x;
+///
+/// assert!(try_trapezoidal_rule(square, 0.0, 1.0, 12_usize).is_ok());
+/// assert_eq!(try_trapezoidal_rule(square, 0.0, 1.0, 0_usize), Err(QuadError::ZeroSteps));
+/// ```
+pub fn try_trapezoidal_rule<
+    Func,
+    F1: Float + Sync,
+    F2: Float + Send,
+    U: Unsigned + ToPrimitive + Copy,
+>(
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    n_intervals: U,
+) -> Result<f64, QuadError>
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    // checking arguments
+    validate_newton_method_args(lower_limit, upper_limit, n_intervals)?;
+    check_step(lower_limit, upper_limit, n_intervals)?;
+
+    Ok(trapezoidal_rule(
+        func,
+        lower_limit,
+        upper_limit,
+        n_intervals,
+    ))
+}
+
+/// This function integrates $f(x)$ from $a$ to $b$ using the trapezoidal rule with $n$ and $2n$
+/// subintervals, and returns the estimate with $2n$ subintervals along with the Richardson
+/// estimate of its error.
+///
+/// The error of the trapezoidal rule behaves like $K h^2$, so that halving $h$ divides it by
+/// $2^2$ and the error of $I_{2n}$ is about
+///
+/// ```math
+/// \frac{\left| I_{2n} - I_n \right|}{2^2 - 1}
+/// ```
+///
+/// as long as $f$ is smooth enough for the leading error term to dominate.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_intervals` - number of subintervals $n$ of the coarser estimate.
+///
+/// # Examples
+/// ```
+/// use integrate::newton_cotes::trapezoidal::trapezoidal_rule_with_error;
+///
+///
+/// let f = |x: f64| x.exp();
+///
+/// let (integral, error) = trapezoidal_rule_with_error(f, 0.0, 1.0, 100_usize);
+///
+/// assert!((integral - (1f64.exp() - 1.0)).abs() < 2.0 * error);
+/// ```
+#[must_use]
+pub fn trapezoidal_rule_with_error<
+    Func,
+    F1: Float + Sync,
+    F2: Float + Send,
+    U: Unsigned + ToPrimitive + Copy,
+>(
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    n_intervals: U,
+) -> (f64, f64)
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    let coarse = trapezoidal_rule(&func, lower_limit, upper_limit, n_intervals);
+    let fine = trapezoidal_rule(&func, lower_limit, upper_limit, n_intervals + n_intervals);
+
+    (fine, richardson_error(coarse, fine, 2))
+}
+
+/// This function estimates the error of [`trapezoidal_rule`] with `n_intervals`
+/// subintervals from the leading term of the Euler-Maclaurin summation formula
+/// ```math
+/// T_h(f) - \int_{a}^{b} f(x)dx \approx \frac{h^2}{12} \left[ f^{\prime}(b) - f^{\prime}(a) \right]
+/// ```
+/// where the derivatives at the end points are computed by central differences.
+///
+/// Subtracting the estimate from the result of [`trapezoidal_rule`] gives a more accurate
+/// approximation of the integral, as long as $f$ is smooth enough.
+///
+/// * `func` - Integrand function of a single variable, evaluated slightly outside of
+///   $\[a, b\]$ by the central differences.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_intervals` - number of subintervals.
+///
+/// # Examples
+/// ```
+/// use integrate::newton_cotes::trapezoidal::{trapezoidal_error_estimate, trapezoidal_rule};
+///
+///
+/// let f = |x: f64| x.exp();
+/// let exact = 1f64.exp() - 1.0;
+///
+/// let integral = trapezoidal_rule(f, 0.0, 1.0, 100_usize);
+/// let error = trapezoidal_error_estimate(f, 0.0, 1.0, 100_usize);
+///
+/// assert!((integral - error - exact).abs() < (integral - exact).abs());
+/// ```
+#[must_use]
+pub fn trapezoidal_error_estimate<
+    Func,
+    F1: Float + Sync,
+    F2: Float + Send,
+    U: Unsigned + ToPrimitive + Copy,
+>(
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    n_intervals: U,
+) -> f64
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    // checking arguments
+    check_newton_method_args(lower_limit, upper_limit, n_intervals);
+
+    // length of each subinterval
+    let h: F1 = (upper_limit - lower_limit)
+        / F1::from(n_intervals).expect("failed to convert length of subinterval h");
+
+    // step balancing the truncation and rounding errors of the central differences
+    let derivative = |x: F1| {
+        let delta = F1::epsilon().cbrt() * x.abs().max(F1::one());
+        let two_delta = (delta + delta).to_f64().unwrap();
+
+        (func(x + delta).to_f64().unwrap() - func(x - delta).to_f64().unwrap()) / two_delta
+    };
+
+    let h = h.to_f64().unwrap();
+
+    h * h / 12.0 * (derivative(upper_limit) - derivative(lower_limit))
+}
+
+/// This function computes the composite trapezoidal estimates $T_h(f), T_{\frac{h}{2}}(f), ..., T_{\frac{h}{2^{l-1}}}(f)$
+/// of the integral of $f(x)$ from $a$ to $b$, with $h = b - a$, i.e. using $1, 2, 4, ..., 2^{l-1}$ subintervals.
+///
+/// Each estimate reuses the evaluations of the previous one, only evaluating $f$ at the midpoints
+/// of the previous subintervals
+/// ```math
+/// T_{\frac{h}{2}}(f) = \frac{1}{2} T_h(f) + \frac{h}{2} \sum_{i=1}^{n} f\left(a + (2i - 1) \frac{h}{2}\right)
+/// ```
+/// so that the whole sequence costs $2^{l-1} + 1$ evaluations. This is the first column of the
+/// Romberg table (see [`crate::romberg`]), and can be fed to any other acceleration scheme.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `levels` - number $l$ of estimates.
+///
+/// # Examples
+/// ```
+/// use integrate::newton_cotes::trapezoidal::trapezoidal_sequence;
+///
+///
+/// let square = |x: f64| x * x;
+///
+/// let estimates = trapezoidal_sequence(square, 0.0, 1.0, 10);
+///
+/// assert_eq!(estimates.len(), 10);
+/// assert!((estimates[9] - 1.0 / 3.0).abs() < 1e-6);
+/// ```
+#[must_use]
+pub fn trapezoidal_sequence<Func, F1: Float + Sync, F2: Float + Send>(
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    levels: usize,
+) -> Vec<f64>
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    // checking arguments
+    check_newton_method_args(lower_limit, upper_limit, levels);
+
+    let mut h: F1 = upper_limit - lower_limit;
+    let two = F1::one() + F1::one();
+
+    let mut estimates: Vec<f64> = Vec::with_capacity(levels);
+
+    let mut estimate = 0.5
+        * (func(lower_limit).to_f64().unwrap() + func(upper_limit).to_f64().unwrap())
+        * h.to_f64().unwrap();
+    estimates.push(estimate);
+
+    for level in 1..levels {
+        // number of subintervals of the previous estimate
+        let n: usize = 1 << (level - 1);
+        h = h / two;
+
+        let midpoints: f64 = (1..=n)
+            .into_par_iter()
+            .map(|i| {
+                let i = F1::from(2 * i - 1).expect("failed to convert midpoint index");
+                func(lower_limit + i * h).to_f64().unwrap()
+            })
+            .sum();
+
+        estimate = 0.5 * estimate + midpoints * h.to_f64().unwrap();
+        estimates.push(estimate);
+    }
+
+    estimates
 }
 
 #[cfg(test)]
@@ -241,4 +476,62 @@ mod tests {
     //         trapezoidal_rule(f1, a, b, NUM_STEPS);
     //     })
     // }
+
+    #[test]
+    fn test_trapezoidal_error_estimate() {
+        let f = |x: f64| x.exp();
+        let exact = 1f64.exp() - 1.0;
+
+        for n in [10_usize, 100, 1_000] {
+            let integral = trapezoidal_rule(f, 0.0, 1.0, n);
+            let error = trapezoidal_error_estimate(f, 0.0, 1.0, n);
+
+            // the next Euler-Maclaurin term is O(h^4)
+            let corrected_error = (integral - error - exact).abs();
+            assert!(corrected_error < 1e-3 * (integral - exact).abs());
+        }
+    }
+
+    #[test]
+    fn test_trapezoidal_sequence() {
+        let f = |x: f64| x.sin() + x.exp();
+        let (a, b) = (0.0, 2.0);
+        let levels = 12;
+
+        let estimates = trapezoidal_sequence(f, a, b, levels);
+        assert_eq!(estimates.len(), levels);
+
+        for (level, estimate) in estimates.iter().enumerate() {
+            let expected = trapezoidal_rule(f, a, b, 1_usize << level);
+            assert!((estimate - expected).abs() < 1e-12 * expected.abs());
+        }
+    }
+
+    #[test]
+    fn test_try_trapezoidal_rule_errors() {
+        let f = |x: f64| x;
+
+        assert_eq!(
+            try_trapezoidal_rule(f, 0.0, 1.0, 0_usize),
+            Err(QuadError::ZeroSteps)
+        );
+        assert_eq!(
+            try_trapezoidal_rule(f, 0.0, f64::INFINITY, 10_usize),
+            Err(QuadError::InfiniteLimit)
+        );
+        assert_eq!(
+            try_trapezoidal_rule(f, f64::NEG_INFINITY, 0.0, 10_usize),
+            Err(QuadError::InfiniteLimit)
+        );
+        assert_eq!(
+            try_trapezoidal_rule(f, 1.0, 0.0, 12_usize),
+            try_trapezoidal_rule(f, 0.0, 1.0, 12_usize).map(|integral| -integral)
+        );
+
+        let integral = try_trapezoidal_rule(f, 0.0, 1.0, 12_usize).unwrap();
+        assert!((integral - 0.5).abs() < 1e-12);
+
+        // a zero-width interval is valid, its integral is zero
+        assert_eq!(try_trapezoidal_rule(f, 1.0, 1.0, 10_usize), Ok(0.0));
+    }
 }
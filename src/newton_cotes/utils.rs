@@ -1,17 +1,119 @@
-use num::{Float, Unsigned};
+use std::ops::Range;
+
+use num::{Float, ToPrimitive, Unsigned};
+use rayon::prelude::*;
+
+use crate::error::QuadError;
+
+/// Number of terms below which the composite Newton-Cotes rules sum serially on the
+/// calling thread: for cheap integrands and small numbers of subintervals, the cost of
+/// distributing the work across threads exceeds the cost of the evaluations.
+pub const PARALLEL_THRESHOLD: usize = 10_000;
+
+/// Sums `term(i)` for `i` in `range` stepping by `step`, in parallel only if there are
+/// at least [`PARALLEL_THRESHOLD`] terms.
+///
+/// An empty `range`, as the interior of a rule with a single subinterval, sums to `0.0`
+/// without going through either iterator.
+pub(crate) fn sum_terms<T>(range: Range<usize>, step: usize, term: T) -> f64
+where
+    T: Fn(usize) -> f64 + Sync + Send,
+{
+    if range.is_empty() {
+        return 0.0;
+    }
+
+    let nb_terms = (range.len() + step - 1) / step;
+
+    if nb_terms < PARALLEL_THRESHOLD {
+        range.step_by(step).map(term).sum()
+    } else {
+        range.into_par_iter().step_by(step).map(term).sum()
+    }
+}
+
+/// Richardson estimate of the error of `fine`, computed with half the step of `coarse` by a
+/// rule whose error behaves like $K h^{order}$.
+pub(crate) fn richardson_error(coarse: f64, fine: f64, order: i32) -> f64 {
+    (fine - coarse).abs() / (2f64.powi(order) - 1.0)
+}
+
+/// Limits `a` and `b` in increasing order, along with the sign of the integral from `a` to `b`
+/// relative to the one over the ordered limits: $-1$ if `a > b`, $1$ otherwise.
+pub(crate) fn oriented<F: Float>(a: F, b: F) -> (F, F, f64) {
+    if a > b {
+        (b, a, -1.0)
+    } else {
+        (a, b, 1.0)
+    }
+}
+
+/// Checks that the limits are finite numbers and that the length $b - a$ of the integration
+/// interval does not overflow to infinity, in which case $h$ and all the nodes would be
+/// meaningless.
+pub(crate) fn check_width<F: Float>(a: F, b: F) -> Result<(), QuadError> {
+    if a.is_nan() || b.is_nan() {
+        return Err(QuadError::NanLimit);
+    }
+
+    if a.is_infinite() || b.is_infinite() {
+        return Err(QuadError::InfiniteLimit);
+    }
+
+    if (b - a).is_infinite() {
+        return Err(QuadError::IntervalOverflow);
+    }
+
+    Ok(())
+}
+
+/// Checks that the length of the subintervals $h = \frac{b-a}{n}$ neither overflows, see
+/// [`check_width`], nor underflows to zero, a zero-width interval $a = b$ being valid.
+pub(crate) fn check_step<F: Float, U: ToPrimitive>(a: F, b: F, n: U) -> Result<(), QuadError> {
+    check_width(a, b)?;
+
+    let n = F::from(n).expect("failed to convert n");
+
+    if b != a && (b - a) / n == F::zero() {
+        return Err(QuadError::StepUnderflow);
+    }
+
+    Ok(())
+}
+
+/// Checks integral arguments for Newton-Cotes methods, returning [`QuadError::ZeroSteps`]
+/// or [`QuadError::InfiniteLimit`] for invalid ones. Reversed limits are left to the
+/// caller, which either swaps them or rejects them.
+pub(crate) fn validate_newton_method_args<F: Float, U: Unsigned>(
+    a: F,
+    b: F,
+    n: U,
+) -> Result<(), QuadError> {
+    if n.is_zero() {
+        return Err(QuadError::ZeroSteps);
+    }
+
+    if a.is_infinite() | b.is_infinite() {
+        return Err(QuadError::InfiniteLimit);
+    }
+
+    Ok(())
+}
 
 /// Checks integral arguments for Newton-Codes methods
 ///
 /// * `a` - lower limit of the integration interval.
 /// * `b` - lower limit of the integration interval.
 /// * `n` - number of steps.
+///
+/// # Panics
+///
+/// Panics if `n` is zero or if either limit is infinite, with the message of the
+/// corresponding [`QuadError`], and if `a > b`: the rules accepting reversed limits swap
+/// them before calling it.
 pub fn check_newton_method_args<F: Float, U: Unsigned>(a: F, b: F, n: U) {
-    if n.is_zero() {
-        panic!("number of steps can't be zero");
-    }
-
-    if a.is_infinite() | b.is_infinite() {
-        panic!("Integral limits a and b can't be infinite");
+    if let Err(error) = validate_newton_method_args(a, b, n) {
+        panic!("{}", error);
     }
 
     if a > b {
@@ -0,0 +1,135 @@
+//! Convenience re-exports
+//!
+//! `use integrate::prelude::*;` brings the [`IntegrableFn`] extension trait into scope, so
+//! that the rules can be called directly on closures and functions, along with the
+//! [`Method`] enumeration and the [`quad_simple`] one-liner.
+
+pub use crate::adaptive_quadrature::simpson::quad_simple;
+pub use crate::method::Method;
+
+use crate::{
+    gauss_quadrature::legendre::legendre_rule,
+    newton_cotes::{
+        boole::boole_rule, newton::newton_rule, rectangle::rectangle_rule, simpson::simpson_rule,
+        trapezoidal::trapezoidal_rule,
+    },
+    romberg::romberg_method,
+};
+
+/// Extension trait calling the integration rules on any `Fn(f64) -> f64 + Sync`.
+///
+/// Every method integrates `self` from `lower_limit` to `upper_limit` with the rule of the
+/// same name, and `n` has the meaning it has for that rule.
+///
+/// # Examples
+/// ```
+/// use integrate::prelude::*;
+///
+///
+/// let integral = (|x: f64| x * x).integrate_simpson(0.0, 1.0, 1_000);
+///
+/// assert!((integral - 1.0 / 3.0).abs() < 1e-12);
+/// ```
+pub trait IntegrableFn {
+    /// Integrates using the rectangle rule with `n` subintervals.
+    #[must_use]
+    fn integrate_rectangle(&self, lower_limit: f64, upper_limit: f64, n: usize) -> f64;
+
+    /// Integrates using the trapezoidal rule with `n` subintervals.
+    #[must_use]
+    fn integrate_trapezoidal(&self, lower_limit: f64, upper_limit: f64, n: usize) -> f64;
+
+    /// Integrates using Simpson's rule with `n` subintervals.
+    #[must_use]
+    fn integrate_simpson(&self, lower_limit: f64, upper_limit: f64, n: usize) -> f64;
+
+    /// Integrates using Newton's 3/8 rule with `n` subintervals.
+    #[must_use]
+    fn integrate_newton(&self, lower_limit: f64, upper_limit: f64, n: usize) -> f64;
+
+    /// Integrates using Boole's rule with `n` subintervals.
+    #[must_use]
+    fn integrate_boole(&self, lower_limit: f64, upper_limit: f64, n: usize) -> f64;
+
+    /// Integrates using the `n`-point Gauss-Legendre rule.
+    #[must_use]
+    fn integrate_gauss_legendre(&self, lower_limit: f64, upper_limit: f64, n: usize) -> f64;
+
+    /// Integrates using Romberg's method with `n_columns` columns.
+    #[must_use]
+    fn integrate_romberg(&self, lower_limit: f64, upper_limit: f64, n_columns: usize) -> f64;
+
+    /// Integrates using the given [`Method`] with `n` subintervals, or `n` points for
+    /// Gauss-Legendre.
+    #[must_use]
+    fn integrate_with(&self, method: Method, lower_limit: f64, upper_limit: f64, n: usize) -> f64;
+}
+
+impl<Func> IntegrableFn for Func
+where
+    Func: Fn(f64) -> f64 + Sync,
+{
+    fn integrate_rectangle(&self, lower_limit: f64, upper_limit: f64, n: usize) -> f64 {
+        rectangle_rule(self, lower_limit, upper_limit, n)
+    }
+
+    fn integrate_trapezoidal(&self, lower_limit: f64, upper_limit: f64, n: usize) -> f64 {
+        trapezoidal_rule(self, lower_limit, upper_limit, n)
+    }
+
+    fn integrate_simpson(&self, lower_limit: f64, upper_limit: f64, n: usize) -> f64 {
+        simpson_rule(self, lower_limit, upper_limit, n)
+    }
+
+    fn integrate_newton(&self, lower_limit: f64, upper_limit: f64, n: usize) -> f64 {
+        newton_rule(self, lower_limit, upper_limit, n)
+    }
+
+    fn integrate_boole(&self, lower_limit: f64, upper_limit: f64, n: usize) -> f64 {
+        boole_rule(self, lower_limit, upper_limit, n)
+    }
+
+    fn integrate_gauss_legendre(&self, lower_limit: f64, upper_limit: f64, n: usize) -> f64 {
+        legendre_rule(self, lower_limit, upper_limit, n)
+    }
+
+    fn integrate_romberg(&self, lower_limit: f64, upper_limit: f64, n_columns: usize) -> f64 {
+        romberg_method(|x: f64| self(x), lower_limit, upper_limit, n_columns)
+    }
+
+    fn integrate_with(&self, method: Method, lower_limit: f64, upper_limit: f64, n: usize) -> f64 {
+        method.integrate(self, lower_limit, upper_limit, n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fluent_calls_match_rules() {
+        let f = |x: f64| x.exp();
+        let exact = 1.0_f64.exp() - 1.0;
+
+        let fn_item = f64::exp;
+
+        for integral in [
+            f.integrate_rectangle(0.0, 1.0, 10_000),
+            f.integrate_trapezoidal(0.0, 1.0, 10_000),
+            f.integrate_simpson(0.0, 1.0, 1_000),
+            f.integrate_newton(0.0, 1.0, 1_000),
+            f.integrate_boole(0.0, 1.0, 100),
+            f.integrate_gauss_legendre(0.0, 1.0, 10),
+            f.integrate_romberg(0.0, 1.0, 10),
+            f.integrate_with(Method::Simpson, 0.0, 1.0, 1_000),
+            fn_item.integrate_simpson(0.0, 1.0, 1_000),
+        ] {
+            assert!((integral - exact).abs() < 1e-8);
+        }
+
+        assert_eq!(
+            f.integrate_simpson(0.0, 1.0, 100),
+            simpson_rule(f, 0.0, 1.0, 100_usize)
+        );
+    }
+}
@@ -70,19 +70,62 @@
 //! is the estimate of the integral.
 //!
 //! The numbers which are used the divide the difference of two adjacent elements in the $i^{th}$ column is $4^i - 1$.
+//!
+//! Richardson extrapolation fits a polynomial in $h^2$ through the trapezoidal estimates and
+//! evaluates it at $h = 0$. [`romberg_method_rational`] fits a rational function in $h^2$
+//! instead, as in the Bulirsch-Stoer algorithm, which may converge faster when the
+//! estimates don't behave like a polynomial in $h^2$.
 
 use num::{Float, ToPrimitive, Unsigned};
 
-use rayon::prelude::*;
-
-use crate::newton_cotes::trapezoidal::trapezoidal_rule;
+use crate::newton_cotes::trapezoidal::trapezoidal_sequence;
+use crate::newton_cotes::utils::oriented;
+use crate::tolerance::Tolerance;
 
 use std::collections::HashMap;
 
+use std::fmt;
+
 use std::hash::Hash;
 
+use std::marker::PhantomData;
+
 use std::sync::Mutex;
 
+/// Maximum number of columns of [`romberg_method_checked`]: the last trapezoidal estimate
+/// uses $2^{n-1}$ subintervals, which must be representable as a `usize`.
+pub const MAX_COLUMNS: usize = usize::BITS as usize;
+
+/// Error returned by [`romberg_method_checked`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RombergError {
+    /// The method was requested with zero columns.
+    ZeroColumns,
+    /// More than [`MAX_COLUMNS`] columns were requested.
+    TooManyColumns,
+    /// One of the limits of integration is infinite.
+    InfiniteLimit,
+    /// The tolerance was not met with the maximum number of columns.
+    ToleranceNotMet,
+}
+
+impl fmt::Display for RombergError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RombergError::ZeroColumns => write!(f, "number of columns can't be zero"),
+            RombergError::TooManyColumns => {
+                write!(f, "number of columns can't exceed {}", MAX_COLUMNS)
+            }
+            RombergError::InfiniteLimit => write!(f, "Integral limits a and b can't be infinite"),
+            RombergError::ToleranceNotMet => {
+                write!(f, "tolerance not met with the maximum number of columns")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RombergError {}
+
 /// Computes elements of Romberg's matrix recursively given
 /// row's and column's index
 ///
@@ -167,7 +210,8 @@ where
 /// # Inputs
 /// * `func` - Integrand function of a single variable.
 /// * `lower_limit` - lower limit of the integration interval.
-/// * `upper_limit` - upper limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval, the sign of the result
+///   changes if it is less than `lower_limit`.
 /// * `n_columns` - number of columns to be used in the Romberg method (columns of the Romberg Matrix).
 ///
 /// This corresponds to a minimum integration subintervals of of length $\dfrac{1}{2^n} * h$
@@ -176,6 +220,7 @@ where
 /// # Resources
 /// * [Methods of numerical Integration (2nd edition), by Philip J. Davis and Philip Rabinowitz.](https://www.cambridge.org/core/journals/mathematical-gazette/article/abs/methods-of-numerical-integration-2nd-edition-by-philip-j-davis-and-philip-rabinowitz-pp-612-3650-1984-isbn-0122063600-academic-press/C331158D0392E1D5CD9B0C6ED4EE5F43)
 /// * [Romberg's method](https://en.wikipedia.org/wiki/Romberg%27s_method)
+#[must_use]
 pub fn romberg_method<
     Func,
     F1: Float + Sync,
@@ -190,19 +235,19 @@ pub fn romberg_method<
 where
     Func: Fn(F1) -> F2 + Sync + Send + Copy,
 {
+    let (lower_limit, upper_limit, sign) = oriented(lower_limit, upper_limit);
+
     // first columm of romberg table
-    // calculated using trapezoid rule
-    let mut trapezoidals: Vec<F2> = Vec::with_capacity(n_columns.to_usize().unwrap());
-
-    // initializing first column of the romberg's matrix using trapezoid rule
-    (0..n_columns.to_usize().unwrap())
-        .into_par_iter()
-        .map(|i| {
-            let pow_2 = 2_usize.pow(i.try_into().unwrap()); // 2 ** i
-            let trapezoidal = trapezoidal_rule(func, lower_limit, upper_limit, pow_2);
-            F2::from(trapezoidal).unwrap()
-        })
-        .collect_into_vec(&mut trapezoidals);
+    // calculated using trapezoid rule, each halving reusing previous evaluations
+    let trapezoidals: Vec<F2> = trapezoidal_sequence(
+        func,
+        lower_limit,
+        upper_limit,
+        n_columns.to_usize().unwrap(),
+    )
+    .into_iter()
+    .map(|trapezoidal| F2::from(trapezoidal).unwrap())
+    .collect();
 
     // Storing computed values (shared between threads)
     let cache: Mutex<HashMap<(U, U), F2>> = Mutex::new(HashMap::new());
@@ -214,7 +259,470 @@ where
         &cache,
     );
 
-    integral.to_f64().unwrap()
+    sign * integral.to_f64().unwrap()
+}
+
+/// Same as [`romberg_method`], but returns an error instead of panicking on invalid
+/// arguments.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_columns` - number of columns to be used in the Romberg method, at most [`MAX_COLUMNS`].
+///
+/// # Examples
+/// ```
+/// use integrate::romberg::{romberg_method_checked, RombergError};
+///
+///
+/// let square = |x: f64| x * x;
+///
+/// let integral = romberg_method_checked(square, 0.0, 1.0, 10_usize).unwrap();
+/// assert!((integral - 1.0 / 3.0).abs() < 1e-12);
+///
+/// let reversed = romberg_method_checked(square, 1.0, 0.0, 10_usize).unwrap();
+/// assert_eq!(reversed, -integral);
+///
+/// let too_many = romberg_method_checked(square, 0.0, 1.0, 1_000_usize);
+/// assert_eq!(too_many, Err(RombergError::TooManyColumns));
+/// ```
+pub fn romberg_method_checked<
+    Func,
+    F1: Float + Sync,
+    F2: Float + Sync + Send,
+    U: Unsigned + ToPrimitive + Copy + Send + Sync + Hash + Eq,
+>(
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    n_columns: U,
+) -> Result<f64, RombergError>
+where
+    Func: Fn(F1) -> F2 + Sync + Send + Copy,
+{
+    if n_columns.is_zero() {
+        return Err(RombergError::ZeroColumns);
+    }
+
+    if n_columns.to_usize().map_or(true, |n| n > MAX_COLUMNS) {
+        return Err(RombergError::TooManyColumns);
+    }
+
+    if lower_limit.is_infinite() || upper_limit.is_infinite() {
+        return Err(RombergError::InfiniteLimit);
+    }
+
+    Ok(romberg_method(func, lower_limit, upper_limit, n_columns))
+}
+
+/// Romberg's method adding rows to Romberg's matrix until the tolerance is met.
+///
+/// Each row halves the subintervals of the trapezoidal rule of the previous row, reusing
+/// its evaluations, and extrapolates one more column. The method stops as soon as the
+/// difference between the last extrapolations of two consecutive rows satisfies
+/// [`Tolerance::satisfied`], and returns the last one.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `tolerance` - combined absolute and relative tolerance.
+/// * `max_columns` - maximum number of columns of Romberg's matrix, at most [`MAX_COLUMNS`].
+///
+/// # Examples
+/// ```
+/// use integrate::romberg::romberg_method_tolerance;
+/// use integrate::tolerance::Tolerance;
+///
+///
+/// let f = |x: f64| x.exp();
+///
+/// let integral = romberg_method_tolerance(f, 0.0, 1.0, &Tolerance::absolute(1e-12), 20).unwrap();
+/// assert!((integral - (1f64.exp() - 1.0)).abs() < 1e-12);
+/// ```
+pub fn romberg_method_tolerance<Func, F1: Float, F2: Float>(
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    tolerance: &Tolerance,
+    max_columns: usize,
+) -> Result<f64, RombergError>
+where
+    Func: Fn(F1) -> F2,
+{
+    if max_columns == 0 {
+        return Err(RombergError::ZeroColumns);
+    }
+
+    if max_columns > MAX_COLUMNS {
+        return Err(RombergError::TooManyColumns);
+    }
+
+    if lower_limit.is_infinite() || upper_limit.is_infinite() {
+        return Err(RombergError::InfiniteLimit);
+    }
+
+    let (lower_limit, upper_limit, sign) = oriented(lower_limit, upper_limit);
+
+    let f = |x: F1| func(x).to_f64().unwrap();
+
+    let mut previous_row = first_romberg_row(&f, lower_limit, upper_limit);
+
+    for k in 1..max_columns {
+        let row = next_romberg_row(&f, lower_limit, upper_limit, &previous_row);
+
+        let error = (row[k] - previous_row[k - 1]).abs();
+        if tolerance.satisfied(error, row[k]) {
+            return Ok(sign * row[k]);
+        }
+
+        previous_row = row;
+    }
+
+    Err(RombergError::ToleranceNotMet)
+}
+
+/// First row of Romberg's matrix, the trapezoidal rule with a single subinterval.
+fn first_romberg_row<Func, F1: Float>(f: &Func, lower_limit: F1, upper_limit: F1) -> Vec<f64>
+where
+    Func: Fn(F1) -> f64,
+{
+    let width = (upper_limit - lower_limit).to_f64().unwrap();
+
+    vec![width * (f(lower_limit) + f(upper_limit)) / 2.0]
+}
+
+/// Row $k$ of Romberg's matrix from row $k - 1$: the trapezoidal rule with $2^k$
+/// subintervals, evaluating $f$ at the midpoints of the previous ones only, then $k$
+/// Richardson extrapolations.
+fn next_romberg_row<Func, F1: Float>(
+    f: &Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    previous_row: &[f64],
+) -> Vec<f64>
+where
+    Func: Fn(F1) -> f64,
+{
+    let k = previous_row.len();
+
+    // midpoints of the 2^(k-1) subintervals of the previous row
+    let n_midpoints = 1_usize << (k - 1);
+    let h = (upper_limit - lower_limit) / F1::from(2 * n_midpoints).unwrap();
+
+    let midpoints: f64 = (0..n_midpoints)
+        .map(|i| f(lower_limit + F1::from(2 * i + 1).unwrap() * h))
+        .sum();
+
+    let mut row = Vec::with_capacity(k + 1);
+    row.push(previous_row[0] / 2.0 + h.to_f64().unwrap() * midpoints);
+
+    for m in 1..=k {
+        let [coef0, coef1]: [f64; 2] = romberg_coefficients(m);
+        row.push(coef1 * row[m - 1] - coef0 * previous_row[m - 1]);
+    }
+
+    row
+}
+
+/// Romberg's matrix with `n_rows` rows, row $k$ holding the trapezoidal estimate with $2^k$
+/// subintervals followed by its $k$ Richardson extrapolations, so that the last element of
+/// the last row is the estimate of [`romberg_method`] with `n_rows` columns.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `n_rows` - number of rows of the matrix, at most [`MAX_COLUMNS`].
+///
+/// # Examples
+/// ```
+/// use integrate::romberg::romberg_tableau;
+///
+///
+/// let square = |x: f64| x * x;
+///
+/// let tableau = romberg_tableau(square, 0.0, 1.0, 3);
+///
+/// assert_eq!(tableau.len(), 3);
+/// assert_eq!(tableau[2].len(), 3);
+/// assert!((tableau[2][2] - 1.0 / 3.0).abs() < 1e-15);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `n_rows` is zero or greater than [`MAX_COLUMNS`], or if the limits are infinite.
+#[must_use]
+pub fn romberg_tableau<Func, F1: Float, F2: Float>(
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    n_rows: usize,
+) -> Vec<Vec<f64>>
+where
+    Func: Fn(F1) -> F2,
+{
+    if n_rows == 0 {
+        panic!("{}", RombergError::ZeroColumns);
+    }
+
+    if n_rows > MAX_COLUMNS {
+        panic!("{}", RombergError::TooManyColumns);
+    }
+
+    if lower_limit.is_infinite() || upper_limit.is_infinite() {
+        panic!("{}", RombergError::InfiniteLimit);
+    }
+
+    let (lower_limit, upper_limit, sign) = oriented(lower_limit, upper_limit);
+
+    let f = |x: F1| func(x).to_f64().unwrap();
+    let tableau = vec![first_romberg_row(&f, lower_limit, upper_limit)];
+
+    let mut tableau = romberg_extend(tableau, func, lower_limit, upper_limit, n_rows - 1);
+    tableau
+        .iter_mut()
+        .flatten()
+        .for_each(|estimate| *estimate *= sign);
+
+    tableau
+}
+
+/// Appends `additional_columns` rows to a Romberg matrix computed by [`romberg_tableau`],
+/// each costing only the evaluations of its new trapezoidal estimate.
+///
+/// * `tableau` - Romberg's matrix of $f$ over $\[a, b\]$.
+/// * `func` - Integrand function of a single variable, the one of `tableau`.
+/// * `lower_limit` - lower limit of the integration interval, the one of `tableau`.
+/// * `upper_limit` - upper limit of the integration interval, the one of `tableau`.
+/// * `additional_columns` - number of rows, hence of columns, to append.
+///
+/// # Examples
+/// ```
+/// use integrate::romberg::{romberg_extend, romberg_tableau};
+///
+///
+/// let f = |x: f64| x.exp();
+///
+/// let tableau = romberg_tableau(f, 0.0, 1.0, 3);
+/// let tableau = romberg_extend(tableau, f, 0.0, 1.0, 4);
+///
+/// assert_eq!(tableau.len(), 7);
+/// assert!((tableau[6][6] - (1f64.exp() - 1.0)).abs() < 1e-14);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `tableau` is empty or is not a Romberg matrix, i.e. row $k$ doesn't have
+/// $k + 1$ elements, or if the extended matrix would have more than [`MAX_COLUMNS`] rows.
+#[must_use]
+pub fn romberg_extend<Func, F1: Float, F2: Float>(
+    mut tableau: Vec<Vec<f64>>,
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    additional_columns: usize,
+) -> Vec<Vec<f64>>
+where
+    Func: Fn(F1) -> F2,
+{
+    if tableau.is_empty()
+        || tableau
+            .iter()
+            .enumerate()
+            .any(|(k, row)| row.len() != k + 1)
+    {
+        panic!("tableau must be a Romberg matrix, row k having k + 1 elements.");
+    }
+
+    if tableau.len() + additional_columns > MAX_COLUMNS {
+        panic!("{}", RombergError::TooManyColumns);
+    }
+
+    let f = |x: F1| func(x).to_f64().unwrap();
+
+    for _ in 0..additional_columns {
+        let row = next_romberg_row(&f, lower_limit, upper_limit, &tableau[tableau.len() - 1]);
+        tableau.push(row);
+    }
+
+    tableau
+}
+
+/// Iterator over the diagonal of Romberg's matrix, returned by [`romberg_diagnostics_iter`].
+pub struct RombergDiagnostics<Func, F1, F2> {
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    previous_row: Vec<f64>,
+    integrand: PhantomData<fn(F1) -> F2>,
+}
+
+impl<Func, F1: Float, F2: Float> Iterator for RombergDiagnostics<Func, F1, F2>
+where
+    Func: Fn(F1) -> F2,
+{
+    type Item = (usize, f64, f64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.previous_row.len() == MAX_COLUMNS {
+            return None;
+        }
+
+        let func = &self.func;
+        let f = |x: F1| func(x).to_f64().unwrap();
+
+        let row = if self.previous_row.is_empty() {
+            first_romberg_row(&f, self.lower_limit, self.upper_limit)
+        } else {
+            next_romberg_row(&f, self.lower_limit, self.upper_limit, &self.previous_row)
+        };
+
+        let k = row.len() - 1;
+        let error = match k {
+            0 => f64::INFINITY,
+            _ => (row[k] - self.previous_row[k - 1]).abs(),
+        };
+        let estimate = row[k];
+
+        self.previous_row = row;
+
+        Some((k, estimate, error))
+    }
+}
+
+/// Streams the diagonal of Romberg's matrix: the $k$-th item is the triple
+/// `(k, estimate, error)`, where `estimate` is the estimate of [`romberg_method`] with
+/// $k + 1$ columns and `error` the difference with the previous diagonal element, the one
+/// [`romberg_method_tolerance`] compares to its tolerance. The first error is infinite.
+///
+/// Each row only evaluates $f$ at the midpoints of the subintervals of the previous row,
+/// so that stopping after $k$ items costs no more evaluations than [`romberg_tableau`]
+/// with $k$ rows. The iterator ends after [`MAX_COLUMNS`] items.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+///
+/// # Examples
+/// ```
+/// use integrate::romberg::romberg_diagnostics_iter;
+///
+///
+/// let f = |x: f64| x.exp();
+///
+/// let (k, integral, _) = romberg_diagnostics_iter(f, 0.0, 1.0)
+///     .find(|&(_, _, error)| error < 1e-12)
+///     .unwrap();
+///
+/// assert!(k < 10);
+/// assert!((integral - (1f64.exp() - 1.0)).abs() < 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics if either limit is infinite.
+pub fn romberg_diagnostics_iter<Func, F1: Float, F2: Float>(
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+) -> RombergDiagnostics<Func, F1, F2>
+where
+    Func: Fn(F1) -> F2,
+{
+    if lower_limit.is_infinite() || upper_limit.is_infinite() {
+        panic!("{}", RombergError::InfiniteLimit);
+    }
+
+    RombergDiagnostics {
+        func,
+        lower_limit,
+        upper_limit,
+        previous_row: Vec::new(),
+        integrand: PhantomData,
+    }
+}
+
+/// Offset of the initial denominators of the rational extrapolation, so that a sequence
+/// of equal estimates doesn't give $0/0$.
+const RATIONAL_TINY: f64 = 1e-25;
+
+/// Romberg's method with rational, Bulirsch-Stoer, extrapolation of the trapezoidal
+/// estimates $T_h(f), T_{\frac{h}{2}}(f), \dots, T_{\frac{h}{2^{l-1}}}(f)$ to $h = 0$.
+///
+/// The estimates are interpolated by a diagonal rational function of $h^2$, evaluated at
+/// $h = 0$ by the Stoer-Bulirsch recurrence. Returns the extrapolated value along with the
+/// last correction of the recurrence as an error estimate. If the rational function has a
+/// pole at $h = 0$ the recurrence stops early, and the error is infinite.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit of the integration interval.
+/// * `upper_limit` - upper limit of the integration interval.
+/// * `levels` - number $l$ of trapezoidal estimates, at least 2.
+///
+/// # Examples
+/// ```
+/// use integrate::romberg::romberg_method_rational;
+///
+///
+/// let f = |x: f64| 1.0 / (1.0 + x * x);
+///
+/// let (integral, error) = romberg_method_rational(f, 0.0, 1.0, 8);
+///
+/// assert!((integral - std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+/// assert!(error < 1e-10);
+/// ```
+///
+/// # Panics
+///
+/// Panics if fewer than 2 levels are requested, and in the cases where
+/// [`trapezoidal_sequence`] panics.
+#[must_use]
+pub fn romberg_method_rational<Func, F1: Float + Sync, F2: Float + Send>(
+    func: Func,
+    lower_limit: F1,
+    upper_limit: F1,
+    levels: usize,
+) -> (f64, f64)
+where
+    Func: Fn(F1) -> F2 + Sync,
+{
+    if levels < 2 {
+        panic!("at least 2 levels are required.");
+    }
+
+    let estimates = trapezoidal_sequence(func, lower_limit, upper_limit, levels);
+
+    // squared subinterval lengths, relative to the first one
+    let h2: Vec<f64> = (0..levels).map(|k| 0.25_f64.powi(k as i32)).collect();
+
+    let mut c = estimates.clone();
+    let mut d: Vec<f64> = estimates.iter().map(|&t| t + RATIONAL_TINY).collect();
+
+    // starting from the finest estimate, the one closest to h = 0
+    let mut value = estimates[levels - 1];
+    let mut error = f64::INFINITY;
+
+    for m in 1..levels {
+        for i in 0..levels - m {
+            let w = c[i + 1] - d[i];
+            let t = h2[i] * d[i] / h2[i + m];
+
+            let denominator = t - c[i + 1];
+            if denominator == 0.0 {
+                // pole of the rational function at h = 0
+                return (value, f64::INFINITY);
+            }
+
+            let ratio = w / denominator;
+            d[i] = c[i + 1] * ratio;
+            c[i] = t * ratio;
+        }
+
+        let correction = d[levels - 1 - m];
+        value += correction;
+        error = correction.abs();
+    }
+
+    (value, error)
 }
 
 /// Returns coefficients to be used in the Richardson extrapolation for computing
@@ -262,6 +770,37 @@ mod tests {
         assert!((integral - analytic_result).abs() < EPSILON);
     }
 
+    #[test]
+    fn test_romberg_method_checked() {
+        let square = |x: f64| x * x;
+
+        let integral = romberg_method_checked(square, 0.0, 1.0, NUM_STEPS).unwrap();
+        assert!((integral - 1.0 / 3.0).abs() < EPSILON);
+
+        assert_eq!(
+            romberg_method_checked(square, 0.0, 1.0, 0_usize),
+            Err(RombergError::ZeroColumns)
+        );
+        assert_eq!(
+            romberg_method_checked(square, 0.0, 1.0, MAX_COLUMNS + 1),
+            Err(RombergError::TooManyColumns)
+        );
+        assert_eq!(
+            romberg_method_checked(square, 0.0, f64::INFINITY, NUM_STEPS),
+            Err(RombergError::InfiniteLimit)
+        );
+        assert_eq!(
+            romberg_method_checked(square, f64::NEG_INFINITY, 0.0, NUM_STEPS),
+            Err(RombergError::InfiniteLimit)
+        );
+
+        // reversed limits change the sign of the result
+        assert_eq!(
+            romberg_method_checked(square, 1.0, 0.0, NUM_STEPS),
+            romberg_method_checked(square, 0.0, 1.0, NUM_STEPS).map(|integral| -integral)
+        );
+    }
+
     #[test]
     fn test_f32_to_f64() {
         // f32 to f64
@@ -312,4 +851,122 @@ mod tests {
 
         assert!((integral - analytic_result).abs() < EPSILON);
     }
+
+    #[test]
+    fn test_romberg_method_tolerance() {
+        use std::cell::Cell;
+
+        let exact = 1e8 * (1f64.exp() - 1.0);
+
+        let evaluations = Cell::new(0);
+        let f = |x: f64| {
+            evaluations.set(evaluations.get() + 1);
+            1e8 * x.exp()
+        };
+
+        let absolute = Tolerance::absolute(1e-6);
+        let integral = romberg_method_tolerance(f, 0.0, 1.0, &absolute, 20).unwrap();
+        assert!((integral - exact).abs() < 1e-6);
+        let absolute_evaluations = evaluations.replace(0);
+
+        let relative = Tolerance::relative(1e-6);
+        let integral = romberg_method_tolerance(f, 0.0, 1.0, &relative, 20).unwrap();
+        assert!((integral - exact).abs() < 1e-6 * exact);
+        let relative_evaluations = evaluations.get();
+
+        assert!(relative_evaluations < absolute_evaluations);
+
+        // 2 columns are not enough for 1e-6
+        assert_eq!(
+            romberg_method_tolerance(f, 0.0, 1.0, &relative, 2),
+            Err(RombergError::ToleranceNotMet)
+        );
+    }
+
+    #[test]
+    fn test_romberg_method_rational() {
+        let f = |x: f64| 1.0 / (1.0 + x * x);
+        let exact = std::f64::consts::FRAC_PI_4;
+
+        for levels in [4_usize, 6, 8] {
+            let (rational, error) = romberg_method_rational(f, 0.0, 1.0, levels);
+            let polynomial = romberg_method(f, 0.0, 1.0, levels);
+
+            let rational_error = (rational - exact).abs();
+            let polynomial_error = (polynomial - exact).abs();
+
+            // same trapezoidal estimates, at least comparable accuracy
+            assert!(
+                rational_error <= 10.0 * polynomial_error + 1e-15,
+                "{} levels: {} against {}",
+                levels,
+                rational_error,
+                polynomial_error
+            );
+            assert!(rational_error <= 10.0 * error + 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_romberg_method_rational_constant() {
+        let (integral, _) = romberg_method_rational(|_: f64| 2.0, 1.0, 4.0, 5);
+        assert!((integral - 6.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_romberg_extend() {
+        let f = |x: f64| 1.0 / (1.0 + x * x);
+
+        let extended = romberg_extend(romberg_tableau(f, 0.0, 1.0, 5), f, 0.0, 1.0, 3);
+        assert_eq!(extended, romberg_tableau(f, 0.0, 1.0, 8));
+
+        // the last element is the estimate of the Romberg method
+        let estimate = romberg_method(f, 0.0, 1.0, 8_usize);
+        assert!((extended[7][7] - estimate).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_reversed_limits() {
+        let f = |x: f64| x.exp();
+
+        let tableau = romberg_tableau(f, 0.0, 1.0, 5);
+        let reversed = romberg_tableau(f, 1.0, 0.0, 5);
+        for (row, reversed_row) in tableau.iter().zip(&reversed) {
+            for (estimate, reversed_estimate) in row.iter().zip(reversed_row) {
+                assert_eq!(*reversed_estimate, -estimate);
+            }
+        }
+
+        let tolerance = Tolerance::absolute(1e-12);
+        assert_eq!(
+            romberg_method_tolerance(f, 1.0, 0.0, &tolerance, 20),
+            romberg_method_tolerance(f, 0.0, 1.0, &tolerance, 20).map(|integral| -integral)
+        );
+    }
+
+    #[test]
+    fn test_romberg_diagnostics_iter() {
+        let f = |x: f64| x.exp();
+        let k = 6;
+
+        let triples: Vec<(usize, f64, f64)> =
+            romberg_diagnostics_iter(f, 0.0, 2.0).take(k).collect();
+        assert_eq!(triples.len(), k);
+
+        for window in triples.windows(2) {
+            assert!(window[1].2 < window[0].2, "{:?}", window);
+        }
+
+        for (i, &(column, estimate, _)) in triples.iter().enumerate() {
+            assert_eq!(column, i);
+
+            let integral = romberg_method(f, 0.0, 2.0, column + 1);
+            assert!(
+                (estimate - integral).abs() < 1e-13,
+                "{} != {}",
+                estimate,
+                integral
+            );
+        }
+    }
 }
@@ -0,0 +1,769 @@
+//! Tabulated Data
+//!
+//! When the integrand is only known through samples $(x_0, y_0), \dots, (x_n, y_n)$
+//! with $x_0 < x_1 < \dots < x_n$, the Newton-Cotes rules can still be applied
+//! directly to the tabulated values.
+//!
+//! The trapezoidal rule sums the areas of the trapezoids joining consecutive samples
+//! ```math
+//! \int_{x_0}^{x_n} f(x) dx \approx \sum_{i=0}^{n-1} \frac{x_{i+1} - x_i}{2} \left[ y_i + y_{i+1} \right]
+//! ```
+//!
+//! Simpson's rule groups the samples in consecutive triples $(x_{2i}, x_{2i+1}, x_{2i+2})$
+//! and integrates the parabola through each triple. With $h_0 = x_{2i+1} - x_{2i}$ and
+//! $h_1 = x_{2i+2} - x_{2i+1}$ the contribution of a triple is
+//! ```math
+//! \frac{h_0 + h_1}{6} \left[ \left(2 - \frac{h_1}{h_0}\right) y_{2i}
+//! + \frac{(h_0 + h_1)^2}{h_0 h_1} y_{2i+1} + \left(2 - \frac{h_0}{h_1}\right) y_{2i+2} \right]
+//! ```
+//! which reduces to the familiar $\frac{h}{3}\left[y_{2i} + 4y_{2i+1} + y_{2i+2}\right]$ on a
+//! uniform grid. Simpson's rule therefore requires an odd number of samples.
+//!
+//! Complex samples, such as frequency-domain data, are integrated by
+//! [`trapezoidal_from_complex_samples`], which applies the trapezoidal rule to their real and
+//! imaginary parts.
+//!
+//! [`integrate_submesh`] restricts the trapezoidal rule to a subinterval of the samples,
+//! interpolating at the cut points, so that a mesh built once for $\[x_0, x_n\]$ can be
+//! reused for any $\[c, d\]$ inside it.
+//!
+//! [`cumulative_trapezoidal`] returns the running integrals $\int_{x_0}^{x_i} f(x) dx$ at
+//! every sample.
+//!
+//! [`TabulatedIntegrand`] holds the samples, checked once when it is built, and offers all
+//! of the above as methods.
+//!
+//! [`OnlineTrapezoidal`] applies the trapezoidal rule to samples received one at a time,
+//! such as a stream of measurements, without storing them.
+//!
+//! With the `ndarray` feature enabled, [`trapezoidal_ndarray`] and [`simpson_ndarray`]
+//! accept `ndarray` arrays and views, the latter integrating values tabulated on a
+//! uniform 2D grid.
+
+use num::Complex;
+
+#[cfg(feature = "ndarray")]
+use ndarray::{ArrayBase, Axis, Data, Ix1, Ix2};
+
+fn check_samples<T>(xs: &[f64], ys: &[T], min_len: usize) {
+    if xs.len() != ys.len() {
+        panic!(
+            "xs and ys must have the same length, got {} and {}.",
+            xs.len(),
+            ys.len()
+        );
+    }
+
+    if xs.len() < min_len {
+        panic!("at least {} samples are required.", min_len);
+    }
+
+    if xs.windows(2).any(|w| w[0] >= w[1]) {
+        panic!("xs must be strictly increasing.");
+    }
+}
+
+/// This function integrates tabulated samples $(x_i, y_i)$ using the trapezoidal rule.
+///
+/// * `xs` - strictly increasing abscissas.
+/// * `ys` - values of the integrand at `xs`.
+///
+/// # Examples
+/// ```
+/// use integrate::tabulated::trapezoidal_from_samples;
+///
+/// let xs = [0.0, 0.5, 2.0];
+/// let ys = [0.0, 0.5, 2.0];
+///
+/// // the trapezoidal rule is exact for linear functions
+/// let integral = trapezoidal_from_samples(&xs, &ys);
+/// assert!((integral - 2.0).abs() < 1e-15);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `xs` and `ys` have different lengths, if fewer than two samples are given,
+/// or if `xs` is not strictly increasing.
+#[must_use]
+pub fn trapezoidal_from_samples(xs: &[f64], ys: &[f64]) -> f64 {
+    check_samples(xs, ys, 2);
+
+    trapezoidal_sum(xs, ys)
+}
+
+/// Trapezoidal rule on samples already checked.
+fn trapezoidal_sum(xs: &[f64], ys: &[f64]) -> f64 {
+    xs.windows(2)
+        .zip(ys.windows(2))
+        .map(|(x, y)| (x[1] - x[0]) * (y[0] + y[1]) / 2.0)
+        .sum()
+}
+
+/// This function computes the running integrals $\int_{x_0}^{x_i} f(x) dx$ of tabulated
+/// samples $(x_i, y_i)$ using the trapezoidal rule, as SciPy's `cumulative_trapezoid` with
+/// an initial value of zero.
+///
+/// * `xs` - strictly increasing abscissas.
+/// * `ys` - values of the integrand at `xs`.
+///
+/// # Examples
+/// ```
+/// use integrate::tabulated::cumulative_trapezoidal;
+///
+/// let xs = [0.0, 1.0, 3.0];
+/// let ys = [1.0, 1.0, 1.0];
+///
+/// assert_eq!(cumulative_trapezoidal(&xs, &ys), vec![0.0, 1.0, 3.0]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `xs` and `ys` have different lengths, if fewer than two samples are given,
+/// or if `xs` is not strictly increasing.
+#[must_use]
+pub fn cumulative_trapezoidal(xs: &[f64], ys: &[f64]) -> Vec<f64> {
+    check_samples(xs, ys, 2);
+
+    cumulative_sum(xs, ys)
+}
+
+/// Running trapezoidal integrals of samples already checked.
+fn cumulative_sum(xs: &[f64], ys: &[f64]) -> Vec<f64> {
+    let mut integral = 0.0;
+
+    std::iter::once(0.0)
+        .chain(xs.windows(2).zip(ys.windows(2)).map(|(x, y)| {
+            integral += (x[1] - x[0]) * (y[0] + y[1]) / 2.0;
+            integral
+        }))
+        .collect()
+}
+
+/// This function integrates complex tabulated samples $(x_i, y_i)$ using the trapezoidal
+/// rule, applied to the real and imaginary parts of $y_i$.
+///
+/// * `xs` - strictly increasing abscissas.
+/// * `ys` - complex values of the integrand at `xs`.
+///
+/// # Examples
+/// ```
+/// use integrate::tabulated::trapezoidal_from_complex_samples;
+/// use num::Complex;
+///
+/// let xs = [0.0, 1.0, 2.0];
+/// let ys = [Complex::new(0.0, 2.0), Complex::new(1.0, 1.0), Complex::new(2.0, 0.0)];
+///
+/// let integral = trapezoidal_from_complex_samples(&xs, &ys);
+/// assert!((integral - Complex::new(2.0, 2.0)).norm() < 1e-15);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `xs` and `ys` have different lengths, if fewer than two samples are given,
+/// or if `xs` is not strictly increasing.
+#[must_use]
+pub fn trapezoidal_from_complex_samples(xs: &[f64], ys: &[Complex<f64>]) -> Complex<f64> {
+    check_samples(xs, ys, 2);
+
+    xs.windows(2)
+        .zip(ys.windows(2))
+        .map(|(x, y)| (y[0] + y[1]) * (x[1] - x[0]) / 2.0)
+        .sum()
+}
+
+/// This function integrates tabulated samples $(x_i, y_i)$ over the subinterval $\[c, d\]$
+/// of $\[x_0, x_n\]$ using the trapezoidal rule, without building a new mesh.
+///
+/// The samples falling strictly inside $\[c, d\]$ are used as they are, and the values at
+/// $c$ and $d$ are linearly interpolated from the samples around them, which is what the
+/// trapezoidal rule on the whole mesh assumes between two samples. Integrating over
+/// $\[x_0, x_n\]$ thus gives the same result as [`trapezoidal_from_samples`], and the
+/// integrals over $\[x_0, m\]$ and $\[m, x_n\]$ add up to it for any cut point $m$.
+///
+/// * `mesh_nodes` - strictly increasing abscissas.
+/// * `mesh_values` - values of the integrand at `mesh_nodes`.
+/// * `c` - lower limit of the subinterval.
+/// * `d` - upper limit of the subinterval.
+///
+/// # Examples
+/// ```
+/// use integrate::tabulated::integrate_submesh;
+///
+/// let xs = [0.0, 1.0, 2.0, 4.0];
+/// let ys = [1.0, 1.0, 1.0, 1.0];
+///
+/// let integral = integrate_submesh(&xs, &ys, 0.5, 3.0);
+/// assert!((integral - 2.5).abs() < 1e-15);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `mesh_nodes` and `mesh_values` have different lengths, if fewer than two
+/// samples are given, if `mesh_nodes` is not strictly increasing, or if $\[c, d\]$ is not
+/// contained in $\[x_0, x_n\]$.
+#[must_use]
+pub fn integrate_submesh(mesh_nodes: &[f64], mesh_values: &[f64], c: f64, d: f64) -> f64 {
+    check_samples(mesh_nodes, mesh_values, 2);
+
+    submesh_sum(mesh_nodes, mesh_values, c, d)
+}
+
+/// Trapezoidal rule over $\[c, d\]$ on samples already checked, see [`integrate_submesh`].
+fn submesh_sum(mesh_nodes: &[f64], mesh_values: &[f64], c: f64, d: f64) -> f64 {
+    let first = mesh_nodes[0];
+    let last = mesh_nodes[mesh_nodes.len() - 1];
+
+    if c.is_nan() || d.is_nan() || c > d {
+        panic!("c must be less than or equal to d.");
+    }
+
+    if c < first || d > last {
+        panic!("[c, d] must be contained in the range of mesh_nodes.");
+    }
+
+    // values at the cut points, interpolated on the mesh cell containing them
+    let interpolate = |x: f64| {
+        let i = mesh_nodes
+            .partition_point(|&node| node <= x)
+            .clamp(1, mesh_nodes.len() - 1);
+        let (x0, x1) = (mesh_nodes[i - 1], mesh_nodes[i]);
+        let (y0, y1) = (mesh_values[i - 1], mesh_values[i]);
+
+        y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+    };
+
+    // samples strictly inside (c, d)
+    let start = mesh_nodes.partition_point(|&node| node <= c);
+    let end = mesh_nodes.partition_point(|&node| node < d);
+
+    let xs = std::iter::once(c)
+        .chain(mesh_nodes[start..end].iter().copied())
+        .chain(std::iter::once(d));
+    let ys = std::iter::once(interpolate(c))
+        .chain(mesh_values[start..end].iter().copied())
+        .chain(std::iter::once(interpolate(d)));
+
+    let samples: Vec<(f64, f64)> = xs.zip(ys).collect();
+
+    samples
+        .windows(2)
+        .map(|w| (w[1].0 - w[0].0) * (w[0].1 + w[1].1) / 2.0)
+        .sum()
+}
+
+/// This function integrates tabulated samples $(x_i, y_i)$ using Simpson's rule,
+/// allowing non-uniform spacing between the abscissas.
+///
+/// * `xs` - strictly increasing abscissas, an odd number of them.
+/// * `ys` - values of the integrand at `xs`.
+///
+/// # Examples
+/// ```
+/// use integrate::tabulated::simpson_from_samples;
+///
+/// let xs = [0.0, 0.25, 1.0, 1.5, 2.0];
+/// let ys: Vec<f64> = xs.iter().map(|x| x * x).collect();
+///
+/// // Simpson's rule is exact for quadratics, even on a non-uniform grid
+/// let integral = simpson_from_samples(&xs, &ys);
+/// assert!((integral - 8.0 / 3.0).abs() < 1e-14);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `xs` and `ys` have different lengths, if fewer than three or an even
+/// number of samples are given, or if `xs` is not strictly increasing.
+#[must_use]
+pub fn simpson_from_samples(xs: &[f64], ys: &[f64]) -> f64 {
+    check_samples(xs, ys, 3);
+
+    simpson_sum(xs, ys)
+}
+
+/// Simpson's rule on samples already checked, but for their number.
+fn simpson_sum(xs: &[f64], ys: &[f64]) -> f64 {
+    if xs.len() < 3 || xs.len() % 2 == 0 {
+        panic!("Simpson's rule requires an odd number of samples, at least 3.");
+    }
+
+    xs.windows(3)
+        .zip(ys.windows(3))
+        .step_by(2)
+        .map(|(x, y)| {
+            let h0 = x[1] - x[0];
+            let h1 = x[2] - x[1];
+            let h = h0 + h1;
+
+            h / 6.0 * ((2.0 - h1 / h0) * y[0] + h * h / (h0 * h1) * y[1] + (2.0 - h0 / h1) * y[2])
+        })
+        .sum()
+}
+
+/// Integrand known through tabulated samples $(x_i, y_i)$.
+///
+/// The samples are checked once, when the integrand is built, rather than by each of the
+/// functions of this module.
+///
+/// # Examples
+/// ```
+/// use integrate::tabulated::TabulatedIntegrand;
+///
+///
+/// let samples: Vec<(f64, f64)> = (0..=10).map(|i| (i as f64 / 10.0, 1.0)).collect();
+///
+/// let integrand = TabulatedIntegrand::from(samples);
+///
+/// assert!((integrand.integrate_trapezoidal() - 1.0).abs() < 1e-15);
+/// assert!((integrand.integrate_simpson() - 1.0).abs() < 1e-15);
+/// assert!((integrand.cumulative()[5] - 0.5).abs() < 1e-15);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TabulatedIntegrand {
+    xs: Vec<f64>,
+    ys: Vec<f64>,
+}
+
+impl TabulatedIntegrand {
+    /// Integrand taking the values `ys` at the abscissas `xs`.
+    ///
+    /// * `xs` - strictly increasing abscissas.
+    /// * `ys` - values of the integrand at `xs`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `xs` and `ys` have different lengths, if fewer than two samples are given,
+    /// or if `xs` is not strictly increasing.
+    pub fn new(xs: Vec<f64>, ys: Vec<f64>) -> Self {
+        check_samples(&xs, &ys, 2);
+
+        TabulatedIntegrand { xs, ys }
+    }
+
+    /// Abscissas of the samples.
+    pub fn xs(&self) -> &[f64] {
+        &self.xs
+    }
+
+    /// Values of the samples.
+    pub fn ys(&self) -> &[f64] {
+        &self.ys
+    }
+
+    /// Integral over $\[x_0, x_n\]$ by the trapezoidal rule, see [`trapezoidal_from_samples`].
+    #[must_use]
+    pub fn integrate_trapezoidal(&self) -> f64 {
+        trapezoidal_sum(&self.xs, &self.ys)
+    }
+
+    /// Integral over $\[x_0, x_n\]$ by Simpson's rule, see [`simpson_from_samples`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of samples is even.
+    #[must_use]
+    pub fn integrate_simpson(&self) -> f64 {
+        simpson_sum(&self.xs, &self.ys)
+    }
+
+    /// Running integrals $\int_{x_0}^{x_i} f(x) dx$, see [`cumulative_trapezoidal`].
+    #[must_use]
+    pub fn cumulative(&self) -> Vec<f64> {
+        cumulative_sum(&self.xs, &self.ys)
+    }
+
+    /// Integral over $\[c, d\]$ by the trapezoidal rule, see [`integrate_submesh`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if $\[c, d\]$ is not contained in $\[x_0, x_n\]$.
+    #[must_use]
+    pub fn integrate_submesh(&self, c: f64, d: f64) -> f64 {
+        submesh_sum(&self.xs, &self.ys, c, d)
+    }
+}
+
+impl From<Vec<(f64, f64)>> for TabulatedIntegrand {
+    /// Integrand from samples $(x_i, y_i)$, see [`TabulatedIntegrand::new`].
+    fn from(samples: Vec<(f64, f64)>) -> Self {
+        let (xs, ys) = samples.into_iter().unzip();
+
+        TabulatedIntegrand::new(xs, ys)
+    }
+}
+
+/// Running trapezoidal integral of samples $(x_i, y_i)$ received one at a time.
+///
+/// Each new sample adds the area of the trapezoid joining it to the previous one, so that
+/// only the last sample is kept. Samples can be pushed one by one with
+/// [`OnlineTrapezoidal::push`], or taken from an iterator with [`Extend::extend`].
+///
+/// # Examples
+/// ```
+/// use integrate::tabulated::OnlineTrapezoidal;
+///
+///
+/// let mut integral = OnlineTrapezoidal::new();
+///
+/// integral.push(0.0, 0.0);
+/// integral.push(1.0, 1.0);
+/// assert_eq!(integral.value(), 0.5);
+///
+/// integral.extend([(2.0, 2.0), (3.0, 3.0)]);
+/// assert_eq!(integral.value(), 4.5);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct OnlineTrapezoidal {
+    // last sample received
+    last: Option<(f64, f64)>,
+    // integral from the first sample to the last one
+    integral: f64,
+}
+
+impl OnlineTrapezoidal {
+    /// Integral with no sample received yet.
+    #[must_use]
+    pub fn new() -> Self {
+        OnlineTrapezoidal::default()
+    }
+
+    /// Adds the sample $(x, y)$, and the area of the trapezoid joining it to the previous
+    /// sample to the integral.
+    ///
+    /// * `x` - abscissa, not less than the previous one.
+    /// * `y` - value of the integrand at `x`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is less than the abscissa of the previous sample, or is `NaN`.
+    pub fn push(&mut self, x: f64, y: f64) {
+        if x.is_nan() {
+            panic!("x can't be NaN.");
+        }
+
+        if let Some((last_x, last_y)) = self.last {
+            if x < last_x {
+                panic!("x must be non-decreasing, got {} after {}.", x, last_x);
+            }
+
+            self.integral += (x - last_x) * (last_y + y) / 2.0;
+        }
+
+        self.last = Some((x, y));
+    }
+
+    /// Integral from the first sample to the last one, zero before the second sample.
+    #[must_use]
+    pub fn value(&self) -> f64 {
+        self.integral
+    }
+}
+
+impl Extend<(f64, f64)> for OnlineTrapezoidal {
+    fn extend<I: IntoIterator<Item = (f64, f64)>>(&mut self, samples: I) {
+        for (x, y) in samples {
+            self.push(x, y);
+        }
+    }
+}
+
+/// Uniform grid Simpson's rule, weights $(1, 4, 2, \dots, 2, 4, 1) \frac{h}{3}$.
+#[cfg(feature = "ndarray")]
+fn simpson_uniform<S>(ys: &ArrayBase<S, Ix1>, h: f64) -> f64
+where
+    S: Data<Elem = f64>,
+{
+    let n = ys.len();
+
+    let interior: f64 = ys
+        .iter()
+        .enumerate()
+        .skip(1)
+        .take(n - 2)
+        .map(|(i, y)| if i % 2 == 1 { 4.0 * y } else { 2.0 * y })
+        .sum();
+
+    h / 3.0 * (ys[0] + interior + ys[n - 1])
+}
+
+/// This function integrates tabulated samples stored in `ndarray` arrays using the
+/// trapezoidal rule, see [`trapezoidal_from_samples`].
+///
+/// * `x` - strictly increasing abscissas.
+/// * `y` - values of the integrand at `x`.
+///
+/// Both owned arrays and views are accepted.
+///
+/// # Examples
+/// ```
+/// use integrate::tabulated::trapezoidal_ndarray;
+/// use ndarray::Array1;
+///
+/// let x = Array1::linspace(0.0, 1.0, 1001);
+/// let y = x.mapv(|x| x * x);
+///
+/// let integral = trapezoidal_ndarray(&x, &y);
+/// assert!((integral - 1.0 / 3.0).abs() < 1e-6);
+/// ```
+#[cfg(feature = "ndarray")]
+#[must_use]
+pub fn trapezoidal_ndarray<S1, S2>(x: &ArrayBase<S1, Ix1>, y: &ArrayBase<S2, Ix1>) -> f64
+where
+    S1: Data<Elem = f64>,
+    S2: Data<Elem = f64>,
+{
+    match (x.as_slice(), y.as_slice()) {
+        (Some(xs), Some(ys)) => trapezoidal_from_samples(xs, ys),
+        _ => trapezoidal_from_samples(&x.to_vec(), &y.to_vec()),
+    }
+}
+
+/// This function integrates values tabulated on a uniform 2D grid using Simpson's rule
+/// along both axes.
+///
+/// * `values` - $f(x_0 + i\\,dx, y_0 + j\\,dy)$ stored at row `i` and column `j`.
+/// * `dx` - spacing between rows.
+/// * `dy` - spacing between columns.
+///
+/// Both dimensions of `values` must be odd and at least three.
+///
+/// # Examples
+/// ```
+/// use integrate::tabulated::simpson_ndarray;
+/// use ndarray::Array2;
+///
+/// // f(x, y) = x² y on [0, 1] x [0, 2]
+/// let (nx, ny) = (11, 21);
+/// let (dx, dy) = (1.0 / 10.0, 2.0 / 20.0);
+/// let values = Array2::from_shape_fn((nx, ny), |(i, j)| {
+///     let (x, y) = (i as f64 * dx, j as f64 * dy);
+///     x * x * y
+/// });
+///
+/// let integral = simpson_ndarray(&values, dx, dy);
+/// assert!((integral - 2.0 / 3.0).abs() < 1e-14);
+/// ```
+///
+/// # Panics
+///
+/// Panics if a dimension of `values` is even or smaller than three, or if `dx` or `dy`
+/// is not positive.
+#[cfg(feature = "ndarray")]
+#[must_use]
+pub fn simpson_ndarray<S>(values: &ArrayBase<S, Ix2>, dx: f64, dy: f64) -> f64
+where
+    S: Data<Elem = f64>,
+{
+    let (nx, ny) = values.dim();
+
+    if nx < 3 || ny < 3 || nx % 2 == 0 || ny % 2 == 0 {
+        panic!(
+            "Simpson's rule requires odd dimensions of at least 3, got ({}, {}).",
+            nx, ny
+        );
+    }
+
+    if !(dx > 0.0 && dy > 0.0) {
+        panic!("dx and dy must be positive.");
+    }
+
+    let rows = values.map_axis(Axis(1), |row| simpson_uniform(&row, dy));
+    simpson_uniform(&rows, dx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn linspace(a: f64, b: f64, n: usize) -> Vec<f64> {
+        let h = (b - a) / (n - 1) as f64;
+        (0..n).map(|i| a + i as f64 * h).collect()
+    }
+
+    #[test]
+    fn test_trapezoidal_from_samples() {
+        let xs = linspace(0.0, 1.0, 1001);
+        let ys: Vec<f64> = xs.iter().map(|x| x * x).collect();
+
+        // error is (b - a) h² f'' / 12
+        let integral = trapezoidal_from_samples(&xs, &ys);
+        assert!((integral - 1.0 / 3.0).abs() < 2e-7);
+    }
+
+    #[test]
+    fn test_simpson_from_samples() {
+        let xs = linspace(0.0, 3.0, 31);
+        let ys: Vec<f64> = xs.iter().map(|x| x * x * x).collect();
+        let integral = simpson_from_samples(&xs, &ys);
+        assert!((integral - 81.0 / 4.0).abs() < 1e-12);
+
+        let xs: Vec<f64> = linspace(0.0, 1.0, 101).iter().map(|x| x * x).collect();
+        let ys: Vec<f64> = xs.iter().map(|x| x.exp()).collect();
+        let integral = simpson_from_samples(&xs, &ys);
+        assert!((integral - (1f64.exp() - 1.0)).abs() < 1e-7);
+    }
+
+    #[test]
+    fn test_trapezoidal_from_complex_samples() {
+        let xs = linspace(0.0, 2.0 * std::f64::consts::PI, 1001);
+        let ys: Vec<Complex<f64>> = xs.iter().map(|&x| Complex::new(0.0, x).exp()).collect();
+
+        // ∫ e^{ix} over a period vanishes
+        let integral = trapezoidal_from_complex_samples(&xs, &ys);
+        assert!(integral.norm() < 1e-12);
+
+        let ys: Vec<Complex<f64>> = xs.iter().map(|&x| Complex::new(x, -x)).collect();
+        let integral = trapezoidal_from_complex_samples(&xs, &ys);
+        let real = trapezoidal_from_samples(&xs, &xs);
+        assert!((integral - Complex::new(real, -real)).norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_integrate_submesh() {
+        // graded mesh
+        let xs: Vec<f64> = linspace(0.0, 1.0, 41).iter().map(|t| 2.0 * t * t).collect();
+
+        let ys: Vec<f64> = xs.iter().map(|x| x.sin()).collect();
+        let full = trapezoidal_from_samples(&xs, &ys);
+        assert!((integrate_submesh(&xs, &ys, 0.0, 2.0) - full).abs() < 1e-15);
+
+        let left = integrate_submesh(&xs, &ys, 0.0, 0.7);
+        let right = integrate_submesh(&xs, &ys, 0.7, 2.0);
+        assert!((left + right - full).abs() < 1e-14);
+
+        // linear data: the trapezoidal rule is exact on every piece
+        let ys: Vec<f64> = xs.iter().map(|x| 3.0 * x + 1.0).collect();
+        let full = trapezoidal_from_samples(&xs, &ys);
+        assert!((full - 8.0).abs() < 1e-13);
+
+        let half = integrate_submesh(&xs, &ys, 0.0, 1.0);
+        assert!((half - 2.5).abs() < 1e-13);
+
+        let half = integrate_submesh(&xs, &ys, 0.5, 1.5);
+        assert!((half - 4.0).abs() < 1e-13);
+
+        assert_eq!(integrate_submesh(&xs, &ys, 0.3, 0.3), 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_submesh_out_of_range() {
+        let _ = integrate_submesh(&[0.0, 1.0, 2.0], &[0.0, 1.0, 4.0], 0.5, 2.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_complex_samples_length_mismatch() {
+        let _ = trapezoidal_from_complex_samples(&[0.0, 1.0, 2.0], &[Complex::new(0.0, 1.0); 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_simpson_even_samples() {
+        let _ = simpson_from_samples(&[0.0, 1.0, 2.0, 3.0], &[0.0, 1.0, 4.0, 9.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unsorted_samples() {
+        let _ = trapezoidal_from_samples(&[0.0, 2.0, 1.0], &[0.0, 1.0, 4.0]);
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn test_trapezoidal_ndarray() {
+        use ndarray::{s, Array1};
+
+        let x = Array1::linspace(0.0, 1.0, 2001);
+        let y = x.mapv(|x| x * x);
+        let integral = trapezoidal_ndarray(&x, &y);
+        assert!((integral - 1.0 / 3.0).abs() < 1e-7);
+
+        // strided views are accepted as well
+        let integral = trapezoidal_ndarray(&x.slice(s![..;2]), &y.slice(s![..;2]));
+        assert!((integral - 1.0 / 3.0).abs() < 1e-6);
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn test_simpson_ndarray() {
+        use ndarray::{Array1, Array2};
+
+        let x = Array1::linspace(0.0, 2.0, 21);
+        let y = Array1::linspace(-1.0, 1.0, 41);
+        let values = Array2::from_shape_fn((x.len(), y.len()), |(i, j)| x[i] * x[i] + y[j] * y[j]);
+
+        // ∫∫ x² + y² over [0, 2] x [-1, 1] = 16/3 + 4/3
+        let integral = simpson_ndarray(&values, x[1] - x[0], y[1] - y[0]);
+        assert!((integral - 20.0 / 3.0).abs() < 1e-12);
+
+        // integrating the transposed view swaps the spacings
+        let integral = simpson_ndarray(&values.t(), y[1] - y[0], x[1] - x[0]);
+        assert!((integral - 20.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_online_trapezoidal() {
+        let xs = linspace(-1.0, 2.0, 301);
+        let ys: Vec<f64> = xs.iter().map(|x| x * x).collect();
+
+        let mut online = OnlineTrapezoidal::new();
+        for (&x, &y) in xs.iter().zip(&ys) {
+            online.push(x, y);
+        }
+
+        // same terms added in the same order
+        assert_eq!(online.value(), trapezoidal_from_samples(&xs, &ys));
+
+        let mut extended = OnlineTrapezoidal::new();
+        extended.extend(xs.iter().map(|&x| (x, x * x)));
+        assert_eq!(extended.value(), online.value());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_online_trapezoidal_decreasing() {
+        let mut online = OnlineTrapezoidal::new();
+        online.push(1.0, 0.0);
+        online.push(0.5, 0.0);
+    }
+
+    #[test]
+    fn test_tabulated_integrand() {
+        let xs = vec![0.0, 0.25, 1.0, 1.5, 2.0];
+        let ys: Vec<f64> = xs.iter().map(|x| x * x * x).collect();
+
+        let integrand = TabulatedIntegrand::new(xs.clone(), ys.clone());
+        let from_pairs: TabulatedIntegrand = xs
+            .iter()
+            .map(|&x| (x, x * x * x))
+            .collect::<Vec<_>>()
+            .into();
+        assert_eq!(integrand, from_pairs);
+
+        assert_eq!(
+            integrand.integrate_trapezoidal(),
+            trapezoidal_from_samples(&xs, &ys)
+        );
+        assert_eq!(
+            integrand.integrate_simpson(),
+            simpson_from_samples(&xs, &ys)
+        );
+        assert_eq!(integrand.cumulative(), cumulative_trapezoidal(&xs, &ys));
+        assert_eq!(
+            integrand.integrate_submesh(0.1, 1.7),
+            integrate_submesh(&xs, &ys, 0.1, 1.7)
+        );
+
+        // the last running integral is the whole integral
+        let cumulative = integrand.cumulative();
+        assert_eq!(cumulative.len(), xs.len());
+        assert_eq!(cumulative[0], 0.0);
+        assert!((cumulative[4] - integrand.integrate_trapezoidal()).abs() < 1e-15);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_tabulated_integrand_unsorted() {
+        let _ = TabulatedIntegrand::from(vec![(0.0, 1.0), (2.0, 1.0), (1.0, 1.0)]);
+    }
+}
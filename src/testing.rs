@@ -1,3 +1,23 @@
+//! Benchmark problems
+//!
+//! The 30 integrals the rules of this crate are tested against, each with its integrand,
+//! limits and exact value, so that wrappers built on top of the crate can be validated
+//! against the same battery.
+//!
+//! This module is only available with the `test-util` feature.
+//!
+//! # Examples
+//! ```
+//! use integrate::newton_cotes::simpson::simpson_rule;
+//! use integrate::testing::problem01;
+//!
+//! let problem = problem01::<f64>();
+//! let (a, b) = problem.limits;
+//!
+//! let integral = simpson_rule(problem.function, a, b, problem.n);
+//! assert!(problem.check_result(integral));
+//! ```
+
 use std::{f64::consts::PI, iter::Sum};
 
 use num::Float;
@@ -6,6 +26,7 @@ use rayon::iter::{IntoParallelIterator, ParallelIterator};
 const EPSILON: f64 = 10e-4;
 const NUM_STEPS: usize = 100_000;
 
+/// A definite integral with a known value.
 #[derive(Clone, Debug)]
 pub struct Problem<F: Float> {
     pub id: usize,
@@ -16,12 +37,49 @@ pub struct Problem<F: Float> {
 }
 
 impl<F: Float> Problem<F> {
+    /// Returns whether `result` is within `1e-3` of the exact value.
     pub fn check_result(&self, result: F) -> bool {
         let epsilon = F::from(EPSILON).unwrap();
         (self.exact - result).abs() < epsilon
     }
 }
 
+/// Returns the 30 benchmark problems, ordered by id.
+pub fn problems<F: Float + Send + Sum + Sync>() -> Vec<Problem<F>> {
+    vec![
+        problem01(),
+        problem02(),
+        problem03(),
+        problem04(),
+        problem05(),
+        problem06(),
+        problem07(),
+        problem08(),
+        problem09(),
+        problem10(),
+        problem11(),
+        problem12(),
+        problem13(),
+        problem14(),
+        problem15(),
+        problem16(),
+        problem17(),
+        problem18(),
+        problem19(),
+        problem20(),
+        problem21(),
+        problem22(),
+        problem23(),
+        problem24(),
+        problem25(),
+        problem26(),
+        problem27(),
+        problem28(),
+        problem29(),
+        problem30(),
+    ]
+}
+
 pub fn problem01<F: Float>() -> Problem<F> {
     fn f<F: Float>(x: F) -> F {
         x.exp()
@@ -0,0 +1,114 @@
+//! Error tolerances
+//!
+//! The error-controlled methods of this crate stop once their estimated error $E$ of an
+//! approximation $I$ of the integral satisfies
+//!
+//! ```math
+//! E < \max \left( \epsilon_{abs}, \epsilon_{rel} |I| \right)
+//! ```
+//!
+//! as SciPy's `quad` does. A purely absolute tolerance has $\epsilon_{rel} = 0$, which
+//! asks for the same number of correct decimals whatever the magnitude of the integral,
+//! while a purely relative one has $\epsilon_{abs} = 0$, which asks for the same number of
+//! significant digits, but can't be met by an integral equal to zero.
+
+/// Combined absolute and relative tolerance.
+///
+/// # Examples
+/// ```
+/// use integrate::tolerance::Tolerance;
+///
+///
+/// let tolerance = Tolerance::new(1e-10, 1e-6);
+///
+/// // the relative part dominates for large integrals
+/// assert!(tolerance.satisfied(1e-3, 1e4));
+/// assert!(!tolerance.satisfied(1e-3, 1.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tolerance {
+    abs: f64,
+    rel: f64,
+}
+
+impl Tolerance {
+    /// Tolerance with the given absolute and relative parts.
+    ///
+    /// * `abs` - absolute tolerance.
+    /// * `rel` - relative tolerance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either part is negative or `NaN`, or if both are zero.
+    pub fn new(abs: f64, rel: f64) -> Self {
+        if abs.is_nan() || abs < 0.0 || rel.is_nan() || rel < 0.0 {
+            panic!("tolerances must be non-negative.");
+        }
+
+        if abs == 0.0 && rel == 0.0 {
+            panic!("absolute and relative tolerances can't both be zero.");
+        }
+
+        Tolerance { abs, rel }
+    }
+
+    /// Purely absolute tolerance.
+    pub fn absolute(abs: f64) -> Self {
+        Tolerance::new(abs, 0.0)
+    }
+
+    /// Purely relative tolerance.
+    pub fn relative(rel: f64) -> Self {
+        Tolerance::new(0.0, rel)
+    }
+
+    /// Absolute tolerance $\epsilon_{abs}$.
+    #[must_use]
+    pub fn abs(&self) -> f64 {
+        self.abs
+    }
+
+    /// Relative tolerance $\epsilon_{rel}$.
+    #[must_use]
+    pub fn rel(&self) -> f64 {
+        self.rel
+    }
+
+    /// Largest error allowed for the approximation `estimate`, $\max(\epsilon_{abs}, \epsilon_{rel} |I|)$.
+    #[must_use]
+    pub fn bound(&self, estimate: f64) -> f64 {
+        self.abs.max(self.rel * estimate.abs())
+    }
+
+    /// Whether the estimated `error` of the approximation `estimate` is below [`Tolerance::bound`].
+    #[must_use]
+    pub fn satisfied(&self, error: f64, estimate: f64) -> bool {
+        error < self.bound(estimate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_satisfied() {
+        let absolute = Tolerance::absolute(1e-6);
+        assert!(absolute.satisfied(5e-7, 1e9));
+        assert!(!absolute.satisfied(2e-6, 1e9));
+
+        let relative = Tolerance::relative(1e-6);
+        assert!(relative.satisfied(500.0, 1e9));
+        assert!(!relative.satisfied(5e-7, 0.1));
+
+        // a zero integral never satisfies a purely relative tolerance
+        assert!(!relative.satisfied(0.0, 0.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_both_zero() {
+        let _ = Tolerance::new(0.0, 0.0);
+    }
+}
@@ -0,0 +1,312 @@
+//! Semi-infinite intervals
+//!
+//! The rules of [`Method`] integrate over bounded intervals. An integral over $\[a, \infty)$
+//! is brought back to $\[0, 1)$ by the change of variable
+//!
+//! ```math
+//! x = a + \frac{t}{1 - t}, \quad dx = \frac{dt}{(1 - t)^2}
+//! ```
+//!
+//! so that
+//!
+//! ```math
+//! \int_{a}^{\infty} f(x) dx = \int_{0}^{1} f\left(a + \frac{t}{1 - t}\right) \frac{dt}{(1 - t)^2}
+//! ```
+//!
+//! The Jacobian $\frac{1}{(1 - t)^2}$ blows up as $t \to 1$, and the transformed integrand
+//! only has a finite limit, zero, when $f(x)$ decays faster than $\frac{1}{x^2}$. The
+//! rectangle and Gauss-Legendre rules never evaluate the integrand at $t = 1$, while the
+//! closed rules do: there, and wherever $1 - t$ is below [`Float::min_positive_value`], the
+//! transformed integrand is taken to be its limit, zero, instead of the $0 \cdot \infty$
+//! that evaluating it would produce.
+//!
+//! # Endpoint singularities
+//!
+//! An integrand behaving like $(x - a)^p$ near $a$, with $-1 < p < 0$, is integrable but
+//! unbounded, and the polynomial rules converge slowly on it. The change of variable
+//!
+//! ```math
+//! x = a + (b - a) u^k, \quad k = \frac{1}{1 + p}
+//! ```
+//!
+//! turns $\int_{a}^{b} (x - a)^p g(x) dx$ into
+//!
+//! ```math
+//! k (b - a)^{1 + p} \int_{0}^{1} g\left(a + (b - a) u^k\right) du
+//! ```
+//!
+//! whose integrand is bounded. When $p$ is not known, [`estimate_endpoint_exponent`]
+//! estimates it from the slope of $\ln |f(x)|$ against $\ln (x - a)$ close to $a$.
+
+use num::Float;
+
+use crate::gauss_quadrature::legendre::legendre_rule;
+use crate::method::Method;
+
+/// Distances to the endpoint, relative to the length of the interval, at which the
+/// integrand is sampled to estimate its exponent.
+const EXPONENT_FIT_DISTANCES: [f64; 6] = [1e-5, 1e-6, 1e-7, 1e-8, 1e-9, 1e-10];
+
+/// Integrates $f(x)$ from $a$ to $\infty$ by mapping $\[a, \infty)$ onto $\[0, 1)$.
+///
+/// * `func` - Integrand function of a single variable, decaying faster than $\frac{1}{x^2}$.
+/// * `lower_limit` - lower limit $a$ of the integration interval.
+/// * `n` - number of subintervals, or number of points for Gauss-Legendre.
+/// * `rule` - integration method applied on $\[0, 1\]$.
+///
+/// Open rules, [`Method::Rectangle`] and [`Method::GaussLegendre`], are preferred: they
+/// evaluate the integrand strictly inside $\[0, 1)$. See the [module documentation](self)
+/// for the behavior of the closed rules as $t \to 1$.
+///
+/// # Examples
+/// ```
+/// use integrate::method::Method;
+/// use integrate::transform::integrate_semi_infinite;
+///
+///
+/// let f = |x: f64| (-x).exp();
+///
+/// let integral = integrate_semi_infinite(f, 0.0, 1_000, Method::Rectangle);
+///
+/// assert!((integral - 1.0).abs() < 1e-6);
+/// ```
+#[must_use]
+pub fn integrate_semi_infinite<Func, F: Float + Send + Sync>(
+    func: Func,
+    lower_limit: F,
+    n: usize,
+    rule: Method,
+) -> f64
+where
+    Func: Fn(F) -> F + Sync,
+{
+    if !lower_limit.is_finite() {
+        panic!("lower limit must be finite.");
+    }
+
+    let transformed = |t: F| {
+        let one_minus_t = F::one() - t;
+
+        // limit of f(x) / (1 - t)² as t → 1
+        if one_minus_t < F::min_positive_value() {
+            return F::zero();
+        }
+
+        let x = lower_limit + t / one_minus_t;
+        func(x) / (one_minus_t * one_minus_t)
+    };
+
+    rule.integrate(transformed, F::zero(), F::one(), n)
+}
+
+/// Integrates $f(x)$ from $a$ to $b$ when $f(x)$ behaves like $(x - a)^p$ near $a$, using
+/// the substitution $x = a + (b - a) u^{\frac{1}{1 + p}}$ followed by the $n$ point
+/// Gauss-Legendre rule.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - lower limit $a$ of the integration interval, where $f$ may be singular.
+/// * `upper_limit` - upper limit $b$ of the integration interval.
+/// * `exponent` - exponent $p$ of the behavior of $f$ near $a$, greater than $-1$.
+/// * `n` - number of Gauss-Legendre points.
+///
+/// The Gauss-Legendre nodes lie strictly inside $\[0, 1\]$, so $f$ is never evaluated at $a$.
+///
+/// # Examples
+/// ```
+/// use integrate::transform::integrate_endpoint_singularity;
+///
+///
+/// let f = |x: f64| 1.0 / x.sqrt();
+///
+/// let integral = integrate_endpoint_singularity(f, 0.0, 1.0, -0.5, 10);
+///
+/// assert!((integral - 2.0).abs() < 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the limits are not finite, if `lower_limit` is not less than `upper_limit`,
+/// or if `exponent` is not a finite number greater than $-1$.
+#[must_use]
+pub fn integrate_endpoint_singularity<Func>(
+    func: Func,
+    lower_limit: f64,
+    upper_limit: f64,
+    exponent: f64,
+    n: usize,
+) -> f64
+where
+    Func: Fn(f64) -> f64 + Sync,
+{
+    if !lower_limit.is_finite() || !upper_limit.is_finite() {
+        panic!("Integral limits a and b can't be infinite");
+    }
+
+    if lower_limit >= upper_limit {
+        panic!("a must be strictly less than b");
+    }
+
+    if !exponent.is_finite() || exponent <= -1.0 {
+        panic!("exponent must be a finite number greater than -1.");
+    }
+
+    let k = 1.0 / (1.0 + exponent);
+    let width = upper_limit - lower_limit;
+
+    let transformed = |u: f64| {
+        let x = lower_limit + width * u.powf(k);
+        k * width * u.powf(k - 1.0) * func(x)
+    };
+
+    legendre_rule(transformed, 0.0, 1.0, n)
+}
+
+/// Estimates the exponent $p$ of an integrand behaving like $(x - a)^p$ near $a$.
+///
+/// $f$ is sampled at distances from $a$ ranging from $10^{-5}$ to $10^{-10}$ times $b - a$,
+/// and $p$ is the least squares slope of $\ln |f(x)|$ against $\ln (x - a)$. The estimate
+/// can be passed to [`integrate_endpoint_singularity`].
+///
+/// * `func` - Integrand function of a single variable.
+/// * `lower_limit` - endpoint $a$ near which $f$ is sampled.
+/// * `upper_limit` - other endpoint $b$ of the integration interval.
+///
+/// Returns `None` if $f$ vanishes or is not finite at one of the samples, or if the
+/// samples are too close to $a$ to be told apart.
+///
+/// # Examples
+/// ```
+/// use integrate::transform::{estimate_endpoint_exponent, integrate_endpoint_singularity};
+///
+///
+/// let f = |x: f64| (1.0 + x) / x.sqrt();
+///
+/// let p = estimate_endpoint_exponent(f, 0.0, 1.0).unwrap();
+/// assert!((p + 0.5).abs() < 1e-4);
+///
+/// let integral = integrate_endpoint_singularity(f, 0.0, 1.0, p, 20);
+/// assert!((integral - 8.0 / 3.0).abs() < 1e-4);
+/// ```
+#[must_use]
+pub fn estimate_endpoint_exponent<Func>(
+    func: Func,
+    lower_limit: f64,
+    upper_limit: f64,
+) -> Option<f64>
+where
+    Func: Fn(f64) -> f64,
+{
+    let width = upper_limit - lower_limit;
+
+    let mut points = Vec::with_capacity(EXPONENT_FIT_DISTANCES.len());
+    for distance in EXPONENT_FIT_DISTANCES {
+        let x = lower_limit + width * distance;
+        let y = func(x).abs();
+
+        // actual distance, after x has been rounded
+        let d = (x - lower_limit).abs();
+
+        if d == 0.0 || y == 0.0 || !y.is_finite() {
+            return None;
+        }
+
+        points.push((d.ln(), y.ln()));
+    }
+
+    let count = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / count;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / count;
+
+    let (covariance, variance) = points.iter().fold((0.0, 0.0), |(cov, var), (x, y)| {
+        (
+            cov + (x - mean_x) * (y - mean_y),
+            var + (x - mean_x) * (x - mean_x),
+        )
+    });
+
+    let slope = covariance / variance;
+
+    if slope.is_finite() {
+        Some(slope)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_bias_near_endpoint() {
+        let exp = |x: f64| (-x).exp();
+        let x_exp = |x: f64| x * (-x).exp();
+
+        for rule in Method::ALL {
+            let n = if rule == Method::GaussLegendre {
+                100
+            } else {
+                10_000
+            };
+
+            let tolerance = match rule.order() {
+                Some(2) => 1e-7,
+                _ => 1e-10,
+            };
+
+            let integral = integrate_semi_infinite(exp, 0.0, n, rule);
+            assert!(
+                (integral - 1.0).abs() < tolerance,
+                "{} gave {}",
+                rule,
+                integral
+            );
+
+            let integral = integrate_semi_infinite(x_exp, 0.0, n, rule);
+            assert!(
+                (integral - 1.0).abs() < tolerance,
+                "{} gave {}",
+                rule,
+                integral
+            );
+        }
+    }
+
+    #[test]
+    fn test_shifted_lower_limit() {
+        let integral =
+            integrate_semi_infinite(|x: f64| 1.0 / (x * x), 1.0, 50, Method::GaussLegendre);
+
+        assert!((integral - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_estimate_endpoint_exponent() {
+        let f = |x: f64| 1.0 / x.sqrt();
+
+        let p = estimate_endpoint_exponent(f, 0.0, 1.0).unwrap();
+        assert!((p + 0.5).abs() < 1e-10);
+
+        let integral = integrate_endpoint_singularity(f, 0.0, 1.0, p, 20);
+        assert!((integral - 2.0).abs() < 1e-8);
+
+        // the plain rule converges like √h on the same integrand
+        let plain = Method::GaussLegendre.integrate(f, 0.0, 1.0, 20);
+        assert!((plain - 2.0).abs() > 1e-2);
+
+        // shifted interval and another exponent, ∫ (x - 1)^{-0.3} over [1, 3]
+        let g = |x: f64| (x - 1.0).powf(-0.3);
+
+        let p = estimate_endpoint_exponent(g, 1.0, 3.0).unwrap();
+        assert!((p + 0.3).abs() < 1e-6);
+
+        let integral = integrate_endpoint_singularity(g, 1.0, 3.0, p, 20);
+        let exact = 2.0_f64.powf(0.7) / 0.7;
+        assert!((integral - exact).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_estimate_endpoint_exponent_vanishing() {
+        assert_eq!(estimate_endpoint_exponent(|_: f64| 0.0, 0.0, 1.0), None);
+    }
+}
@@ -0,0 +1,77 @@
+//! Affine change of variable
+//!
+//! Gauss rules are defined on the reference interval $\[-1, 1\]$. Integrating over
+//! $\[a, b\]$ uses the affine map
+//!
+//! ```math
+//! x = c t + d, \quad c = \frac{b - a}{2}, \quad d = \frac{b + a}{2}
+//! ```
+//!
+//! so that
+//!
+//! ```math
+//! \int_{a}^{b} f(x) dx = c \int_{-1}^{1} f(c t + d) dt
+//! ```
+
+use num::Float;
+
+/// Affine map $t \mapsto c t + d$ sending $\[-1, 1\]$ onto $\[a, b\]$.
+///
+/// # Examples
+/// ```
+/// use integrate::utils::affine::AffineMap;
+///
+/// let map = AffineMap::new(2.0, 5.0);
+///
+/// assert_eq!(map.map(-1.0), 2.0);
+/// assert_eq!(map.map(1.0), 5.0);
+/// assert_eq!(map.jacobian(), 1.5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AffineMap<F: Float> {
+    c: F,
+    d: F,
+}
+
+impl<F: Float> AffineMap<F> {
+    /// Map sending $\[-1, 1\]$ onto $\[a, b\]$.
+    ///
+    /// * `lower_limit` - image $a$ of $-1$.
+    /// * `upper_limit` - image $b$ of $1$.
+    #[must_use]
+    pub fn new(lower_limit: F, upper_limit: F) -> Self {
+        let two = F::one() + F::one();
+
+        AffineMap {
+            c: (upper_limit - lower_limit) / two,
+            d: (upper_limit + lower_limit) / two,
+        }
+    }
+
+    /// Image $x = c t + d$ of `t`.
+    #[must_use]
+    pub fn map(&self, t: F) -> F {
+        self.c * t + self.d
+    }
+
+    /// Jacobian $\frac{dx}{dt} = c$ of the map.
+    #[must_use]
+    pub fn jacobian(&self) -> F {
+        self.c
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoints() {
+        let map = AffineMap::new(2.0, 5.0);
+
+        assert_eq!(map.map(-1.0), 2.0);
+        assert_eq!(map.map(1.0), 5.0);
+        assert_eq!(map.map(0.0), 3.5);
+        assert_eq!(map.jacobian(), 1.5);
+    }
+}
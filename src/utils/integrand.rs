@@ -0,0 +1,385 @@
+//! Integrand wrappers
+//!
+//! Functions in this module take an integrand $f(x)$ and return a new integrand that can
+//! be passed to any rule of this crate in place of $f$.
+//!
+//! [`PiecewiseIntegrand`] instead describes an integrand defined by a different function on
+//! each segment of $\[a, b\]$, and integrates each segment separately.
+//!
+//! [`MemoizedIntegrand`] remembers the values of an expensive integrand, so that each
+//! distinct $x$ is evaluated once across several integrations.
+//!
+//! [`RemovableSingularity`] supplies the limit of an integrand at a point where it can't be
+//! evaluated, such as $\frac{\sin(x)}{x}$ at $x = 0$.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use num::Float;
+
+use crate::method::Method;
+
+/// Wraps `func` so that every evaluation saturates to the interval $\[min, max\]$.
+///
+/// * `func` - Integrand function of a single variable.
+/// * `min` - lowest value the wrapped integrand may return.
+/// * `max` - highest value the wrapped integrand may return.
+///
+/// Finite values of $f(x)$ are clamped to $\[min, max\]$, $+\infty$ is mapped to `max`,
+/// $-\infty$ to `min` and `NaN` to zero (itself clamped to $\[min, max\]$).
+///
+/// This removes the need to hand-roll overflow guards inside the integrand, as is done
+/// for instance when evaluating $\operatorname{sech}(x) = \frac{1}{\cosh(x)}$ for large $x$.
+/// Note that this trades correctness for robustness: wherever $f$ is clamped, the rule
+/// integrates a different function than $f$, and the result is only meaningful if the
+/// clamped region contributes little to the integral.
+///
+/// # Examples
+/// ```
+/// use integrate::newton_cotes::simpson::simpson_rule;
+/// use integrate::utils::integrand::clamp_integrand;
+///
+/// // f(0) = +inf would poison the sum
+/// let f = clamp_integrand(|x: f64| 1.0 / (x * x), -1.0e6, 1.0e6);
+///
+/// let integral = simpson_rule(f, 0.0, 1.0, 100_usize);
+///
+/// assert!(integral.is_finite());
+/// ```
+pub fn clamp_integrand<Func, F1: Float, F2: Float + Send + Sync>(
+    func: Func,
+    min: F2,
+    max: F2,
+) -> impl Fn(F1) -> F2 + Sync + Send + Copy
+where
+    Func: Fn(F1) -> F2 + Sync + Send + Copy,
+{
+    assert!(min <= max, "min must be less than or equal to max");
+
+    move |x: F1| {
+        let y = func(x);
+
+        if y.is_nan() {
+            F2::zero().max(min).min(max)
+        } else {
+            y.max(min).min(max)
+        }
+    }
+}
+
+/// Integrand defined piecewise over consecutive segments $\[x_0, x_1\], \[x_1, x_2\], ..., \[x_{k-1}, x_k\]$,
+/// with its own function on each segment.
+///
+/// Rather than branching on $x$ inside a single integrand, which defeats vectorization and
+/// makes the rules straddle the discontinuities, each segment is integrated separately
+/// with its own function, and the results are added.
+///
+/// # Examples
+/// ```
+/// use integrate::method::Method;
+/// use integrate::utils::integrand::PiecewiseIntegrand;
+///
+/// // |x| on [-1, 2]
+/// let f = PiecewiseIntegrand::new(-1.0)
+///     .piece(0.0, |x: f64| -x)
+///     .piece(2.0, |x: f64| x);
+///
+/// let integral = f.integrate(Method::Trapezoidal, 10);
+///
+/// assert!((integral - 2.5).abs() < 1e-12);
+/// ```
+pub struct PiecewiseIntegrand<'a, F: Float> {
+    breakpoints: Vec<F>,
+    pieces: Vec<Box<dyn Fn(F) -> F + Sync + Send + 'a>>,
+}
+
+impl<'a, F: Float + Sync + Send> PiecewiseIntegrand<'a, F> {
+    /// Starts a piecewise integrand at `lower_limit`, without any segment.
+    ///
+    /// * `lower_limit` - lower limit of the first segment.
+    pub fn new(lower_limit: F) -> Self {
+        PiecewiseIntegrand {
+            breakpoints: vec![lower_limit],
+            pieces: Vec::new(),
+        }
+    }
+
+    /// Appends the segment from the current last breakpoint to `upper_limit`, on which the
+    /// integrand is `func`.
+    ///
+    /// * `upper_limit` - upper limit of the segment.
+    /// * `func` - integrand on the segment.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `upper_limit` is not greater than the previous breakpoint.
+    pub fn piece<Func>(mut self, upper_limit: F, func: Func) -> Self
+    where
+        Func: Fn(F) -> F + Sync + Send + 'a,
+    {
+        let last = *self.breakpoints.last().unwrap();
+
+        if upper_limit <= last {
+            panic!("breakpoints must be strictly increasing");
+        }
+
+        self.breakpoints.push(upper_limit);
+        self.pieces.push(Box::new(func));
+        self
+    }
+
+    /// Breakpoints $x_0 < x_1 < ... < x_k$ of the integrand.
+    pub fn breakpoints(&self) -> &[F] {
+        &self.breakpoints
+    }
+
+    /// Integrates the integrand over $\[x_0, x_k\]$, applying `rule` with `n` steps to each segment.
+    ///
+    /// * `rule` - integration method used on each segment.
+    /// * `n` - number of subintervals, or number of points for Gauss-Legendre, per segment.
+    #[must_use]
+    pub fn integrate(&self, rule: Method, n: usize) -> f64 {
+        self.breakpoints
+            .windows(2)
+            .zip(self.pieces.iter())
+            .map(|(limits, func)| rule.integrate(func, limits[0], limits[1], n))
+            .sum()
+    }
+}
+
+/// Integrand remembering its values, so that $f$ is evaluated at most once per distinct $x$.
+///
+/// The values are stored in a `HashMap` keyed on the bit pattern of $x$, as given by
+/// [`Float::integer_decode`], so that $0$ and $-0$ are distinct keys. The lock on the map
+/// is not held while $f$ is evaluated, two threads asking for the same new $x$ at once may
+/// therefore both evaluate $f$.
+///
+/// Within a single run, the rules of this crate evaluate $f$ once per node already, the
+/// savings come from runs sharing nodes, e.g. Romberg's method or the trapezoidal rule
+/// with an increasing number of steps, or several rules applied to the same integrand.
+///
+/// # Examples
+/// ```
+/// use integrate::romberg::romberg_method;
+/// use integrate::utils::integrand::MemoizedIntegrand;
+///
+///
+/// let memoized = MemoizedIntegrand::new(|x: f64| x.exp());
+/// let f = |x: f64| memoized.eval(x);
+///
+/// let coarse = romberg_method(f, 0.0, 1.0, 5_usize);
+/// assert_eq!(memoized.evaluations(), 17);
+///
+/// // only the 16 new midpoints are evaluated
+/// let fine = romberg_method(f, 0.0, 1.0, 6_usize);
+/// assert_eq!(memoized.evaluations(), 33);
+/// ```
+pub struct MemoizedIntegrand<F1: Float, F2, Func> {
+    func: Func,
+    values: Mutex<HashMap<(u64, i16, i8), F2>>,
+    _x: std::marker::PhantomData<F1>,
+}
+
+impl<F1: Float, F2: Copy, Func> MemoizedIntegrand<F1, F2, Func>
+where
+    Func: Fn(F1) -> F2,
+{
+    /// Wraps `func`, with no value remembered yet.
+    ///
+    /// * `func` - Integrand function of a single variable.
+    pub fn new(func: Func) -> Self {
+        MemoizedIntegrand {
+            func,
+            values: Mutex::new(HashMap::new()),
+            _x: std::marker::PhantomData,
+        }
+    }
+
+    /// Value of the integrand at `x`, evaluating it only if `x` was never seen before.
+    pub fn eval(&self, x: F1) -> F2 {
+        let key = x.integer_decode();
+
+        if let Some(&y) = self.values.lock().unwrap().get(&key) {
+            return y;
+        }
+
+        let y = (self.func)(x);
+        self.values.lock().unwrap().insert(key, y);
+        y
+    }
+
+    /// Number of distinct values of $x$ at which the integrand was evaluated.
+    pub fn evaluations(&self) -> usize {
+        self.values.lock().unwrap().len()
+    }
+}
+
+/// Removable singularity of an integrand: $f(x)$ can't be evaluated at `at`, e.g. it gives
+/// $\frac{0}{0}$, but has the finite limit `limit_value` there.
+///
+/// Rather than moving the limits of integration away from the singularity by a small
+/// amount, [`RemovableSingularity::apply`] wraps the integrand so that `limit_value` is
+/// returned at the nodes within machine epsilon (relative to `at`) of the singularity.
+///
+/// # Examples
+/// ```
+/// use integrate::newton_cotes::simpson::simpson_rule;
+/// use integrate::utils::integrand::RemovableSingularity;
+///
+///
+/// // sin(x) / x tends to 1 at x = 0
+/// let hint = RemovableSingularity { at: 0.0, limit_value: 1.0 };
+/// let f = hint.apply(|x: f64| x.sin() / x);
+///
+/// assert_eq!(f(0.0), 1.0);
+///
+/// let integral = simpson_rule(f, 0.0, std::f64::consts::PI, 100_usize);
+/// assert!((integral - 1.851_937_051_982_466).abs() < 1e-8);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RemovableSingularity<F: Float> {
+    /// Point where the integrand can't be evaluated.
+    pub at: F,
+    /// Limit of the integrand at `at`.
+    pub limit_value: F,
+}
+
+impl<F: Float + Send + Sync> RemovableSingularity<F> {
+    /// Whether `x` is within machine epsilon of the singularity, relative to its magnitude
+    /// when it is larger than one.
+    pub fn contains(&self, x: F) -> bool {
+        (x - self.at).abs() <= F::epsilon() * self.at.abs().max(F::one())
+    }
+
+    /// Wraps `func` so that it returns `limit_value` near the singularity, see
+    /// [`RemovableSingularity::contains`], and $f(x)$ elsewhere.
+    ///
+    /// * `func` - Integrand function of a single variable.
+    pub fn apply<Func>(self, func: Func) -> impl Fn(F) -> F + Sync + Send + Copy
+    where
+        Func: Fn(F) -> F + Sync + Send + Copy,
+    {
+        move |x: F| {
+            if self.contains(x) {
+                self.limit_value
+            } else {
+                func(x)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::newton_cotes::simpson::simpson_rule;
+
+    #[test]
+    fn test_clamp_values() {
+        let f = clamp_integrand(|x: f64| 1.0 / x, -10.0, 10.0);
+
+        assert_eq!(f(0.0), 10.0);
+        assert_eq!(f(-0.0), -10.0);
+        assert_eq!(f(0.5), 2.0);
+
+        let g = clamp_integrand(|_: f64| f64::NAN, -10.0, 10.0);
+
+        assert_eq!(g(1.0), 0.0);
+    }
+
+    #[test]
+    fn test_clamped_singular_integrand() {
+        let f = clamp_integrand(|x: f64| 1.0 / x.powi(2), -1.0e6, 1.0e6);
+
+        let integral = simpson_rule(f, 0.0, 1.0, 1_000_usize);
+
+        assert!(integral.is_finite());
+    }
+
+    #[test]
+    fn test_piecewise_problem29() {
+        // 1 / (x + 2) on [0, e - 2], then 0 on [e - 2, 1]
+        let f = PiecewiseIntegrand::new(0.0)
+            .piece(1f64.exp() - 2.0, |x: f64| 1.0 / (x + 2.0))
+            .piece(1.0, |_: f64| 0.0);
+
+        assert_eq!(f.breakpoints().len(), 3);
+
+        let exact = 1.0 - 2f64.ln();
+        let integral = f.integrate(Method::Simpson, 100);
+
+        assert!((integral - exact).abs() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_piecewise_unsorted_breakpoints() {
+        let _ = PiecewiseIntegrand::new(0.0)
+            .piece(1.0, |x: f64| x)
+            .piece(0.5, |x: f64| x);
+    }
+
+    #[test]
+    fn test_memoized_romberg() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        use crate::romberg::romberg_method;
+
+        let calls = AtomicUsize::new(0);
+        let f = |x: f64| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            x.sin()
+        };
+
+        // a convergence study, without memoization every run starts from scratch
+        for n_columns in 1..=8_usize {
+            let _ = romberg_method(f, 0.0, 1.0, n_columns);
+        }
+        let plain_calls = calls.swap(0, Ordering::SeqCst);
+
+        let memoized = MemoizedIntegrand::new(f);
+        let g = |x: f64| memoized.eval(x);
+        let mut integrals = Vec::new();
+        for n_columns in 1..=8_usize {
+            integrals.push(romberg_method(g, 0.0, 1.0, n_columns));
+        }
+        let memoized_calls = calls.load(Ordering::SeqCst);
+
+        // each run reuses the nodes of the previous ones, the last run has 2^7 + 1 nodes
+        assert_eq!(memoized_calls, 129);
+        assert_eq!(memoized.evaluations(), 129);
+        assert!(memoized_calls < plain_calls);
+
+        assert_eq!(
+            *integrals.last().unwrap(),
+            romberg_method(f, 0.0, 1.0, 8_usize)
+        );
+    }
+
+    #[test]
+    fn test_removable_singularity() {
+        // Si(π)
+        let exact = 1.851_937_051_982_466;
+        let sinc = |x: f64| x.sin() / x;
+
+        // without the hint the node at 0 poisons the sum
+        assert!(simpson_rule(sinc, 0.0, std::f64::consts::PI, 100_usize).is_nan());
+
+        let hint = RemovableSingularity {
+            at: 0.0,
+            limit_value: 1.0,
+        };
+        let integral = simpson_rule(hint.apply(sinc), 0.0, std::f64::consts::PI, 1_000_usize);
+        assert!((integral - exact).abs() < 1e-12);
+
+        // x / (e^x - 1) tends to 1 at x = 0, problem 12 of the test suite over [0, 1]
+        let hint = RemovableSingularity {
+            at: 0.0,
+            limit_value: 1.0,
+        };
+        let f = hint.apply(|x: f64| x / (x.exp() - 1.0));
+        let integral = simpson_rule(f, 0.0, 1.0, 1_000_usize);
+        assert!((integral - 0.777_504_634_112_248_2).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,56 @@
+//! Scalar helpers
+//!
+//! `min` and `max` follow the IEEE 754 `minNum`/`maxNum` semantics of [`Float::min`] and
+//! [`Float::max`]: when exactly one argument is NaN the other one is returned, so that a
+//! reduction over many values is not poisoned by a single NaN. The branchless form
+//! $\frac{(a+b) \pm |a-b|}{2}$ returns NaN as soon as one argument is NaN, and can also
+//! overflow or lose precision when $a$ and $b$ differ by orders of magnitude.
+
+use num::Float;
+
+/// Returns the maximum of `a` and `b`, ignoring a NaN argument.
+///
+/// # Examples
+/// ```
+/// use integrate::utils::math::max;
+///
+/// assert_eq!(max(1.0, 2.0), 2.0);
+/// assert_eq!(max(f64::NAN, 2.0), 2.0);
+/// ```
+#[must_use]
+pub fn max<F: Float>(a: F, b: F) -> F {
+    a.max(b)
+}
+
+/// Returns the minimum of `a` and `b`, ignoring a NaN argument.
+///
+/// # Examples
+/// ```
+/// use integrate::utils::math::min;
+///
+/// assert_eq!(min(1.0, 2.0), 1.0);
+/// assert_eq!(min(1.0, f64::NAN), 1.0);
+/// ```
+#[must_use]
+pub fn min<F: Float>(a: F, b: F) -> F {
+    a.min(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_min_max() {
+        assert_eq!(min(-1.0, 3.0), -1.0);
+        assert_eq!(max(-1.0, 3.0), 3.0);
+
+        // large magnitude differences are exact
+        assert_eq!(min(1e300, -1e300), -1e300);
+        assert_eq!(max(1e-20, 1e20), 1e20);
+
+        assert_eq!(min(f64::NAN, 3.0), 3.0);
+        assert_eq!(max(3.0, f64::NAN), 3.0);
+        assert!(min(f64::NAN, f64::NAN).is_nan());
+    }
+}
@@ -1,2 +1,9 @@
-pub mod matrix;
-pub mod orthogonal_polynomials;
+//! Utilities
+//!
+//! Helpers shared by the integration methods, and wrappers that can be applied to an
+//! integrand before handing it to any of the rules of this crate.
+
+pub mod affine;
+pub mod integrand;
+pub mod math;
+pub(crate) mod orthogonal_polynomials;
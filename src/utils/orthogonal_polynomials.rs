@@ -1,11 +1,12 @@
-use std::{fmt::Debug, ops::AddAssign};
+use std::fmt::Debug;
 
 use num::Float;
 
-pub trait OrthogonalPolynomial<F: Float + Debug + AddAssign> {
+pub trait OrthogonalPolynomial<F: Float + Debug> {
     fn new(degree: usize) -> Self;
 
     fn eval(&self, x: F) -> F;
 
+    /// Zeros of the polynomial, in ascending order.
     fn zeros(&self) -> Vec<F>;
 }
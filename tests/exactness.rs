@@ -0,0 +1,188 @@
+//! Each rule integrates exactly the polynomials up to its degree of exactness: the midpoint
+//! and trapezoidal rules through degree 1, Simpson's and Newton's 3/8 rules through degree
+//! 3, Boole's rule through degree 5 and the $n$ point Gauss-Legendre rule through degree
+//! $2n - 1$. The polynomials and intervals are drawn from a fixed seed, so that failures
+//! can be reproduced.
+
+use integrate::gauss_quadrature::legendre::legendre_rule;
+use integrate::newton_cotes::boole::boole_rule;
+use integrate::newton_cotes::newton::newton_rule;
+use integrate::newton_cotes::rectangle::rectangle_rule;
+use integrate::newton_cotes::simpson::simpson_rule;
+use integrate::newton_cotes::trapezoidal::trapezoidal_rule;
+
+/// Number of random polynomials drawn for each rule and degree.
+const CASES: usize = 50;
+
+/// SplitMix64 pseudo-random generator.
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform sample in $\[lo, hi)$.
+    fn uniform(&mut self, lo: f64, hi: f64) -> f64 {
+        let unit = (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64;
+        lo + (hi - lo) * unit
+    }
+}
+
+/// Polynomial $\sum_k c_k x^k$ with coefficients in $\[-1, 1)$.
+struct Polynomial {
+    coefficients: Vec<f64>,
+}
+
+impl Polynomial {
+    fn random(generator: &mut SplitMix64, degree: usize) -> Self {
+        let coefficients = (0..=degree).map(|_| generator.uniform(-1.0, 1.0)).collect();
+        Polynomial { coefficients }
+    }
+
+    fn eval(&self, x: f64) -> f64 {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0.0, |acc, &c| acc * x + c)
+    }
+
+    /// $\int_a^b p(x) dx$, from the antiderivative $\sum_k \frac{c_k}{k+1} x^{k+1}$.
+    fn integral(&self, a: f64, b: f64) -> f64 {
+        let antiderivative = |x: f64| {
+            self.coefficients
+                .iter()
+                .enumerate()
+                .rev()
+                .fold(0.0, |acc, (k, &c)| acc * x + c / (k + 1) as f64)
+                * x
+        };
+
+        antiderivative(b) - antiderivative(a)
+    }
+
+    /// $\int_a^b \sum_k |c_k| |x|^k dx$ bound, the scale of the rounding errors.
+    fn scale(&self, a: f64, b: f64) -> f64 {
+        let m = a.abs().max(b.abs()).max(1.0);
+        let sum: f64 = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .map(|(k, c)| c.abs() * m.powi(k as i32))
+            .sum();
+
+        sum * (b - a)
+    }
+}
+
+/// Checks that `rule` integrates random polynomials of degree up to `degree` exactly, and
+/// returns the largest relative error seen.
+fn check_exactness<R>(name: &str, seed: u64, degree: usize, rule: R) -> f64
+where
+    R: Fn(&Polynomial, f64, f64) -> f64,
+{
+    let mut generator = SplitMix64::new(seed);
+    let mut worst: f64 = 0.0;
+
+    for d in 0..=degree {
+        for _ in 0..CASES {
+            let polynomial = Polynomial::random(&mut generator, d);
+            let a = generator.uniform(-2.0, 1.0);
+            let b = a + generator.uniform(0.1, 3.0);
+
+            let error = (rule(&polynomial, a, b) - polynomial.integral(a, b)).abs();
+            let relative = error / polynomial.scale(a, b);
+
+            assert!(
+                relative < 1e-12,
+                "{} on a polynomial of degree {} over [{}, {}]: relative error {:e}, coefficients {:?}",
+                name,
+                d,
+                a,
+                b,
+                relative,
+                polynomial.coefficients
+            );
+            worst = worst.max(relative);
+        }
+    }
+
+    worst
+}
+
+/// Relative error of `rule` on $x^d$ over $\[0, 1\]$, which should be far from zero just
+/// above the degree of exactness.
+fn monomial_error<R>(degree: usize, rule: R) -> f64
+where
+    R: Fn(&Polynomial, f64, f64) -> f64,
+{
+    let mut coefficients = vec![0.0; degree + 1];
+    coefficients[degree] = 1.0;
+    let monomial = Polynomial { coefficients };
+
+    (rule(&monomial, 0.0, 1.0) - monomial.integral(0.0, 1.0)).abs() / monomial.scale(0.0, 1.0)
+}
+
+#[test]
+fn test_rectangle_exactness() {
+    let rule = |p: &Polynomial, a: f64, b: f64| rectangle_rule(|x| p.eval(x), a, b, 7_usize);
+
+    check_exactness("rectangle rule", 1, 1, rule);
+    assert!(monomial_error(2, rule) > 1e-4);
+}
+
+#[test]
+fn test_trapezoidal_exactness() {
+    let rule = |p: &Polynomial, a: f64, b: f64| trapezoidal_rule(|x| p.eval(x), a, b, 7_usize);
+
+    check_exactness("trapezoidal rule", 2, 1, rule);
+    assert!(monomial_error(2, rule) > 1e-4);
+}
+
+#[test]
+fn test_simpson_exactness() {
+    let rule = |p: &Polynomial, a: f64, b: f64| simpson_rule(|x| p.eval(x), a, b, 3_usize);
+
+    check_exactness("Simpson's rule", 3, 3, rule);
+    assert!(monomial_error(4, rule) > 1e-6);
+}
+
+#[test]
+fn test_newton_exactness() {
+    let rule = |p: &Polynomial, a: f64, b: f64| newton_rule(|x| p.eval(x), a, b, 3_usize);
+
+    check_exactness("Newton's 3/8 rule", 4, 3, rule);
+    assert!(monomial_error(4, rule) > 1e-6);
+}
+
+#[test]
+fn test_boole_exactness() {
+    let rule = |p: &Polynomial, a: f64, b: f64| boole_rule(|x| p.eval(x), a, b, 2_usize);
+
+    check_exactness("Boole's rule", 5, 5, rule);
+    assert!(monomial_error(6, rule) > 1e-8);
+}
+
+#[test]
+fn test_legendre_exactness() {
+    for n in [1_usize, 2, 3, 5, 8, 13, 20] {
+        let rule = |p: &Polynomial, a: f64, b: f64| legendre_rule(|x| p.eval(x), a, b, n);
+
+        check_exactness("Gauss-Legendre rule", 6 + n as u64, 2 * n - 1, rule);
+
+        // the error on x^{2n} decreases like 4^{-2n}, and is lost in rounding for larger n
+        if n <= 5 {
+            assert!(monomial_error(2 * n, rule) > 1e-10);
+        }
+    }
+}
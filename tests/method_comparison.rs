@@ -0,0 +1,42 @@
+#![cfg(feature = "test-util")]
+
+//! At the same number of evaluations of the integrand, the Gauss-Legendre rule is more
+//! accurate than Simpson's rule on smooth integrands: guards the relative accuracy of the
+//! two rules, and the nodes and weights of the former.
+
+use integrate::gauss_quadrature::legendre::legendre_rule;
+use integrate::newton_cotes::simpson::simpson_rule;
+use integrate::testing::problems;
+
+/// Ids of the problems whose integrand is analytic over the interval of integration.
+const SMOOTH_PROBLEMS: [usize; 8] = [1, 4, 5, 8, 10, 11, 20, 22];
+
+#[test]
+fn test_legendre_beats_simpson_on_smooth_problems() {
+    let problems = problems::<f64>();
+
+    for id in SMOOTH_PROBLEMS {
+        let problem = &problems[id - 1];
+        assert_eq!(problem.id, id);
+
+        let (a, b) = problem.limits;
+
+        for n in [2_usize, 4, 8, 16] {
+            // Simpson's rule with n subintervals evaluates f at 2n + 1 nodes
+            let evaluations = 2 * n + 1;
+
+            let simpson_error = (simpson_rule(problem.function, a, b, n) - problem.exact).abs();
+            let legendre_error =
+                (legendre_rule(problem.function, a, b, evaluations) - problem.exact).abs();
+
+            assert!(
+                legendre_error < simpson_error,
+                "problem {} with {} evaluations: Gauss-Legendre error {:e}, Simpson error {:e}",
+                id,
+                evaluations,
+                legendre_error,
+                simpson_error
+            );
+        }
+    }
+}
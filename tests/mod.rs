@@ -1 +1,2 @@
+#[path = "../src/testing.rs"]
 pub mod problems;
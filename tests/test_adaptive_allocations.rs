@@ -0,0 +1,58 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use integrate::adaptive_quadrature::simpson::adaptive_simpson_method;
+
+// counts every allocation made by this test binary
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+static EVALUATIONS: AtomicUsize = AtomicUsize::new(0);
+
+// sharp peaks forcing many subdivisions
+fn f(x: f64) -> f64 {
+    EVALUATIONS.fetch_add(1, Ordering::SeqCst);
+
+    (1.0 / (10.0 * (x - 0.2)).cosh()).powi(2)
+        + (1.0 / (100.0 * (x - 0.4)).cosh()).powi(4)
+        + (1.0 / (1000.0 * (x - 0.6)).cosh()).powi(6)
+}
+
+#[test]
+fn test_adaptive_simpson_allocations() {
+    let tolerance = 10.0e-10;
+    let min_h = 10.0e-9;
+
+    let allocations_before = ALLOCATIONS.load(Ordering::SeqCst);
+
+    let result = adaptive_simpson_method(f, 0.0, 1.0, min_h, tolerance);
+
+    let allocations = ALLOCATIONS.load(Ordering::SeqCst) - allocations_before;
+    let evaluations = EVALUATIONS.load(Ordering::SeqCst);
+
+    assert!((result.unwrap() - 0.210_802_736_310_181_77).abs() < 10e-8);
+
+    // each subdivision evaluates f twice, the stack only grows logarithmically
+    assert!(evaluations > 1_000, "only {} evaluations", evaluations);
+    assert!(
+        allocations < 64,
+        "{} allocations for {} evaluations",
+        allocations,
+        evaluations
+    );
+}
@@ -1,8 +1,18 @@
+#[allow(dead_code)]
+#[path = "../src/testing.rs"]
 mod problems;
 
 use std::iter::Sum;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
-use integrate::adaptive_quadrature::simpson::adaptive_simpson_method;
+use integrate::adaptive_quadrature::simpson::{
+    adaptive_simpson_method, adaptive_simpson_method_with_config,
+    adaptive_simpson_method_with_progress, adaptive_simpson_method_with_tolerance, quad_simple,
+    AdaptiveConfig, DEFAULT_TOLERANCE,
+};
+use integrate::adaptive_quadrature::simpson_2d::adaptive_simpson_2d;
+use integrate::newton_cotes::simpson::simpson_rule;
+use integrate::tolerance::Tolerance;
 use num::Float;
 
 use problems::{
@@ -120,3 +130,178 @@ fn test_f64_problems() {
         test_problem_f64(problem);
     }
 }
+
+#[test]
+fn test_progress() {
+    use std::cell::RefCell;
+
+    let problem: Problem<f64> = problem21();
+    let (a, b) = problem.limits;
+
+    let fractions = RefCell::new(Vec::new());
+    let progress = |fraction: f64| fractions.borrow_mut().push(fraction);
+
+    let result =
+        adaptive_simpson_method_with_progress(problem.function, a, b, 1e-8, 1e-9, Some(&progress))
+            .unwrap();
+    assert!(problem.check_result(result));
+
+    let fractions = fractions.into_inner();
+    assert!(fractions.len() > 1);
+    assert!(fractions.windows(2).all(|w| w[0] < w[1]));
+    assert!((fractions.last().unwrap() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_simpson_2d_peak() {
+    // narrow gaussian bump, far enough from the edges for its tails to be negligible
+    let sigma = 0.01;
+    let f = |x: f64, y: f64| {
+        let r2 = (x - 0.3).powi(2) + (y - 0.6).powi(2);
+        (-r2 / (2.0 * sigma * sigma)).exp()
+    };
+    let exact = 2.0 * std::f64::consts::PI * sigma * sigma;
+
+    // uniform tensor product Simpson's rule with 10 x 10 cells misses the bump
+    let uniform = simpson_rule(
+        |x: f64| simpson_rule(|y: f64| f(x, y), 0.0, 1.0, 10_usize),
+        0.0,
+        1.0,
+        10_usize,
+    );
+    assert!((uniform - exact).abs() > 1e-2 * exact);
+
+    let integral = adaptive_simpson_2d(f, (0.0, 1.0), (0.0, 1.0), 1e-9).unwrap();
+    assert!((integral - exact).abs() < 1e-8);
+}
+
+#[test]
+#[should_panic(expected = "min_h must be positive.")]
+fn test_zero_min_h() {
+    let _ = adaptive_simpson_method(|x: f64| x.exp(), 0.0, 1.0, 0.0, 1e-6);
+}
+
+#[test]
+#[should_panic(expected = "min_h must be positive.")]
+fn test_negative_min_h() {
+    let _ = adaptive_simpson_method(|x: f64| x.exp(), 0.0, 1.0, -1e-3, 1e-6);
+}
+
+#[test]
+#[should_panic(expected = "min_h must be less than the length of the integration interval.")]
+fn test_min_h_larger_than_interval() {
+    let _ = adaptive_simpson_method(|x: f64| x.exp(), 0.0, 1.0, 2.0, 1e-6);
+}
+
+#[test]
+fn test_eval_budget() {
+    // sharp peaks forcing many subdivisions
+    let f = |x: f64| {
+        (1.0 / (10.0 * (x - 0.2)).cosh()).powi(2)
+            + (1.0 / (100.0 * (x - 0.4)).cosh()).powi(4)
+            + (1.0 / (1000.0 * (x - 0.6)).cosh()).powi(6)
+    };
+    let exact = 0.210_802_736_310_181_77;
+
+    let unlimited = AdaptiveConfig::new(1e-9, 1e-10);
+    let outcome = adaptive_simpson_method_with_config(f, 0.0, 1.0, &unlimited).unwrap();
+    assert!(!outcome.budget_exceeded);
+    assert!((outcome.integral - exact).abs() < 1e-8);
+
+    let config = AdaptiveConfig {
+        eval_budget: 200,
+        ..unlimited
+    };
+    let outcome = adaptive_simpson_method_with_config(f, 0.0, 1.0, &config).unwrap();
+
+    assert!(outcome.budget_exceeded);
+    assert!(outcome.evaluations <= 200);
+    assert!(outcome.evaluations >= 199);
+
+    // partial result, the narrower peaks are not resolved yet but the magnitude is right
+    assert!((outcome.integral - exact).abs() < 0.1 * exact);
+}
+
+#[test]
+fn test_combined_tolerance() {
+    let exact = 1e8 * (1f64.exp() - 1.0);
+
+    let evaluations = AtomicUsize::new(0);
+    let counter = &evaluations;
+    let f = move |x: f64| {
+        counter.fetch_add(1, Ordering::Relaxed);
+        1e8 * x.exp()
+    };
+
+    // pure absolute: about 14 significant digits of the large integral
+    let absolute = Tolerance::absolute(1e-5);
+    let integral = adaptive_simpson_method_with_tolerance(f, 0.0, 1.0, 1e-9, &absolute).unwrap();
+    assert!((integral - exact).abs() < 1e-5);
+    let absolute_evaluations = evaluations.swap(0, Ordering::Relaxed);
+
+    // pure relative: 6 significant digits, whatever the magnitude
+    let relative = Tolerance::relative(1e-6);
+    let integral = adaptive_simpson_method_with_tolerance(f, 0.0, 1.0, 1e-9, &relative).unwrap();
+    assert!((integral - exact).abs() < 1e-6 * exact);
+    assert!((integral - exact).abs() > 1e-5);
+    let relative_evaluations = evaluations.load(Ordering::Relaxed);
+
+    assert!(relative_evaluations < absolute_evaluations);
+}
+
+#[test]
+fn test_quad_simple() {
+    let square = |x: f64| x * x;
+
+    assert!((quad_simple(square, 0.0, 1.0) - 1.0 / 3.0).abs() < 1e-12);
+    assert!((quad_simple(square, 1.0, 0.0) + 1.0 / 3.0).abs() < 1e-12);
+    assert_eq!(quad_simple(square, 0.5, 0.5), 0.0);
+
+    // the adaptive method fails on the singularity, the fallback doesn't evaluate f at 0
+    let integral = quad_simple(|x: f64| 1.0 / x.sqrt(), 0.0, 1.0);
+    assert!((integral - 2.0).abs() < 1e-2);
+
+    // but its accuracy is nowhere near the default tolerance
+    assert!(
+        adaptive_simpson_method(|x: f64| 1.0 / x.sqrt(), 0.0, 1.0, 1e-10, DEFAULT_TOLERANCE)
+            .is_err()
+    );
+    assert!((integral - 2.0).abs() > 1e3 * DEFAULT_TOLERANCE);
+}
+
+#[test]
+fn test_reversed_limits() {
+    let f = |x: f64| x.exp();
+
+    let forward = adaptive_simpson_method(f, 0.0, 1.0, 1e-3, 1e-8).unwrap();
+    let reversed = adaptive_simpson_method(f, 1.0, 0.0, 1e-3, 1e-8).unwrap();
+
+    assert_eq!(reversed, -forward);
+
+    let tolerance = Tolerance::absolute(1e-8);
+    let forward = adaptive_simpson_method_with_tolerance(f, 0.0, 1.0, 1e-3, &tolerance).unwrap();
+    let reversed = adaptive_simpson_method_with_tolerance(f, 1.0, 0.0, 1e-3, &tolerance).unwrap();
+
+    assert_eq!(reversed, -forward);
+}
+
+#[test]
+fn test_tolerance_vanishing_three_point_estimate() {
+    // f(0) + 4 f(1/2) + f(1) = 0, while ∫ x (1 - x) (x - 1/2)² dx = 1/120 over [0, 1]
+    let f = |x: f64| x * (1.0 - x) * (x - 0.5).powi(2);
+
+    let tolerance = Tolerance::relative(1e-8);
+    let integral = adaptive_simpson_method_with_tolerance(f, 0.0, 1.0, 1e-6, &tolerance).unwrap();
+
+    assert!((integral - 1.0 / 120.0).abs() < 1e-8 / 120.0);
+}
+
+#[test]
+fn test_tolerance_zero_integrand() {
+    // a purely relative tolerance on a vanishing integrand gives a zero bound
+    let tolerance = Tolerance::relative(1e-8);
+    let integral =
+        adaptive_simpson_method_with_tolerance(|_| 0.0, 0.0, 1.0, 1e-6, &tolerance).unwrap();
+
+    assert_eq!(integral, 0.0);
+}
@@ -0,0 +1,199 @@
+//! The Gauss rules only require `num::Float` (plus `Debug`, `Send` and `Sync`) from the node
+//! type. `Wrapped` is a minimal float type implementing nothing more, in particular neither
+//! `AddAssign`, `Sum` nor `ToBigInt`.
+
+use std::num::FpCategory;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use integrate::gauss_quadrature::chebyshev::gauss_first_kind_chebyshev_rule;
+use integrate::gauss_quadrature::hermite::gauss_hermite_rule;
+use integrate::gauss_quadrature::laguerre::gauss_laguerre_rule;
+use num::{Float, Num, NumCast, One, ToPrimitive, Zero};
+
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+struct Wrapped(f64);
+
+macro_rules! binary_op {
+    ($trait:ident, $method:ident) => {
+        impl $trait for Wrapped {
+            type Output = Wrapped;
+
+            fn $method(self, rhs: Wrapped) -> Wrapped {
+                Wrapped(self.0.$method(rhs.0))
+            }
+        }
+    };
+}
+
+binary_op!(Add, add);
+binary_op!(Sub, sub);
+binary_op!(Mul, mul);
+binary_op!(Div, div);
+binary_op!(Rem, rem);
+
+impl Neg for Wrapped {
+    type Output = Wrapped;
+
+    fn neg(self) -> Wrapped {
+        Wrapped(-self.0)
+    }
+}
+
+impl Zero for Wrapped {
+    fn zero() -> Wrapped {
+        Wrapped(0.0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0.0
+    }
+}
+
+impl One for Wrapped {
+    fn one() -> Wrapped {
+        Wrapped(1.0)
+    }
+}
+
+impl Num for Wrapped {
+    type FromStrRadixErr = <f64 as Num>::FromStrRadixErr;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Wrapped, Self::FromStrRadixErr> {
+        f64::from_str_radix(str, radix).map(Wrapped)
+    }
+}
+
+impl ToPrimitive for Wrapped {
+    fn to_i64(&self) -> Option<i64> {
+        self.0.to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.0.to_u64()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.0)
+    }
+}
+
+impl NumCast for Wrapped {
+    fn from<T: ToPrimitive>(n: T) -> Option<Wrapped> {
+        n.to_f64().map(Wrapped)
+    }
+}
+
+macro_rules! constant {
+    ($($method:ident),*) => {
+        $(fn $method() -> Wrapped {
+            Wrapped(f64::$method())
+        })*
+    };
+}
+
+macro_rules! predicate {
+    ($($method:ident),*) => {
+        $(fn $method(self) -> bool {
+            self.0.$method()
+        })*
+    };
+}
+
+macro_rules! unary {
+    ($($method:ident),*) => {
+        $(fn $method(self) -> Wrapped {
+            Wrapped(self.0.$method())
+        })*
+    };
+}
+
+macro_rules! binary {
+    ($($method:ident),*) => {
+        $(fn $method(self, other: Wrapped) -> Wrapped {
+            Wrapped(self.0.$method(other.0))
+        })*
+    };
+}
+
+impl Float for Wrapped {
+    constant!(
+        nan,
+        infinity,
+        neg_infinity,
+        neg_zero,
+        min_value,
+        min_positive_value,
+        max_value,
+        epsilon
+    );
+
+    predicate!(
+        is_nan,
+        is_infinite,
+        is_finite,
+        is_normal,
+        is_sign_positive,
+        is_sign_negative
+    );
+
+    unary!(
+        floor, ceil, round, trunc, fract, abs, signum, recip, sqrt, exp, exp2, ln, log2, log10,
+        cbrt, sin, cos, tan, asin, acos, atan, exp_m1, ln_1p, sinh, cosh, tanh, asinh, acosh,
+        atanh
+    );
+
+    binary!(powf, log, max, min, hypot, atan2);
+
+    fn abs_sub(self, other: Wrapped) -> Wrapped {
+        Wrapped((self.0 - other.0).max(0.0))
+    }
+
+    fn classify(self) -> FpCategory {
+        self.0.classify()
+    }
+
+    fn mul_add(self, a: Wrapped, b: Wrapped) -> Wrapped {
+        Wrapped(self.0.mul_add(a.0, b.0))
+    }
+
+    fn powi(self, n: i32) -> Wrapped {
+        Wrapped(self.0.powi(n))
+    }
+
+    fn sin_cos(self) -> (Wrapped, Wrapped) {
+        let (sin, cos) = self.0.sin_cos();
+        (Wrapped(sin), Wrapped(cos))
+    }
+
+    fn integer_decode(self) -> (u64, i16, i8) {
+        Float::integer_decode(self.0)
+    }
+}
+
+#[test]
+fn test_hermite_custom_float() {
+    // ∫ x² e^{-x²} dx = √π / 2
+    let integral: Wrapped = gauss_hermite_rule(|x: Wrapped| x * x, 10);
+
+    let exact = std::f64::consts::PI.sqrt() / 2.0;
+    assert!((integral.0 - exact).abs() < 1e-6);
+
+    let reference: f64 = gauss_hermite_rule(|x: f64| x * x, 10);
+    assert!((integral.0 - reference).abs() < 1e-14);
+}
+
+#[test]
+fn test_laguerre_custom_float() {
+    // ∫ x e^{-x} dx = 1
+    let integral: Wrapped = gauss_laguerre_rule(|x: Wrapped| x, 10);
+
+    assert!((integral.0 - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_chebyshev_custom_float() {
+    // ∫ 1 / √(1 - x²) dx = π
+    let integral: Wrapped = gauss_first_kind_chebyshev_rule(|_: Wrapped| Wrapped(1.0), 10);
+
+    assert!((integral.0 - std::f64::consts::PI).abs() < 1e-12);
+}
@@ -1,3 +1,5 @@
+#[allow(dead_code)]
+#[path = "../src/testing.rs"]
 mod problems;
 
 const N: usize = 100_000;
@@ -100,3 +102,12 @@ fn test_f64_problems() {
         test_problem_f64(problem);
     }
 }
+
+#[test]
+fn test_square_100_points() {
+    let square = |x: f64| x * x;
+
+    let integral = legendre_rule(square, 0.0, 1.0, 100_usize);
+
+    assert!((integral - 1.0 / 3.0).abs() < 1e-10);
+}
@@ -1,3 +1,5 @@
+#[allow(dead_code)]
+#[path = "../src/testing.rs"]
 mod problems;
 
 use std::iter::Sum;
@@ -5,8 +7,11 @@ use std::iter::Sum;
 use num::Float;
 
 use integrate::newton_cotes::{
-    newton::newton_rule, rectangle::rectangle_rule, simpson::simpson_rule,
-    trapezoidal::trapezoidal_rule,
+    boole::boole_rule,
+    newton::{newton_rule, newton_rule_with_error},
+    rectangle::{rectangle_rule, rectangle_rule_with_error},
+    simpson::{simpson_rule, simpson_rule_with_error},
+    trapezoidal::{trapezoidal_rule, trapezoidal_rule_with_error},
 };
 
 use itertools::Itertools;
@@ -58,15 +63,17 @@ enum Methods {
     Trapezoidal,
     Newton3Over8,
     Simpson,
+    Boole,
 }
 
 impl Methods {
-    pub fn iter() -> core::array::IntoIter<Methods, 4> {
+    pub fn iter() -> core::array::IntoIter<Methods, 5> {
         [
             Methods::Rectangle,
             Methods::Trapezoidal,
             Methods::Newton3Over8,
             Methods::Simpson,
+            Methods::Boole,
         ]
         .into_iter()
     }
@@ -77,6 +84,7 @@ impl Methods {
             Methods::Trapezoidal => "Trapezoidal Rule",
             Methods::Newton3Over8 => "Newton 3/8 Rule",
             Methods::Simpson => "Simpson Method",
+            Methods::Boole => "Boole's Rule",
         }
     }
 }
@@ -127,6 +135,7 @@ fn integrate<F: Float + Send + Sync>(method: Methods, f: fn(F) -> F, a: F, b: F,
         Methods::Trapezoidal => trapezoidal_rule(f, a, b, n),
         Methods::Newton3Over8 => newton_rule(f, a, b, n),
         Methods::Simpson => simpson_rule(f, a, b, n),
+        Methods::Boole => boole_rule(f, a, b, n),
     }
 }
 
@@ -135,7 +144,7 @@ fn test_f32_problems() {
     let problems: Vec<Problem<f32>> = newton_cotes_problems();
     let methods = Methods::iter();
 
-    for (problem, method) in problems.into_iter().cartesian_product(methods.into_iter()) {
+    for (problem, method) in problems.into_iter().cartesian_product(methods) {
         test_problem_f32(problem, method);
     }
 }
@@ -145,7 +154,98 @@ fn test_f64_problems() {
     let problems: Vec<Problem<f64>> = newton_cotes_problems();
     let methods = Methods::iter();
 
-    for (problem, method) in problems.into_iter().cartesian_product(methods.into_iter()) {
+    for (problem, method) in problems.into_iter().cartesian_product(methods) {
         test_problem_f64(problem, method);
     }
 }
+
+#[test]
+fn test_reversed_limits() {
+    fn square(x: f64) -> f64 {
+        x * x
+    }
+
+    for n in [1_usize, 3, 12, 100] {
+        assert_eq!(
+            trapezoidal_rule(square, 1.0, 0.0, n),
+            -trapezoidal_rule(square, 0.0, 1.0, n)
+        );
+    }
+
+    // the problems sum in parallel, in an order that may change between the two calls
+    let problems: Vec<Problem<f64>> = newton_cotes_problems();
+
+    for (problem, method) in problems.into_iter().cartesian_product(Methods::iter()) {
+        let (a, b) = problem.limits;
+        let f = problem.function;
+
+        let forward = integrate(method, f, a, b, problem.n);
+        let reversed = integrate(method, f, b, a, problem.n);
+
+        assert!(
+            (reversed + forward).abs() <= 1e-12 * forward.abs().max(1.0),
+            "{} on problem {}",
+            method.display(),
+            problem.id
+        );
+    }
+}
+
+#[test]
+fn test_rule_with_error_rates() {
+    // the error of each rule on these integrands is exactly K h^p, p being the order of the rule
+    type WithError = fn(usize) -> (f64, f64);
+    let rules: [(&str, WithError, i32, f64); 4] = [
+        (
+            "rectangle",
+            |n| rectangle_rule_with_error(|x: f64| x * x, 0.0, 1.0, n),
+            2,
+            1.0 / 3.0,
+        ),
+        (
+            "trapezoidal",
+            |n| trapezoidal_rule_with_error(|x: f64| x * x, 0.0, 1.0, n),
+            2,
+            1.0 / 3.0,
+        ),
+        (
+            "simpson",
+            |n| simpson_rule_with_error(|x: f64| x.powi(4), 0.0, 1.0, n),
+            4,
+            0.2,
+        ),
+        (
+            "newton",
+            |n| newton_rule_with_error(|x: f64| x.powi(4), 0.0, 1.0, n),
+            4,
+            0.2,
+        ),
+    ];
+
+    for (name, rule, order, exact) in rules {
+        let mut previous_error = None;
+        for n in [4_usize, 8, 16, 32] {
+            let (integral, error) = rule(n);
+            assert!(
+                (error - (integral - exact).abs()).abs() < 1e-12 + 1e-6 * error,
+                "{} with n = {}: error {}",
+                name,
+                n,
+                error
+            );
+
+            // halving h divides the error by 2^p
+            if let Some(previous_error) = previous_error {
+                let ratio: f64 = previous_error / error;
+                assert!(
+                    (ratio - 2f64.powi(order)).abs() < 1e-3,
+                    "{} with n = {}: ratio {}",
+                    name,
+                    n,
+                    ratio
+                );
+            }
+            previous_error = Some(error);
+        }
+    }
+}
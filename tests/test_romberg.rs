@@ -1,3 +1,5 @@
+#[allow(dead_code)]
+#[path = "../src/testing.rs"]
 mod problems;
 
 const N: usize = 20;
@@ -0,0 +1,16 @@
+#![cfg(feature = "test-util")]
+
+use integrate::testing::problems;
+
+#[test]
+fn test_exported_problems() {
+    let problems = problems::<f64>();
+
+    assert_eq!(problems.len(), 30);
+
+    for (i, problem) in problems.iter().enumerate() {
+        assert_eq!(problem.id, i + 1);
+        assert!(problem.exact.is_finite());
+        assert!(problem.check_result(problem.exact));
+    }
+}